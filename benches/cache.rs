@@ -0,0 +1,140 @@
+#[allow(dead_code)]
+#[path = "../src/structs/cache.rs"]
+mod cache;
+
+use std::thread;
+
+use cache::Cache;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+const GUILDS: u64 = 10_000;
+const THREADS: u64 = 8;
+const USERS_PER_CHANNEL: u64 = 25;
+const VOICE_CHANNELS_PER_GUILD: u64 = 4;
+
+fn guild_id(guild: u64) -> Id<GuildMarker> {
+    Id::new(guild + 1)
+}
+
+fn category_channel_id(guild: u64) -> Id<ChannelMarker> {
+    Id::new((guild + 1) << 8)
+}
+
+fn voice_channel_id(guild: u64, channel: u64) -> Id<ChannelMarker> {
+    Id::new(((guild + 1) << 8) + channel + 1)
+}
+
+fn user_id(guild: u64, channel: u64, user: u64) -> Id<UserMarker> {
+    Id::new((((guild + 1) << 8) + channel + 1) << 8 | user)
+}
+
+fn populated_cache(guilds: u64) -> Cache {
+    let cache = Cache::new();
+
+    for guild in 0..guilds {
+        cache.insert_guild(
+            guild_id(guild),
+            Id::<RoleMarker>::new(guild + 1),
+            false,
+            "unlocked".to_owned(),
+        );
+        cache.insert_category_channel(guild_id(guild), category_channel_id(guild), Vec::new(), []);
+
+        for channel in 0..VOICE_CHANNELS_PER_GUILD {
+            cache.insert_voice_channel(
+                [],
+                guild_id(guild),
+                voice_channel_id(guild, channel),
+                Some(user_id(guild, channel, 0)),
+                None,
+                category_channel_id(guild),
+                Vec::new(),
+            );
+        }
+    }
+
+    cache
+}
+
+// Every thread owns a slice of the guilds, like event handlers for different guilds do.
+fn join_leave_storm(cache: &Cache) {
+    thread::scope(|scope| {
+        for thread in 0..THREADS {
+            scope.spawn(move || {
+                for guild in (thread..GUILDS).step_by(THREADS as usize) {
+                    for channel in 0..VOICE_CHANNELS_PER_GUILD {
+                        for user in 0..USERS_PER_CHANNEL {
+                            cache.insert_voice_state(
+                                guild_id(guild),
+                                voice_channel_id(guild, channel),
+                                user_id(guild, channel, user),
+                            );
+                        }
+                    }
+
+                    for channel in 0..VOICE_CHANNELS_PER_GUILD {
+                        for user in 0..USERS_PER_CHANNEL {
+                            cache.voice_channel_owner(guild_id(guild), user_id(guild, channel, 0));
+                            cache
+                                .remove_voice_state(guild_id(guild), user_id(guild, channel, user));
+                        }
+                    }
+                }
+            });
+        }
+    });
+}
+
+fn benchmarks(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("cache");
+
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(
+        GUILDS * VOICE_CHANNELS_PER_GUILD * USERS_PER_CHANNEL * 2,
+    ));
+    group.bench_function("join_leave_storm", |bencher| {
+        let cache = populated_cache(GUILDS);
+
+        bencher.iter(|| join_leave_storm(&cache));
+    });
+    group.throughput(Throughput::Elements(
+        GUILDS * VOICE_CHANNELS_PER_GUILD * USERS_PER_CHANNEL,
+    ));
+    group.bench_function("remove_guilds", |bencher| {
+        bencher.iter_batched(
+            || {
+                let cache = populated_cache(GUILDS);
+
+                for guild in 0..GUILDS {
+                    for channel in 0..VOICE_CHANNELS_PER_GUILD {
+                        for user in 0..USERS_PER_CHANNEL {
+                            cache.insert_voice_state(
+                                guild_id(guild),
+                                voice_channel_id(guild, channel),
+                                user_id(guild, channel, user),
+                            );
+                        }
+                    }
+                }
+
+                cache
+            },
+            |cache| {
+                for guild in 0..GUILDS {
+                    cache.remove_guild(guild_id(guild));
+                }
+
+                cache
+            },
+            BatchSize::PerIteration,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmarks);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+use std::{fs, path::PathBuf};
+
+use eyre::{Result, WrapErr};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{structs::context::Context, utilities::guild_export::export_guild};
+
+pub async fn run(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let guild_export = export_guild(context, guild_id)
+        .await
+        .wrap_err("Unable to export the server.")?;
+    let output = output.unwrap_or_else(|| PathBuf::from(format!("meloetta-{guild_id}.json")));
+
+    fs::write(&output, serde_json::to_vec_pretty(&guild_export)?)
+        .wrap_err("Unable to write the export file.")?;
+    println!("Exported server {guild_id} to {}.", output.display());
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use eyre::{Result, WrapErr};
+
+use crate::structs::{config::Config, database};
+
+pub async fn run(config: &Config) -> Result<()> {
+    let database = database::connect(&config.database)?;
+
+    database
+        .ping()
+        .await
+        .wrap_err("Unable to connect to the database.")?;
+    database.create_tables().await?;
+    database.close();
+    println!("The database tables are up to date.");
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+pub mod export;
+pub mod migrate;
+pub mod prune;
+pub mod register_commands;
+pub mod stats;
+
+use std::{collections::HashMap, path::PathBuf};
+
+use eyre::{bail, eyre, Result, WrapErr};
+use twilight_http::Client;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::structs::{config::Config, context::Context};
+
+pub const USAGE: &str = "\
+Usage: meloetta [command] [options]
+
+Commands:
+  (none)                              Run the bot
+  migrate                             Create or update the database tables
+  register-commands [--guild ID]      Register the slash commands globally or in one server
+  export --guild ID [--output PATH]   Write a server's settings to a JSON file
+  prune [--guild ID]                  Remove stored servers and channels that no longer exist on Discord
+  stats                               Print how many rows each table holds
+  help                                Print this message";
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Command {
+    Export {
+        guild_id: Id<GuildMarker>,
+        output: Option<PathBuf>,
+    },
+    Help,
+    Migrate,
+    Prune {
+        guild_id: Option<Id<GuildMarker>>,
+    },
+    RegisterCommands {
+        guild_id: Option<Id<GuildMarker>>,
+    },
+    Stats,
+}
+
+impl Command {
+    // Returns `None` when no command is given, in which case the bot runs.
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>> {
+        let Some(name) = args.next() else {
+            return Ok(None);
+        };
+        let mut options = parse_options(args)?;
+        let command = match name.as_str() {
+            "export" => Self::Export {
+                guild_id: guild_id_option(&mut options)?
+                    .ok_or_else(|| eyre!("`export` needs a server ID as `--guild`."))?,
+                output: options.remove("output").map(PathBuf::from),
+            },
+            "help" | "--help" | "-h" => Self::Help,
+            "migrate" => Self::Migrate,
+            "prune" => Self::Prune {
+                guild_id: guild_id_option(&mut options)?,
+            },
+            "register-commands" => Self::RegisterCommands {
+                guild_id: guild_id_option(&mut options)?,
+            },
+            "stats" => Self::Stats,
+            _ => bail!("`{name}` is not a command.\n\n{USAGE}"),
+        };
+
+        if let Some(option) = options.keys().next() {
+            bail!("`{name}` doesn't take `--{option}`.\n\n{USAGE}");
+        }
+
+        Ok(Some(command))
+    }
+}
+
+pub async fn run(command: Command, config: Config) -> Result<()> {
+    match command {
+        Command::Export { guild_id, output } => {
+            export::run(&connect(config).await?, guild_id, output).await
+        }
+        Command::Help => {
+            println!("{USAGE}");
+
+            Ok(())
+        }
+        Command::Migrate => migrate::run(&config).await,
+        Command::Prune { guild_id } => prune::run(&connect(config).await?, guild_id).await,
+        Command::RegisterCommands { guild_id } => {
+            register_commands::run(&connect(config).await?, guild_id).await
+        }
+        Command::Stats => stats::run(&config).await,
+    }
+}
+
+// Builds a context for commands that need Discord's REST API, without starting the gateway.
+async fn connect(config: Config) -> Result<Context> {
+    let client = Client::new(config.discord.token.clone());
+    let application_id = client.current_user_application().await?.model().await?.id;
+    let context = Context::new(client, application_id, config)?;
+
+    context
+        .database
+        .ping()
+        .await
+        .wrap_err("Unable to connect to the database.")?;
+
+    Ok(context)
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<HashMap<String, String>> {
+    let mut options = HashMap::new();
+
+    while let Some(arg) = args.next() {
+        let Some(name) = arg.strip_prefix("--") else {
+            bail!("Unexpected argument `{arg}`.\n\n{USAGE}");
+        };
+        let value = args
+            .next()
+            .ok_or_else(|| eyre!("`--{name}` needs a value."))?;
+
+        options.insert(name.to_owned(), value);
+    }
+
+    Ok(options)
+}
+
+fn guild_id_option(options: &mut HashMap<String, String>) -> Result<Option<Id<GuildMarker>>> {
+    options
+        .remove("guild")
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| eyre!("`{value}` is not a server ID."))
+        })
+        .transpose()
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use eyre::Result;
+use tracing::info;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::{
+    structs::context::Context,
+    utilities::{
+        consistency::{guild_channels, prune_guild_channels},
+        request::ObserveRequest,
+    },
+};
+
+const GUILD_PAGE_SIZE: u16 = 200;
+
+#[derive(Default)]
+pub struct PruneSummary {
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+    pub guild_ids: Vec<Id<GuildMarker>>,
+}
+
+pub async fn run(context: &Context, guild_id: Option<Id<GuildMarker>>) -> Result<()> {
+    let summary = prune(context, guild_id).await?;
+
+    println!(
+        "Removed {} servers and {} channels.",
+        summary.guild_ids.len(),
+        summary.channel_ids.len()
+    );
+
+    Ok(())
+}
+
+// Removes stored servers the bot is no longer in and stored channels deleted on Discord.
+pub async fn prune(context: &Context, guild_id: Option<Id<GuildMarker>>) -> Result<PruneSummary> {
+    let guild_ids = match guild_id {
+        Some(guild_id) => vec![guild_id],
+        None => context
+            .database
+            .guilds()
+            .await?
+            .into_iter()
+            .map(|guild| guild.id)
+            .collect(),
+    };
+    let current_guild_ids = current_guild_ids(context).await?;
+    let mut summary = PruneSummary::default();
+
+    for guild_id in guild_ids {
+        if !current_guild_ids.contains(&guild_id) {
+            context.database.remove_guild(guild_id).await?;
+            info!(guild_id = guild_id.get(), "Removed server");
+            summary.guild_ids.push(guild_id);
+
+            continue;
+        }
+
+        let channels = guild_channels(context, guild_id).await?;
+        let removed_channel_ids = prune_guild_channels(context, guild_id, &channels).await?;
+
+        if !removed_channel_ids.is_empty() {
+            info!(
+                guild_id = guild_id.get(),
+                channel_count = removed_channel_ids.len(),
+                "Removed channels"
+            );
+        }
+
+        summary.channel_ids.extend(removed_channel_ids);
+    }
+
+    Ok(summary)
+}
+
+async fn current_guild_ids(context: &Context) -> Result<HashSet<Id<GuildMarker>>> {
+    let mut guild_ids = HashSet::new();
+    let mut after = None;
+
+    loop {
+        let mut request = context.client.current_user_guilds().limit(GUILD_PAGE_SIZE);
+
+        if let Some(after) = after {
+            request = request.after(after);
+        }
+
+        let guilds = request.observe(context).await?.models().await?;
+
+        after = guilds.last().map(|guild| guild.id);
+        guild_ids.extend(guilds.iter().map(|guild| guild.id));
+
+        if guilds.len() < usize::from(GUILD_PAGE_SIZE) {
+            break;
+        }
+    }
+
+    Ok(guild_ids)
+}
@@ -0,0 +1,40 @@
+use eyre::Result;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{structs::context::Context, utilities::registration::register_commands};
+
+pub async fn run(context: &Context, guild_id: Option<Id<GuildMarker>>) -> Result<()> {
+    let guild_ids = match guild_id {
+        Some(guild_id) => vec![Some(guild_id)],
+        None if context.config.development.guild_ids.is_empty() => vec![None],
+        None => context
+            .config
+            .development
+            .guild_ids
+            .iter()
+            .copied()
+            .map(Some)
+            .collect(),
+    };
+
+    for guild_id in guild_ids {
+        let changes = register_commands(context, guild_id).await?;
+        let scope = guild_id.map_or_else(
+            || "global commands".to_owned(),
+            |guild_id| format!("commands in server {guild_id}"),
+        );
+
+        if changes.is_empty() {
+            println!("The {scope} are up to date.");
+        } else {
+            println!(
+                "Updated the {scope}: {} created, {} updated, {} removed.",
+                changes.created.len(),
+                changes.updated.len(),
+                changes.removed.len()
+            );
+        }
+    }
+
+    Ok(())
+}
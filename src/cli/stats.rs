@@ -0,0 +1,28 @@
+use eyre::{Result, WrapErr};
+
+use crate::structs::{config::Config, database};
+
+pub async fn run(config: &Config) -> Result<()> {
+    let database = database::connect(&config.database)?;
+
+    database
+        .ping()
+        .await
+        .wrap_err("Unable to connect to the database.")?;
+
+    let stats = database.stats().await?;
+
+    database.close();
+    println!("Servers: {}", stats.guilds);
+    println!("Categories: {}", stats.category_channels);
+    println!("Join channels: {}", stats.join_channels);
+    println!("Voice channels: {}", stats.voice_channels);
+    println!("Channel templates: {}", stats.channel_templates);
+    println!(
+        "Pending voice channel creations: {}",
+        stats.voice_channel_creations
+    );
+    println!("Saved gateway sessions: {}", stats.shard_sessions);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_http::Error as HttpError;
+use twilight_model::{
+    channel::ChannelType,
+    gateway::payload::incoming::ChannelCreate,
+    id::{marker::UserMarker, Id},
+};
+
+use crate::{
+    structs::context::Context,
+    utilities::{
+        interaction::{
+            create_panel_message, create_panel_message_components, create_panel_message_embed,
+            guild_locale,
+        },
+        request::is_not_found,
+    },
+};
+
+// Voice channels the bot creates are tracked before this event is handled, so only channels
+// created by members inside a managed category end up here. They start without an owner and
+// can be claimed from the panel.
+pub async fn run(context: Arc<Context>, payload: ChannelCreate) -> Result<()> {
+    let channel = payload.0;
+    let voice_channel_id = channel.id;
+    let (Some(guild_id), Some(parent_id)) = (channel.guild_id, channel.parent_id) else {
+        return Ok(());
+    };
+
+    if channel.kind != ChannelType::GuildVoice
+        || context.cache.voice_channel(voice_channel_id).is_some()
+        || context.cache.join_channel(voice_channel_id).is_some()
+        || !context
+            .cache
+            .category_channel(parent_id)
+            .is_some_and(|category_channel| category_channel.guild_id == guild_id)
+    {
+        return Ok(());
+    }
+
+    let Some(guild) = context.cache.guild(guild_id) else {
+        return Ok(());
+    };
+    let locale = guild_locale(&guild);
+    let components = create_panel_message_components(locale);
+    let embeds = [create_panel_message_embed(&context, &guild, locale)];
+    // A voice channel whose creation was rolled back is already gone again.
+    let panel_message_id =
+        match create_panel_message(&context, voice_channel_id, &components, &embeds).await {
+            Ok(panel_message_id) => panel_message_id,
+            Err(report) if report.downcast_ref::<HttpError>().is_some_and(is_not_found) => {
+                return Ok(());
+            }
+            Err(report) => return Err(report),
+        };
+    let connected_user_ids = context
+        .cache
+        .guild_voice_states(guild_id)
+        .into_iter()
+        .filter(|(_, channel_id)| *channel_id == voice_channel_id)
+        .map(|(user_id, _)| user_id)
+        .collect::<Vec<Id<UserMarker>>>();
+
+    context
+        .database
+        .insert_voice_channel(voice_channel_id, guild_id, parent_id, None)
+        .await?;
+    context
+        .database
+        .update_panel_message(voice_channel_id, Some(panel_message_id))
+        .await?;
+    context.cache.insert_voice_channel(
+        connected_user_ids,
+        guild_id,
+        voice_channel_id,
+        None,
+        Some(panel_message_id),
+        parent_id,
+        channel.permission_overwrites.unwrap_or_default(),
+    );
+
+    Ok(())
+}
@@ -1,36 +1,62 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use twilight_model::{channel::ChannelType, gateway::payload::incoming::ChannelUpdate};
+use twilight_model::{
+    channel::{Channel, ChannelType},
+    gateway::payload::incoming::ChannelUpdate,
+    id::{marker::ChannelMarker, Id},
+};
 
-use crate::structs::context::Context;
+use crate::structs::{cache::CachedJoinChannel, context::Context};
 
-pub fn run(context: Arc<Context>, payload: ChannelUpdate) -> Result<()> {
-    let channel_id = payload.0.id;
+pub async fn run(context: Arc<Context>, payload: ChannelUpdate) -> Result<()> {
+    sync_channel(&context, payload.0).await
+}
+
+// Position changes need no bookkeeping, so only overwrites and parents are synced.
+async fn sync_channel(context: &Context, channel: Channel) -> Result<()> {
+    let channel_id = channel.id;
 
-    match payload.0.kind {
+    match channel.kind {
         ChannelType::GuildCategory => {
             if let Some(category_channel) = context.cache.category_channel(channel_id) {
-                context.cache.insert_category_channel(
-                    category_channel.guild_id,
-                    category_channel.id,
-                    category_channel.join_channel_id.read().clone(),
-                    payload.0.permission_overwrites.unwrap_or_default(),
-                    category_channel.voice_channel_ids.read().clone(),
-                )
+                *category_channel.permission_overwrites.write() =
+                    channel.permission_overwrites.unwrap_or_default();
             }
         }
         ChannelType::GuildVoice => {
-            if let Some(voice_channel) = context.cache.voice_channel(channel_id) {
-                context.cache.insert_voice_channel(
-                    voice_channel.connected_user_ids.read().clone(),
-                    voice_channel.guild_id,
-                    voice_channel.id,
-                    voice_channel.owner_id.read().clone(),
-                    voice_channel.panel_message_id.read().clone(),
-                    voice_channel.parent_id,
-                    payload.0.permission_overwrites.unwrap_or_default(),
-                )
+            if let Some(join_channel) = context.cache.join_channel(channel_id) {
+                return sync_join_channel_parent(context, &join_channel, channel.parent_id).await;
+            }
+
+            let Some(voice_channel) = context.cache.voice_channel(channel_id) else {
+                return Ok(());
+            };
+
+            *voice_channel.permission_overwrites.write() =
+                channel.permission_overwrites.unwrap_or_default();
+
+            let parent_id = *voice_channel.parent_id.read();
+
+            match channel.parent_id {
+                Some(new_parent_id) if new_parent_id == parent_id => {}
+                Some(new_parent_id)
+                    if context.cache.category_channel(new_parent_id).is_some_and(
+                        |category_channel| category_channel.guild_id == voice_channel.guild_id,
+                    ) =>
+                {
+                    context
+                        .database
+                        .update_channels(voice_channel.guild_id, vec![(channel_id, new_parent_id)])
+                        .await?;
+                    context
+                        .cache
+                        .update_voice_channel_parent(channel_id, new_parent_id);
+                }
+                _ => {
+                    context.database.remove_voice_channel(channel_id).await?;
+                    context.cache.remove_voice_channel(channel_id);
+                }
             }
         }
         _ => {}
@@ -38,3 +64,39 @@ pub fn run(context: Arc<Context>, payload: ChannelUpdate) -> Result<()> {
 
     Ok(())
 }
+
+// Join channels keep working in any tracked category, but stop being join channels outside of one.
+async fn sync_join_channel_parent(
+    context: &Context,
+    join_channel: &CachedJoinChannel,
+    parent_id: Option<Id<ChannelMarker>>,
+) -> Result<()> {
+    match parent_id {
+        Some(parent_id) if parent_id == join_channel.category_channel_id => {}
+        Some(parent_id)
+            if context
+                .cache
+                .category_channel(parent_id)
+                .is_some_and(|category_channel| {
+                    category_channel.guild_id == join_channel.guild_id
+                }) =>
+        {
+            context
+                .database
+                .update_join_channel_category(join_channel.id, parent_id)
+                .await?;
+            context
+                .cache
+                .update_join_channel_category(join_channel.id, parent_id);
+        }
+        _ => {
+            context
+                .database
+                .remove_join_channel(join_channel.id)
+                .await?;
+            context.cache.remove_join_channel(join_channel.id);
+        }
+    }
+
+    Ok(())
+}
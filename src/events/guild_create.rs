@@ -1,35 +1,59 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
+use crate::utilities::request::ObserveRequest;
 use eyre::Result;
 use twilight_model::{
     channel::{
         permission_overwrite::PermissionOverwrite as ChannelPermissionOverwrite, ChannelType,
     },
     gateway::payload::incoming::GuildCreate,
-    id::{
-        marker::{ChannelMarker, UserMarker},
-        Id,
-    },
+    id::{marker::ChannelMarker, Id},
 };
 
-use crate::structs::context::Context;
+use crate::structs::{context::Context, error::Error};
 
 pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
-    let guild_id = payload.0.id;
-    let Some(bot_role) = payload.0.roles.into_iter().find(|role| {
+    let guild = match payload {
+        GuildCreate::Available(guild) => guild,
+        GuildCreate::Unavailable(unavailable_guild) => {
+            context.cache.insert_unavailable_guild(unavailable_guild.id);
+
+            return Ok(());
+        }
+    };
+    let guild_id = guild.id;
+    let Some(bot_role) = guild.roles.into_iter().find(|role| {
         role.tags.as_ref().is_some_and(|tags| {
             tags.bot_id
                 .is_some_and(|bot_id| bot_id.eq(&context.application_id.cast()))
         })
     }) else {
-        context.client.leave_guild(guild_id).await?;
+        context
+            .client
+            .leave_guild(guild_id)
+            .observe(&context)
+            .await?;
 
         return Ok(());
     };
 
-    context.database.insert_guild(guild_id).await?;
+    context
+        .database
+        .insert_guild(
+            guild_id,
+            context.config.defaults.permanence,
+            &context.config.defaults.privacy,
+        )
+        .await?;
 
-    let database_guild = context.database.guild(guild_id).await?.unwrap();
+    let database_guild = context
+        .database
+        .guild(guild_id)
+        .await?
+        .ok_or(Error::MissingData("database guild"))?;
 
     context.cache.insert_guild(
         guild_id,
@@ -37,7 +61,18 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
         database_guild.permanence,
         database_guild.privacy,
     );
-
+    context.cache.update_locale(guild_id, database_guild.locale);
+    context
+        .cache
+        .update_appearance(guild_id, database_guild.appearance);
+
+    let stored_category_channel_ids = context
+        .database
+        .guild_category_channels(guild_id)
+        .await?
+        .into_iter()
+        .map(|database_category_channel| database_category_channel.id)
+        .collect::<HashSet<Id<ChannelMarker>>>();
     let mut category_channel_permission_overwrites_map: HashMap<
         Id<ChannelMarker>,
         Vec<ChannelPermissionOverwrite>,
@@ -47,8 +82,7 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
         Vec<ChannelPermissionOverwrite>,
     > = HashMap::new();
     let mut voice_channel_and_parent_ids: Vec<(Id<ChannelMarker>, Id<ChannelMarker>)> = Vec::new();
-    let category_and_voice_channel_ids = payload
-        .0
+    let category_and_voice_channel_ids = guild
         .channels
         .iter()
         .filter_map(|channel| {
@@ -64,13 +98,16 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
                     Some(channel_id)
                 }
                 ChannelType::GuildVoice => {
-                    if let Some(parent_id) = channel.parent_id {
-                        voice_channel_permission_overwrites_map.insert(
-                            channel_id,
-                            channel.permission_overwrites.clone().unwrap_or_default(),
-                        );
-                        voice_channel_and_parent_ids.push((channel_id, parent_id));
-                    }
+                    // Voice channels moved out of every managed category stop being managed.
+                    let parent_id = channel
+                        .parent_id
+                        .filter(|parent_id| stored_category_channel_ids.contains(parent_id))?;
+
+                    voice_channel_permission_overwrites_map.insert(
+                        channel_id,
+                        channel.permission_overwrites.clone().unwrap_or_default(),
+                    );
+                    voice_channel_and_parent_ids.push((channel_id, parent_id));
 
                     Some(channel_id)
                 }
@@ -85,6 +122,11 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
             .remove_channels(guild_id, category_and_voice_channel_ids)
             .await?;
     }
+    let voice_channel_parent_id_map = voice_channel_and_parent_ids
+        .iter()
+        .copied()
+        .collect::<HashMap<Id<ChannelMarker>, Id<ChannelMarker>>>();
+
     if !voice_channel_and_parent_ids.is_empty() {
         context
             .database
@@ -94,6 +136,23 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
 
     let database_guild_category_channels =
         context.database.guild_category_channels(guild_id).await?;
+    let mut database_guild_join_channels = context.database.guild_join_channels(guild_id).await?;
+
+    // Join channels moved to another managed category while offline follow it.
+    for database_guild_join_channel in &mut database_guild_join_channels {
+        let Some(&parent_id) = voice_channel_parent_id_map.get(&database_guild_join_channel.id)
+        else {
+            continue;
+        };
+
+        if parent_id != database_guild_join_channel.category_channel_id {
+            context
+                .database
+                .update_join_channel_category(database_guild_join_channel.id, parent_id)
+                .await?;
+            database_guild_join_channel.category_channel_id = parent_id;
+        }
+    }
     let database_guild_voice_channels = context.database.guild_voice_channels(guild_id).await?;
     let database_guild_voice_channel_map: HashMap<Id<ChannelMarker>, Vec<Id<ChannelMarker>>> =
         database_guild_voice_channels.iter().fold(
@@ -102,32 +161,11 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
                 let parent_id = database_guild_voice_channel.parent_id;
                 let channel_id = database_guild_voice_channel.id;
 
-                if !acc.contains_key(&parent_id) {
-                    acc.insert(parent_id, vec![channel_id]);
-                } else if let Some(category_voice_channels) = acc.get_mut(&parent_id) {
-                    category_voice_channels.push(channel_id);
-                };
+                acc.entry(parent_id).or_default().push(channel_id);
 
                 acc
             },
         );
-    let voice_state_map: HashMap<Id<ChannelMarker>, Vec<Id<UserMarker>>> = payload
-        .0
-        .voice_states
-        .iter()
-        .fold(HashMap::new(), |mut acc, voice_state| {
-            if let Some(channel_id) = voice_state.channel_id {
-                let user_id = voice_state.user_id;
-
-                if !acc.contains_key(&channel_id) {
-                    acc.insert(channel_id, vec![user_id]);
-                } else if let Some(user_ids_in_channel) = acc.get_mut(&channel_id) {
-                    user_ids_in_channel.push(user_id);
-                };
-            }
-
-            acc
-        });
 
     for database_guild_category_channel in database_guild_category_channels {
         let channel_id = database_guild_category_channel.id;
@@ -143,25 +181,26 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
         context.cache.insert_category_channel(
             guild_id,
             channel_id,
-            database_guild_category_channel.join_channel_id,
             permission_overwrites,
             voice_channel_ids,
         );
     }
 
+    for database_guild_join_channel in database_guild_join_channels {
+        context
+            .cache
+            .insert_join_channel(database_guild_join_channel.into());
+    }
+
     for database_guild_voice_channel in database_guild_voice_channels {
         let channel_id = database_guild_voice_channel.id;
-        let connected_user_ids = voice_state_map
-            .get(&channel_id)
-            .cloned()
-            .unwrap_or_default();
         let permission_overwrites = voice_channel_permission_overwrites_map
             .get(&channel_id)
             .cloned()
             .unwrap_or_default();
 
         context.cache.insert_voice_channel(
-            connected_user_ids,
+            [],
             guild_id,
             channel_id,
             database_guild_voice_channel.owner_id,
@@ -171,5 +210,16 @@ pub async fn run(context: Arc<Context>, payload: GuildCreate) -> Result<()> {
         );
     }
 
+    // The guild's voice states replace whatever a snapshot restored, and fill in occupancy.
+    context.cache.clear_guild_voice_states(guild_id);
+
+    for voice_state in &guild.voice_states {
+        if let Some(channel_id) = voice_state.channel_id {
+            context
+                .cache
+                .insert_voice_state(guild_id, channel_id, voice_state.user_id);
+        }
+    }
+
     Ok(())
 }
@@ -10,7 +10,7 @@ pub async fn run(context: Arc<Context>, payload: GuildDelete) -> Result<()> {
 
     context.cache.remove_guild(guild_id);
 
-    if !payload.unavailable {
+    if !payload.unavailable.unwrap_or_default() {
         context.database.remove_guild(guild_id).await?;
     }
 
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use eyre::Result;
+use tracing::{info_span, Instrument};
 use twilight_model::{
     application::interaction::InteractionData, gateway::payload::incoming::InteractionCreate,
 };
@@ -9,11 +10,19 @@ use crate::{
     interactions::*,
     structs::{
         context::Context,
+        error::Error,
         interaction::{
-            ApplicationCommandInteraction, MessageComponentInteraction, ModalSubmitInteraction,
+            ApplicationCommandInteraction, GuildMessageComponentInteraction,
+            MessageComponentInteraction, ModalSubmitInteraction,
         },
     },
-    utilities::interaction::{check_interaction, create_interaction_response_embed},
+    utilities::{
+        interaction::{
+            check_interaction, create_interaction_response_embed, custom_id_name, embed_color,
+            interaction_locale,
+        },
+        request::ObserveRequest,
+    },
 };
 
 async fn handle_application_command(
@@ -23,17 +32,24 @@ async fn handle_application_command(
     let application_command_name = interaction.data.name.as_str();
 
     match application_command_name {
+        "admin" => admin::run(context, interaction).await?,
         "create" => create::run(context, interaction).await?,
         "settings" => settings::run(context, interaction).await?,
+        "template" => template::run(context, interaction).await?,
         _ => {
             let interaction_response = create_interaction_response_embed(
-                format!("I don't have a command with the name \"{application_command_name}\"!"),
+                interaction.color,
+                interaction.locale.format(
+                    "interaction.unknown_command",
+                    &[("name", &application_command_name)],
+                ),
                 true,
             );
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
         }
     }
@@ -42,15 +58,48 @@ async fn handle_application_command(
 }
 
 pub async fn run(context: Arc<Context>, payload: InteractionCreate) -> Result<()> {
+    let span = info_span!(
+        "interaction",
+        id = payload.id.get(),
+        kind = ?payload.kind,
+        name = interaction_name(&payload.data),
+    );
+
+    let _timer = context
+        .metrics
+        .interaction_duration
+        .with_label_values(&[interaction_name(&payload.data).unwrap_or("unknown")])
+        .start_timer();
+
+    handle_interaction(Arc::clone(&context), payload)
+        .instrument(span)
+        .await
+}
+
+fn interaction_name(data: &Option<InteractionData>) -> Option<&str> {
+    match data {
+        Some(InteractionData::ApplicationCommand(data)) => Some(data.name.as_str()),
+        Some(InteractionData::MessageComponent(data)) => Some(custom_id_name(&data.custom_id)),
+        Some(InteractionData::ModalSubmit(data)) => Some(data.custom_id.as_str()),
+        _ => None,
+    }
+}
+
+async fn handle_interaction(context: Arc<Context>, payload: InteractionCreate) -> Result<()> {
     let interaction = payload.0;
+    let user_id = interaction.author_id();
+    let locale = interaction_locale(&context, &interaction);
+    let color = embed_color(&context, interaction.guild_id);
 
-    let (guild, voice_channel) = match check_interaction(&context, &interaction) {
+    let (guild, voice_channel) = match check_interaction(&context, &interaction, locale) {
         Err(report) => {
-            let interaction_response = create_interaction_response_embed(report.to_string(), true);
+            let interaction_response =
+                create_interaction_response_embed(color, report.to_string(), true);
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
 
             return Ok(());
@@ -61,20 +110,36 @@ pub async fn run(context: Arc<Context>, payload: InteractionCreate) -> Result<()
     match (interaction.data, guild, voice_channel) {
         (Some(InteractionData::ApplicationCommand(data)), Some(guild), None) => {
             let interaction = ApplicationCommandInteraction {
+                color,
                 data,
                 guild,
                 id: interaction.id,
+                locale,
                 token: interaction.token,
             };
 
             handle_application_command(context, interaction).await?;
         }
+        (Some(InteractionData::MessageComponent(data)), Some(guild), None) => {
+            let interaction = GuildMessageComponentInteraction {
+                color,
+                data,
+                guild,
+                id: interaction.id,
+                locale,
+                token: interaction.token,
+            };
+
+            handle_guild_message_component(context, interaction).await?;
+        }
         (Some(InteractionData::MessageComponent(data)), None, Some(voice_channel)) => {
             let interaction = MessageComponentInteraction {
+                color,
                 data,
                 id: interaction.id,
+                locale,
                 token: interaction.token,
-                user_id: interaction.member.unwrap().user.unwrap().id,
+                user_id: user_id.ok_or(Error::MissingData("interaction author"))?,
                 voice_channel,
             };
 
@@ -82,8 +147,10 @@ pub async fn run(context: Arc<Context>, payload: InteractionCreate) -> Result<()
         }
         (Some(InteractionData::ModalSubmit(data)), None, Some(voice_channel)) => {
             let interaction = ModalSubmitInteraction {
+                color,
                 data,
                 id: interaction.id,
+                locale,
                 token: interaction.token,
                 voice_channel,
             };
@@ -92,13 +159,45 @@ pub async fn run(context: Arc<Context>, payload: InteractionCreate) -> Result<()
         }
         _ => {
             let interaction_response = create_interaction_response_embed(
-                "I don't recognize this interaction.".to_owned(),
+                color,
+                locale.text("interaction.unknown_interaction").to_owned(),
+                true,
+            );
+
+            context
+                .interaction_client()
+                .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_guild_message_component(
+    context: Arc<Context>,
+    interaction: GuildMessageComponentInteraction,
+) -> Result<()> {
+    let message_component_name = custom_id_name(&interaction.data.custom_id);
+
+    match message_component_name {
+        "import-apply-button" => import_apply_button::run(context, interaction).await?,
+        "import-cancel-button" => import_cancel_button::run(context, interaction).await?,
+        _ => {
+            let interaction_response = create_interaction_response_embed(
+                interaction.color,
+                interaction.locale.format(
+                    "interaction.unknown_component",
+                    &[("name", &message_component_name)],
+                ),
                 true,
             );
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
         }
     }
@@ -114,6 +213,7 @@ async fn handle_message_component(
 
     match message_component_name {
         "allow-member-select" => allow_member_select::run(context, interaction).await?,
+        "apply-template-select" => apply_template_select::run(context, interaction).await?,
         "deny-member-select" => deny_member_select::run(context, interaction).await?,
         "edit-channel-select" => edit_channel_select::run(context, interaction).await?,
         "kick-member-select" => kick_member_select::run(context, interaction).await?,
@@ -126,13 +226,18 @@ async fn handle_message_component(
         "transfer-select" => transfer_select::run(context, interaction).await?,
         _ => {
             let interaction_response = create_interaction_response_embed(
-                format!("I don't have a component with the name \"{message_component_name}\"!"),
+                interaction.color,
+                interaction.locale.format(
+                    "interaction.unknown_component",
+                    &[("name", &message_component_name)],
+                ),
                 true,
             );
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
         }
     }
@@ -152,13 +257,17 @@ async fn handle_modal_submit(
         "modify-user-limit-modal" => modify_user_limit_modal::run(context, interaction).await?,
         _ => {
             let interaction_response = create_interaction_response_embed(
-                format!("I don't have a modal with the name \"{modal_submit_name}\"!"),
+                interaction.color,
+                interaction
+                    .locale
+                    .format("interaction.unknown_modal", &[("name", &modal_submit_name)]),
                 true,
             );
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
         }
     }
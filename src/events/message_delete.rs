@@ -9,7 +9,7 @@ pub async fn run(context: Arc<Context>, payload: MessageDelete) -> Result<()> {
     let Some(voice_channel) = context.cache.voice_channel(payload.channel_id) else {
         return Ok(());
     };
-    let Some(panel_message_id) = voice_channel.panel_message_id.read().clone() else {
+    let Some(panel_message_id) = *voice_channel.panel_message_id.read() else {
         return Ok(());
     };
 
@@ -1,3 +1,4 @@
+mod channel_create;
 mod channel_delete;
 mod channel_update;
 mod guild_create;
@@ -12,14 +13,55 @@ mod voice_state_update;
 use std::sync::Arc;
 
 use eyre::Result;
+use tracing::{info_span, Instrument};
 use twilight_gateway::Event;
 
-use crate::structs::context::Context;
+use crate::{
+    structs::{context::Context, error::Error},
+    utilities::{
+        error::{panic_message, report_error, ErrorSource},
+        interaction::interaction_locale,
+    },
+};
 
-pub async fn handle_event(context: Arc<Context>, event: Event) -> Result<()> {
+pub use self::voice_state_update::recover_voice_channel_creations;
+
+pub async fn handle_event(context: Arc<Context>, event: Event) {
+    let source = ErrorSource {
+        event_type: event.kind(),
+        guild_id: event.guild_id(),
+        interaction: match &event {
+            Event::InteractionCreate(payload) => Some((
+                payload.id,
+                payload.token.clone(),
+                interaction_locale(&context, payload),
+            )),
+            _ => None,
+        },
+    };
+    let span = info_span!(
+        "event",
+        kind = ?source.event_type,
+        guild_id = source.guild_id.map(|guild_id| guild_id.get()),
+    );
+    let error = match tokio::spawn(run(Arc::clone(&context), event).instrument(span.clone())).await
+    {
+        Ok(Ok(())) => return,
+        Ok(Err(report)) => Error::from(report),
+        Err(join_error) if join_error.is_panic() => {
+            Error::Panic(panic_message(join_error.into_panic()))
+        }
+        Err(join_error) => Error::Unknown(join_error.into()),
+    };
+
+    report_error(&context, source, error).instrument(span).await;
+}
+
+async fn run(context: Arc<Context>, event: Event) -> Result<()> {
     match event {
+        Event::ChannelCreate(payload) => channel_create::run(context, *payload).await,
         Event::ChannelDelete(payload) => channel_delete::run(context, *payload).await,
-        Event::ChannelUpdate(payload) => channel_update::run(context, *payload),
+        Event::ChannelUpdate(payload) => channel_update::run(context, *payload).await,
         Event::GuildCreate(payload) => guild_create::run(context, *payload).await,
         Event::GuildDelete(payload) => guild_delete::run(context, payload).await,
         Event::InteractionCreate(payload) => interaction_create::run(context, *payload).await,
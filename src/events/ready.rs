@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use eyre::Result;
+use tracing::info;
 use twilight_model::gateway::payload::incoming::Ready;
 
 use crate::structs::context::Context;
@@ -10,7 +11,7 @@ pub fn run(context: Arc<Context>, payload: Ready) -> Result<()> {
         context.cache.insert_unavailable_guild(unvailable_guild.id);
     }
 
-    println!(
+    info!(
         "{}#{:04} is ready!",
         payload.user.name, payload.user.discriminator
     );
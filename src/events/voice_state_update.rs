@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc, time::Instant};
 
 use twilight_model::{
     channel::{
@@ -6,19 +6,36 @@ use twilight_model::{
             PermissionOverwrite as ChannelPermissionOverwrite,
             PermissionOverwriteType as ChannelPermissionOverwriteType,
         },
-        ChannelType,
+        Channel, ChannelType,
     },
     gateway::payload::incoming::VoiceStateUpdate,
     guild::Permissions,
-    id::{marker::GenericMarker, Id},
+    id::{
+        marker::{ChannelMarker, GenericMarker, UserMarker},
+        Id,
+    },
 };
 
 use crate::{
-    structs::context::Context,
-    utilities::constants::{PANEL_MESSAGE_COMPONENTS, PANEL_MESSAGE_EMBED},
+    structs::{
+        cache::{CachedGuild, CachedJoinChannel},
+        context::Context,
+        database::DatabaseVoiceChannelCreation,
+        saga::Saga,
+    },
+    utilities::{
+        consistency::guild_channels,
+        interaction::{
+            create_panel_message, create_panel_message_components, create_panel_message_embed,
+            guild_locale,
+        },
+        permission::merge_permission_overwrites,
+        request::{is_not_found, retry, retry_create, ObserveRequest},
+    },
 };
 
 use eyre::Result;
+use tracing::{info, warn};
 
 pub async fn run(context: Arc<Context>, payload: VoiceStateUpdate) -> Result<()> {
     let Some(guild_id) = payload.guild_id else {
@@ -32,13 +49,17 @@ pub async fn run(context: Arc<Context>, payload: VoiceStateUpdate) -> Result<()>
     if let Some(old_channel_id) = context.cache.voice_state(guild_id, user_id) {
         context.cache.remove_voice_state(guild_id, user_id);
 
-        if guild.permanence.read().clone() {
+        if *guild.permanence.read() {
             return Ok(());
         };
 
         if let Some(old_channel) = context.cache.voice_channel(*old_channel_id) {
             if old_channel.connected_user_ids.read().is_empty() {
-                _ = context.client.delete_channel(*old_channel_id).await;
+                _ = context
+                    .client
+                    .delete_channel(*old_channel_id)
+                    .observe(&context)
+                    .await;
             }
         }
     }
@@ -55,32 +76,54 @@ pub async fn run(context: Arc<Context>, payload: VoiceStateUpdate) -> Result<()>
             return Ok(());
         }
 
-        let category_channel = guild.category_channel_ids.read().iter().find_map(|id| {
-            let Some(category_channel) = context.cache.category_channel(*id) else {
-                return None;
-            };
-            let Some(join_channel_id) = category_channel.join_channel_id.read().clone() else {
-                return None;
-            };
-
-            join_channel_id
-                .eq(&new_channel_id)
-                .then_some(category_channel)
-        });
-        let Some(category_channel) = category_channel else {
+        let Some(mut join_channel) = context
+            .cache
+            .join_channel(new_channel_id)
+            .filter(|join_channel| join_channel.guild_id == guild_id)
+            .as_deref()
+            .cloned()
+        else {
+            return Ok(());
+        };
+        let Some(category_channel) = context
+            .cache
+            .category_channel(join_channel.category_channel_id)
+        else {
             return Ok(());
         };
 
         let Some(member) = payload.0.member else {
             return Ok(());
         };
-        let channel_name = if member.user.name.ends_with("s") {
-            format!("{}' voice", member.user.name)
-        } else {
-            format!("{}'s voice", member.user.name)
+        let locale = guild_locale(&guild);
+        let channel_name = match &join_channel.name_template {
+            Some(name_template) => name_template.replace("{user}", &member.user.name),
+            None if member.user.name.ends_with("s") => locale.format(
+                "channels.voice_channel_after_s",
+                &[("user", &member.user.name)],
+            ),
+            None => locale.format("channels.voice_channel", &[("user", &member.user.name)]),
         };
 
         let mut permission_overwrites = category_channel.permission_overwrites.read().clone();
+
+        if let Some(template_name) = join_channel.template_name.clone() {
+            if let Some(template) = context
+                .database
+                .channel_template(guild_id, template_name)
+                .await?
+            {
+                join_channel.bitrate = join_channel.bitrate.or(template.bitrate);
+                join_channel.privacy = join_channel.privacy.or(template.privacy);
+                join_channel.user_limit = join_channel.user_limit.or(template.user_limit);
+
+                merge_permission_overwrites(
+                    &mut permission_overwrites,
+                    template.permission_overwrites,
+                );
+            }
+        }
+
         let everyone_role_id: Id<GenericMarker> = guild_id.cast();
         let (mut voice_channel_owner_allow, mut voice_channel_owner_deny): (
             Permissions,
@@ -88,7 +131,10 @@ pub async fn run(context: Arc<Context>, payload: VoiceStateUpdate) -> Result<()>
         ) = (Permissions::empty(), Permissions::empty());
         let (mut everyone_allow, mut everyone_deny): (Permissions, Permissions) =
             (Permissions::empty(), Permissions::empty());
-        let privacy_option = guild.privacy.read().clone();
+        let privacy_option = join_channel
+            .privacy
+            .clone()
+            .unwrap_or_else(|| guild.privacy.read().clone());
 
         permission_overwrites.retain(|permission_overwrite| match permission_overwrite.kind {
             ChannelPermissionOverwriteType::Member
@@ -144,53 +190,236 @@ pub async fn run(context: Arc<Context>, payload: VoiceStateUpdate) -> Result<()>
             },
         ]);
 
-        if let Ok(created_voice_channel_response) = context
-            .client
-            .create_guild_channel(guild_id, &channel_name)
-            .kind(ChannelType::GuildVoice)
-            .parent_id(category_channel.id)
-            .permission_overwrites(&permission_overwrites)
-            .await
+        let creation_started_at = Instant::now();
+        let mut saga = Saga::new();
+
+        if let Err(error) = create_voice_channel(
+            &context,
+            &mut saga,
+            &guild,
+            user_id,
+            &join_channel,
+            &channel_name,
+            &permission_overwrites,
+        )
+        .await
         {
-            let created_voice_channel = created_voice_channel_response.model().await?;
-            let panel_message_id = Some(
-                context
-                    .client
-                    .create_message(created_voice_channel.id)
-                    .components(&*PANEL_MESSAGE_COMPONENTS)
-                    .embeds(&[PANEL_MESSAGE_EMBED.clone()])
-                    .await?
-                    .model()
+            saga.roll_back().await;
+
+            return Err(error);
+        }
+
+        context
+            .metrics
+            .voice_channel_creation_duration
+            .observe(creation_started_at.elapsed().as_secs_f64());
+        context.metrics.voice_channels_created.inc();
+    }
+
+    Ok(())
+}
+
+// Creations cut short by a restart are undone. One that never got its channel back may still
+// have created it, so the channel is looked up like a failed request would.
+pub async fn recover_voice_channel_creations(context: &Arc<Context>) -> Result<()> {
+    for voice_channel_creation in context.database.voice_channel_creations().await? {
+        let guild_id = voice_channel_creation.guild_id;
+        let owner_id = voice_channel_creation.owner_id;
+        let result = async {
+            let voice_channel_id = match voice_channel_creation.id {
+                Some(voice_channel_id) => Some(voice_channel_id),
+                None => find_created_voice_channel(context, &voice_channel_creation)
                     .await?
-                    .id,
-            );
-            context
-                .client
-                .update_guild_member(guild_id, user_id)
-                .channel_id(Some(created_voice_channel.id))
-                .await?;
+                    .map(|voice_channel| voice_channel.id),
+            };
+
+            if let Some(voice_channel_id) = voice_channel_id {
+                remove_created_voice_channel(Arc::clone(context), voice_channel_id).await?;
+            }
+
             context
                 .database
-                .insert_voice_channel(
-                    created_voice_channel.id,
-                    guild_id,
-                    category_channel.id,
-                    user_id,
-                )
-                .await?;
-            context.cache.insert_voice_channel(
-                None,
-                guild_id,
-                created_voice_channel.id,
-                Some(user_id),
-                panel_message_id,
-                category_channel.id,
-                created_voice_channel
-                    .permission_overwrites
-                    .unwrap_or_default(),
-            );
+                .remove_voice_channel_creation(guild_id, owner_id)
+                .await
+        }
+        .await;
+
+        if let Err(source) = result {
+            warn!(%guild_id, %owner_id, ?source, "Unable to remove incomplete voice channel");
+        } else {
+            info!(%guild_id, %owner_id, "Removed incomplete voice channel");
         }
     }
 
     Ok(())
 }
+
+async fn create_voice_channel(
+    context: &Arc<Context>,
+    saga: &mut Saga,
+    guild: &CachedGuild,
+    user_id: Id<UserMarker>,
+    join_channel: &CachedJoinChannel,
+    name: &str,
+    permission_overwrites: &[ChannelPermissionOverwrite],
+) -> Result<()> {
+    let guild_id = guild.id;
+    let parent_id = join_channel.category_channel_id;
+    let voice_channel_creation = DatabaseVoiceChannelCreation {
+        guild_id,
+        id: None,
+        name: name.to_owned(),
+        owner_id: user_id,
+        parent_id,
+    };
+
+    context
+        .database
+        .insert_voice_channel_creation(voice_channel_creation.clone())
+        .await?;
+    saga.compensate("record voice channel creation", {
+        let context = Arc::clone(context);
+
+        async move {
+            context
+                .database
+                .remove_voice_channel_creation(guild_id, user_id)
+                .await
+        }
+    });
+
+    let voice_channel = retry_create(
+        || {
+            let mut request = context
+                .client
+                .create_guild_channel(guild_id, name)
+                .kind(ChannelType::GuildVoice)
+                .parent_id(parent_id)
+                .permission_overwrites(permission_overwrites);
+
+            if let Some(bitrate) = join_channel.bitrate {
+                request = request.bitrate(bitrate);
+            }
+
+            if let Some(user_limit) = join_channel.user_limit {
+                request = request.user_limit(user_limit);
+            }
+
+            request.observe(context)
+        },
+        || find_created_voice_channel(context, &voice_channel_creation),
+    )
+    .await?;
+    let voice_channel_id = voice_channel.id;
+
+    saga.compensate(
+        "create voice channel",
+        remove_created_voice_channel(Arc::clone(context), voice_channel_id),
+    );
+    context
+        .database
+        .update_voice_channel_creation(guild_id, user_id, voice_channel_id)
+        .await?;
+
+    let locale = guild_locale(guild);
+    let components = create_panel_message_components(locale);
+    let embeds = [create_panel_message_embed(context, guild, locale)];
+    let panel_message_id =
+        create_panel_message(context, voice_channel_id, &components, &embeds).await?;
+
+    retry(|| {
+        context
+            .client
+            .update_guild_member(guild_id, user_id)
+            .channel_id(Some(voice_channel_id))
+            .observe(context)
+    })
+    .await?;
+    context
+        .database
+        .insert_voice_channel(voice_channel_id, guild_id, parent_id, Some(user_id))
+        .await?;
+    context
+        .database
+        .update_panel_message(voice_channel_id, Some(panel_message_id))
+        .await?;
+    context
+        .database
+        .remove_voice_channel_creation(guild_id, user_id)
+        .await?;
+    context.cache.insert_voice_channel(
+        None,
+        guild_id,
+        voice_channel_id,
+        Some(user_id),
+        Some(panel_message_id),
+        parent_id,
+        voice_channel.permission_overwrites.unwrap_or_default(),
+    );
+
+    Ok(())
+}
+
+async fn remove_created_voice_channel(
+    context: Arc<Context>,
+    voice_channel_id: Id<ChannelMarker>,
+) -> Result<()> {
+    match retry(|| {
+        context
+            .client
+            .delete_channel(voice_channel_id)
+            .observe(&context)
+    })
+    .await
+    {
+        Err(source) if !is_not_found(&source) => return Err(source.into()),
+        _ => {}
+    }
+
+    context
+        .database
+        .remove_voice_channel(voice_channel_id)
+        .await
+}
+
+// Only one voice channel is created per guild at a time, so an untracked voice channel with the
+// requested name and category that grants its owner a member overwrite can only come from it.
+// Tracked channels are read from the database, since recovery runs before the cache is filled.
+async fn find_created_voice_channel(
+    context: &Context,
+    voice_channel_creation: &DatabaseVoiceChannelCreation,
+) -> Result<Option<Channel>> {
+    let guild_id = voice_channel_creation.guild_id;
+    let owner_id = voice_channel_creation.owner_id.cast::<GenericMarker>();
+    let tracked_channel_ids = context
+        .database
+        .guild_voice_channels(guild_id)
+        .await?
+        .into_iter()
+        .map(|database_voice_channel| database_voice_channel.id)
+        .chain(
+            context
+                .database
+                .guild_join_channels(guild_id)
+                .await?
+                .into_iter()
+                .map(|database_join_channel| database_join_channel.id),
+        )
+        .collect::<HashSet<Id<ChannelMarker>>>();
+    let channels = guild_channels(context, guild_id).await?;
+
+    Ok(channels.into_values().find(|channel| {
+        channel.kind == ChannelType::GuildVoice
+            && channel.parent_id == Some(voice_channel_creation.parent_id)
+            && channel.name.as_deref() == Some(voice_channel_creation.name.as_str())
+            && channel
+                .permission_overwrites
+                .iter()
+                .flatten()
+                .any(|permission_overwrite| {
+                    permission_overwrite.kind == ChannelPermissionOverwriteType::Member
+                        && permission_overwrite.id == owner_id
+                })
+            && !tracked_channel_ids.contains(&channel.id)
+    }))
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use crate::{
+    structs::{context::Context, interaction::ApplicationCommandInteraction},
+    utilities::{
+        consistency::run_check,
+        interaction::{create_deferred_interaction_response, create_response_embed},
+        request::ObserveRequest,
+    },
+};
+
+const MAX_LISTED_INCONSISTENCIES: usize = 20;
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let repair = matches!(
+        interaction.data.options.first().map(|option| &option.value),
+        Some(CommandOptionValue::Boolean(true))
+    );
+    let inconsistencies = run_check(&context, interaction.guild.id, repair).await?;
+    let locale = interaction.locale;
+    let description = if inconsistencies.is_empty() {
+        locale.text("admin.check.consistent").to_owned()
+    } else {
+        let mut lines = inconsistencies
+            .iter()
+            .take(MAX_LISTED_INCONSISTENCIES)
+            .map(|inconsistency| format!("- {}", inconsistency.description(locale)))
+            .collect::<Vec<String>>();
+
+        if inconsistencies.len() > MAX_LISTED_INCONSISTENCIES {
+            lines.push(locale.format(
+                "admin.check.more",
+                &[(
+                    "count",
+                    &(inconsistencies.len() - MAX_LISTED_INCONSISTENCIES),
+                )],
+            ));
+        }
+
+        let outcome = if repair {
+            locale.text("admin.check.repaired")
+        } else {
+            locale.text("admin.check.repair_hint")
+        };
+
+        format!("{}\n\n{outcome}", lines.join("\n"))
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
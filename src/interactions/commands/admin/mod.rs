@@ -0,0 +1,63 @@
+pub mod check;
+
+use std::{mem::replace, sync::Arc};
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::{
+    CommandDataOption, CommandOptionValue,
+};
+
+use crate::{
+    structs::{context::Context, error::Error, interaction::ApplicationCommandInteraction},
+    utilities::{interaction::create_interaction_response_embed, request::ObserveRequest},
+};
+
+pub async fn run(
+    context: Arc<Context>,
+    mut interaction: ApplicationCommandInteraction,
+) -> Result<()> {
+    let command_options = interaction.data.options.clone();
+    let CommandDataOption { name, value } = command_options
+        .first()
+        .ok_or(Error::MissingData("subcommand"))?;
+    let CommandOptionValue::SubCommand(options) = value.clone() else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
+
+        context
+            .interaction_client()
+            .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
+            .await?;
+
+        return Ok(());
+    };
+    let _ = replace(&mut interaction.data.options, options);
+
+    match name.as_str() {
+        "check" => check::run(context, interaction).await?,
+        _ => {
+            let interaction_response = create_interaction_response_embed(
+                interaction.color,
+                interaction
+                    .locale
+                    .format("interaction.unknown_subcommand", &[("name", &name)]),
+                true,
+            );
+
+            context
+                .interaction_client()
+                .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use std::{collections::HashSet, sync::Arc};
+
+use eyre::Result;
+use twilight_model::{
+    application::interaction::application_command::CommandOptionValue,
+    channel::{Channel, ChannelType},
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::insert_join_channel;
+use crate::{
+    structs::{
+        config::MAX_CATEGORY_CHANNELS, context::Context, database::DatabaseJoinChannel,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_interaction_response_embed,
+            create_panel_message, create_panel_message_components, create_panel_message_embed,
+            create_response_embed, guild_locale, option_value,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let options = &interaction.data.options;
+    let Some(CommandOptionValue::Channel(category_channel_id)) = option_value(options, "category")
+    else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
+
+        context
+            .interaction_client()
+            .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
+            .await?;
+
+        return Ok(());
+    };
+    let chosen_owner_id = match option_value(options, "owner") {
+        Some(CommandOptionValue::User(user_id)) => Some(user_id),
+        _ => None,
+    };
+    let chosen_join_channel_id = match option_value(options, "join-channel") {
+        Some(CommandOptionValue::Channel(channel_id)) => Some(channel_id),
+        _ => None,
+    };
+    let locale = interaction.locale;
+    let description = if context
+        .cache
+        .category_channel(category_channel_id)
+        .is_some()
+    {
+        locale.text("create.adopt.already_managed").to_owned()
+    } else if interaction.guild.category_channel_ids.read().len() >= MAX_CATEGORY_CHANNELS {
+        locale.text("create.maximum_categories").to_owned()
+    } else {
+        let channels = context
+            .client
+            .guild_channels(interaction.guild.id)
+            .observe(&context)
+            .await?
+            .models()
+            .await?;
+        let category_channel = channels
+            .iter()
+            .find(|channel| channel.id == category_channel_id);
+        let voice_channels = channels
+            .iter()
+            .filter(|channel| {
+                channel.kind == ChannelType::GuildVoice
+                    && channel.parent_id == Some(category_channel_id)
+            })
+            .collect::<Vec<&Channel>>();
+
+        if let Some(category_channel) = category_channel {
+            if chosen_join_channel_id.is_some_and(|channel_id| {
+                !voice_channels
+                    .iter()
+                    .any(|voice_channel| voice_channel.id == channel_id)
+            }) {
+                locale.text("create.adopt.invalid_join_channel").to_owned()
+            } else {
+                let join_channel_id = match chosen_join_channel_id {
+                    Some(channel_id) => Some(channel_id),
+                    None => match context
+                        .client
+                        .create_guild_channel(
+                            interaction.guild.id,
+                            guild_locale(&interaction.guild).text("channels.join_channel"),
+                        )
+                        .kind(ChannelType::GuildVoice)
+                        .parent_id(category_channel_id)
+                        .position(0)
+                        .observe(&context)
+                        .await
+                    {
+                        Ok(response) => Some(response.model().await?.id),
+                        Err(_) => None,
+                    },
+                };
+
+                context
+                    .database
+                    .insert_category_channel(category_channel_id, interaction.guild.id)
+                    .await?;
+                context.cache.insert_category_channel(
+                    interaction.guild.id,
+                    category_channel_id,
+                    category_channel
+                        .permission_overwrites
+                        .clone()
+                        .unwrap_or_default(),
+                    [],
+                );
+
+                if let Some(join_channel_id) = join_channel_id {
+                    insert_join_channel(
+                        &context,
+                        DatabaseJoinChannel::new(
+                            join_channel_id,
+                            interaction.guild.id,
+                            category_channel_id,
+                        ),
+                    )
+                    .await?;
+                }
+
+                let adopted_voice_channel_count = adopt_voice_channels(
+                    &context,
+                    &interaction,
+                    category_channel_id,
+                    voice_channels
+                        .into_iter()
+                        .filter(|voice_channel| Some(voice_channel.id) != join_channel_id)
+                        .collect(),
+                    chosen_owner_id,
+                )
+                .await?;
+
+                let key = if join_channel_id.is_some() {
+                    "create.adopt.adopted"
+                } else {
+                    "create.adopt.adopted_without_join_channel"
+                };
+
+                locale.format(
+                    key,
+                    &[
+                        ("channel_id", &category_channel_id),
+                        ("count", &adopted_voice_channel_count),
+                    ],
+                )
+            }
+        } else {
+            locale.text("create.adopt.unknown_category").to_owned()
+        }
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
+
+// Owners come from each channel's occupants, or else the chosen member, since a member may
+// only own one voice channel. Channels left without an owner can be claimed from the panel.
+async fn adopt_voice_channels(
+    context: &Arc<Context>,
+    interaction: &ApplicationCommandInteraction,
+    category_channel_id: Id<ChannelMarker>,
+    voice_channels: Vec<&Channel>,
+    chosen_owner_id: Option<Id<UserMarker>>,
+) -> Result<usize> {
+    let guild_id = interaction.guild.id;
+    let voice_states = context.cache.guild_voice_states(guild_id);
+    let mut owner_ids = context
+        .cache
+        .guild_voice_channel_owners(guild_id)
+        .into_iter()
+        .map(|(user_id, _)| user_id)
+        .collect::<HashSet<Id<UserMarker>>>();
+    let mut adopted_voice_channel_count = 0;
+    let locale = guild_locale(&interaction.guild);
+    let components = create_panel_message_components(locale);
+    let embeds = [create_panel_message_embed(
+        context,
+        &interaction.guild,
+        locale,
+    )];
+
+    for voice_channel in voice_channels {
+        let voice_channel_id = voice_channel.id;
+
+        if context.cache.voice_channel(voice_channel_id).is_some() {
+            continue;
+        }
+
+        let mut connected_user_ids = voice_states
+            .iter()
+            .filter(|(_, channel_id)| *channel_id == voice_channel_id)
+            .map(|(user_id, _)| *user_id)
+            .collect::<Vec<Id<UserMarker>>>();
+
+        connected_user_ids.sort();
+
+        let owner_id = chosen_owner_id
+            .filter(|user_id| connected_user_ids.contains(user_id))
+            .into_iter()
+            .chain(connected_user_ids.iter().copied())
+            .chain(chosen_owner_id)
+            .find(|user_id| !owner_ids.contains(user_id));
+
+        if let Some(owner_id) = owner_id {
+            owner_ids.insert(owner_id);
+        }
+
+        let panel_message_id =
+            create_panel_message(context, voice_channel_id, &components, &embeds).await?;
+
+        context
+            .database
+            .insert_voice_channel(voice_channel_id, guild_id, category_channel_id, owner_id)
+            .await?;
+        context
+            .database
+            .update_panel_message(voice_channel_id, Some(panel_message_id))
+            .await?;
+        context.cache.insert_voice_channel(
+            connected_user_ids,
+            guild_id,
+            voice_channel_id,
+            owner_id,
+            Some(panel_message_id),
+            category_channel_id,
+            voice_channel
+                .permission_overwrites
+                .clone()
+                .unwrap_or_default(),
+        );
+        adopted_voice_channel_count += 1;
+    }
+
+    Ok(adopted_voice_channel_count)
+}
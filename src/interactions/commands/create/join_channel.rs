@@ -4,12 +4,19 @@ use eyre::Result;
 use twilight_model::{
     application::interaction::application_command::CommandOptionValue, channel::ChannelType,
 };
-use twilight_util::builder::embed::EmbedBuilder;
 
+use super::insert_join_channel;
 use crate::{
-    structs::{context::Context, interaction::ApplicationCommandInteraction},
-    utilities::interaction::{
-        create_deferred_interaction_response, create_interaction_response_embed,
+    structs::{
+        config::MAX_JOIN_CHANNELS, context::Context, database::DatabaseJoinChannel,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_interaction_response_embed,
+            create_response_embed, guild_locale, option_value,
+        },
+        request::ObserveRequest,
     },
 };
 
@@ -19,60 +26,107 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let CommandOptionValue::Channel(channel_id) = interaction.data.options[0].value else {
-        let interaction_response =
-            create_interaction_response_embed("I couldn't find a value!".to_owned(), true);
+    let options = &interaction.data.options;
+    let Some(CommandOptionValue::Channel(channel_id)) = option_value(options, "category") else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
 
         return Ok(());
     };
+    let name = match option_value(options, "name") {
+        Some(CommandOptionValue::String(name)) => name,
+        _ => guild_locale(&interaction.guild)
+            .text("channels.join_channel")
+            .to_owned(),
+    };
+    let name_template = match option_value(options, "name-template") {
+        Some(CommandOptionValue::String(name_template)) => Some(name_template),
+        _ => None,
+    };
+    let user_limit = match option_value(options, "user-limit") {
+        Some(CommandOptionValue::Integer(user_limit)) => Some(user_limit as u16),
+        _ => None,
+    };
+    let privacy = match option_value(options, "privacy") {
+        Some(CommandOptionValue::String(privacy)) => Some(privacy),
+        _ => None,
+    };
+    let bitrate = match option_value(options, "bitrate") {
+        Some(CommandOptionValue::Integer(kilobits)) => Some(kilobits as u32),
+        _ => None,
+    };
+    let min_bitrate = context.config.limits.min_bitrate;
+    let max_bitrate = context.config.limits.max_bitrate;
+    let locale = interaction.locale;
     let description = if let Some(category_channel) = context.cache.category_channel(channel_id) {
-        if category_channel.join_channel_id.read().is_some() {
-            "This category already has a join channel.".to_owned()
+        if bitrate.is_some_and(|bitrate| !(min_bitrate..=max_bitrate).contains(&bitrate)) {
+            locale.format(
+                "interaction.bitrate_out_of_range",
+                &[("min_bitrate", &min_bitrate), ("max_bitrate", &max_bitrate)],
+            )
+        } else if category_channel.join_channel_ids.read().len() >= MAX_JOIN_CHANNELS {
+            locale.text("create.join_channel.maximum").to_owned()
         } else if let Ok(created_join_channel_response) = context
             .client
-            .create_guild_channel(interaction.guild.id, "Join to create")
+            .create_guild_channel(interaction.guild.id, &name)
             .kind(ChannelType::GuildVoice)
             .parent_id(category_channel.id)
             .position(0)
+            .observe(&context)
             .await
         {
             let created_join_channel = created_join_channel_response.model().await?;
-            let created_join_channel_id = created_join_channel.id;
 
-            context
-                .database
-                .update_join_channel(category_channel.id, Some(created_join_channel_id))
-                .await?;
-            context
-                .cache
-                .update_join_channel(category_channel.id, Some(created_join_channel_id));
+            insert_join_channel(
+                &context,
+                DatabaseJoinChannel {
+                    bitrate: bitrate.map(|bitrate| bitrate * 1000),
+                    name_template,
+                    privacy,
+                    user_limit,
+                    ..DatabaseJoinChannel::new(
+                        created_join_channel.id,
+                        interaction.guild.id,
+                        category_channel.id,
+                    )
+                },
+            )
+            .await?;
 
-            format!(
-                "<#{}> is now the join voice channel for this category.",
-                created_join_channel.id
+            locale.format(
+                "create.join_channel.created",
+                &[("channel_id", &created_join_channel.id)],
             )
         } else {
-            "I'm unable to create a join voice channel for this category.".to_owned()
+            locale.text("create.join_channel.failed").to_owned()
         }
     } else {
-        "This category is not a voice channel category.".to_owned()
+        locale
+            .text("create.join_channel.not_voice_category")
+            .to_owned()
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,3 +1,4 @@
+pub mod adopt;
 pub mod join_channel;
 pub mod panel_message;
 pub mod voice_category;
@@ -10,8 +11,11 @@ use twilight_model::application::interaction::application_command::{
 };
 
 use crate::{
-    structs::{context::Context, interaction::ApplicationCommandInteraction},
-    utilities::interaction::create_interaction_response_embed,
+    structs::{
+        context::Context, database::DatabaseJoinChannel, error::Error,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{interaction::create_interaction_response_embed, request::ObserveRequest},
 };
 
 pub async fn run(
@@ -19,14 +23,23 @@ pub async fn run(
     mut interaction: ApplicationCommandInteraction,
 ) -> Result<()> {
     let command_options = interaction.data.options.clone();
-    let CommandDataOption { name, value } = command_options.iter().nth(0).unwrap();
+    let CommandDataOption { name, value } = command_options
+        .first()
+        .ok_or(Error::MissingData("subcommand"))?;
     let CommandOptionValue::SubCommand(options) = value.clone() else {
-        let interaction_response =
-            create_interaction_response_embed("I couldn't find a value!".to_owned(), true);
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
 
         return Ok(());
@@ -34,21 +47,39 @@ pub async fn run(
     let _ = replace(&mut interaction.data.options, options);
 
     match name.as_str() {
+        "adopt" => adopt::run(context, interaction).await?,
         "join-channel" => join_channel::run(context, interaction).await?,
         "panel-message" => panel_message::run(context, interaction).await?,
         "voice-category" => voice_category::run(context, interaction).await?,
         _ => {
             let interaction_response = create_interaction_response_embed(
-                format!("I don't have a subcommand with the name \"{name}\"!"),
+                interaction.color,
+                interaction
+                    .locale
+                    .format("interaction.unknown_subcommand", &[("name", &name)]),
                 true,
             );
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
         }
     }
 
     Ok(())
 }
+
+pub async fn insert_join_channel(
+    context: &Context,
+    join_channel: DatabaseJoinChannel,
+) -> Result<()> {
+    context
+        .database
+        .insert_join_channel(join_channel.clone())
+        .await?;
+    context.cache.insert_join_channel(join_channel.into());
+
+    Ok(())
+}
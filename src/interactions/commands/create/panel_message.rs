@@ -2,13 +2,16 @@ use std::sync::Arc;
 
 use eyre::Result;
 use twilight_model::application::interaction::application_command::CommandOptionValue;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::ApplicationCommandInteraction},
     utilities::{
-        constants::{PANEL_MESSAGE_COMPONENTS, PANEL_MESSAGE_EMBED},
-        interaction::{create_deferred_interaction_response, create_interaction_response_embed},
+        interaction::{
+            create_deferred_interaction_response, create_interaction_response_embed,
+            create_panel_message_components, create_panel_message_embed, create_response_embed,
+            guild_locale,
+        },
+        request::ObserveRequest,
     },
 };
 
@@ -18,25 +21,36 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let CommandOptionValue::Channel(channel_id) = interaction.data.options[0].value else {
-        let interaction_response =
-            create_interaction_response_embed("I couldn't find a value!".to_owned(), true);
+    let Some(&CommandOptionValue::Channel(channel_id)) =
+        interaction.data.options.first().map(|option| &option.value)
+    else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
 
         return Ok(());
     };
     let description = if let Some(voice_channel) = context.cache.voice_channel(channel_id) {
-        let panel_message_id = voice_channel.panel_message_id.read().clone();
+        let panel_message_id = *voice_channel.panel_message_id.read();
         let is_panel_message_valid = if let Some(panel_message_id) = panel_message_id {
             if let Ok(response) = context
                 .client
                 .message(voice_channel.id, panel_message_id)
+                .observe(&context)
                 .await
             {
                 response.model().await.is_ok()
@@ -48,19 +62,31 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
         };
 
         if is_panel_message_valid {
-            format!(
-                "One already exists at https://discord.com/channels/{}/{}/{}!",
-                interaction.guild.id,
-                voice_channel.id,
-                panel_message_id.unwrap()
+            interaction.locale.format(
+                "create.panel_message.exists",
+                &[(
+                    "url",
+                    &format!(
+                        "https://discord.com/channels/{}/{}/{}",
+                        interaction.guild.id,
+                        voice_channel.id,
+                        panel_message_id.unwrap()
+                    ),
+                )],
             )
         } else {
+            let locale = guild_locale(&interaction.guild);
             let new_panel_message_id = Some(
                 context
                     .client
                     .create_message(voice_channel.id)
-                    .components(&*PANEL_MESSAGE_COMPONENTS)
-                    .embeds(&[PANEL_MESSAGE_EMBED.clone()])
+                    .components(&create_panel_message_components(locale))
+                    .embeds(&[create_panel_message_embed(
+                        &context,
+                        &interaction.guild,
+                        locale,
+                    )])
+                    .observe(&context)
                     .await?
                     .model()
                     .await?
@@ -75,20 +101,24 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
                 .cache
                 .update_panel_message(voice_channel.id, new_panel_message_id);
 
-            format!("I've made a new panel message!")
+            interaction
+                .locale
+                .text("create.panel_message.created")
+                .to_owned()
         }
     } else {
-        "I didn't create this voice channel.".to_owned()
+        interaction
+            .locale
+            .text("create.panel_message.unknown_voice_channel")
+            .to_owned()
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
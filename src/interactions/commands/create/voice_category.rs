@@ -12,12 +12,19 @@ use twilight_model::{
     },
     guild::Permissions,
 };
-use twilight_util::builder::embed::EmbedBuilder;
 
+use super::insert_join_channel;
 use crate::{
-    structs::{context::Context, interaction::ApplicationCommandInteraction},
-    utilities::interaction::{
-        create_deferred_interaction_response, create_interaction_response_embed,
+    structs::{
+        config::MAX_CATEGORY_CHANNELS, context::Context, database::DatabaseJoinChannel,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_interaction_response_embed,
+            create_response_embed, guild_locale,
+        },
+        request::ObserveRequest,
     },
 };
 
@@ -27,15 +34,28 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let CommandOptionValue::String(name) = interaction.data.options[0].value.clone() else {
-        let interaction_response =
-            create_interaction_response_embed("I couldn't find a value!".to_owned(), true);
+    let Some(CommandOptionValue::String(name)) = interaction
+        .data
+        .options
+        .first()
+        .map(|option| option.value.clone())
+    else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
 
         return Ok(());
@@ -45,8 +65,10 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
     } else {
         Permissions::empty()
     };
-    let description = if interaction.guild.category_channel_ids.read().len() > 3 {
-        "I'm only allowing a maximum of three voice channel categories in this server!".to_owned()
+    let locale = interaction.locale;
+    let category_channel_count = interaction.guild.category_channel_ids.read().len();
+    let description = if category_channel_count >= MAX_CATEGORY_CHANNELS {
+        locale.text("create.maximum_categories").to_owned()
     } else if let Ok(created_category_channel_response) = context
         .client
         .create_guild_channel(interaction.guild.id, &name)
@@ -65,16 +87,21 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
                 kind: ChannelPermissionOverwriteType::Role,
             },
         ])
+        .observe(&context)
         .await
     {
         let created_category_channel = created_category_channel_response.model().await?;
         let created_category_channel_id = created_category_channel.id;
         let created_join_channel_id = if let Ok(res) = context
             .client
-            .create_guild_channel(interaction.guild.id, "Join to create")
+            .create_guild_channel(
+                interaction.guild.id,
+                guild_locale(&interaction.guild).text("channels.join_channel"),
+            )
             .kind(ChannelType::GuildVoice)
             .parent_id(created_category_channel_id)
             .position(0)
+            .observe(&context)
             .await
         {
             Some(res.model().await?.id)
@@ -84,39 +111,47 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
 
         context
             .database
-            .insert_category_channel(
-                created_category_channel_id,
-                interaction.guild.id,
-                created_join_channel_id,
-            )
+            .insert_category_channel(created_category_channel_id, interaction.guild.id)
             .await?;
         context.cache.insert_category_channel(
             interaction.guild.id,
             created_category_channel_id,
-            created_join_channel_id,
             created_category_channel
                 .permission_overwrites
                 .unwrap_or_default(),
             Vec::new(),
         );
 
+        if let Some(created_join_channel_id) = created_join_channel_id {
+            insert_join_channel(
+                &context,
+                DatabaseJoinChannel::new(
+                    created_join_channel_id,
+                    interaction.guild.id,
+                    created_category_channel_id,
+                ),
+            )
+            .await?;
+        }
+
         if created_join_channel_id.is_some() {
-            format!("I've created <#{created_category_channel_id}>!")
+            locale.format(
+                "create.voice_category.created",
+                &[("channel_id", &created_category_channel_id)],
+            )
         } else {
-            "I'm unable to create a join voice channel for this category. Delete a channel and run the ```/create join-channel``` command.".to_owned()
+            locale.text("create.join_channel_failed").to_owned()
         }
     } else {
-        "I'm unable to create a voice channel category.".to_owned()
+        locale.text("create.voice_category.failed").to_owned()
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,2 +1,4 @@
+pub mod admin;
 pub mod create;
-pub mod settings;
\ No newline at end of file
+pub mod settings;
+pub mod template;
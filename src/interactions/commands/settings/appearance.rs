@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+use twilight_util::builder::embed::ImageSource;
+
+use crate::{
+    structs::{
+        cache::GuildAppearance, context::Context, interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_panel_message_embed,
+            create_response_embed, embed_color, guild_locale, option_value,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let appearance = match appearance_from_options(&interaction) {
+        Ok(appearance) => appearance,
+        Err(description) => {
+            let embed = create_response_embed(interaction.color, description);
+
+            context
+                .interaction_client()
+                .update_response(&interaction.token)
+                .embeds(Some(&[embed]))
+                .observe(&context)
+                .await?;
+
+            return Ok(());
+        }
+    };
+    let description = if interaction.guild.appearance.read().eq(&appearance) {
+        interaction.locale.text("settings.appearance.unchanged")
+    } else {
+        context
+            .database
+            .update_appearance(interaction.guild.id, appearance.clone())
+            .await?;
+        context
+            .cache
+            .update_appearance(interaction.guild.id, appearance);
+
+        interaction.locale.text("settings.appearance.updated")
+    };
+    let embeds = [
+        create_response_embed(
+            embed_color(&context, Some(interaction.guild.id)),
+            description,
+        ),
+        create_panel_message_embed(
+            &context,
+            &interaction.guild,
+            guild_locale(&interaction.guild),
+        ),
+    ];
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&embeds))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
+
+fn appearance_from_options(
+    interaction: &ApplicationCommandInteraction,
+) -> Result<GuildAppearance, String> {
+    let options = &interaction.data.options;
+    let mut appearance = match option_value(options, "reset") {
+        Some(CommandOptionValue::Boolean(true)) => GuildAppearance::default(),
+        _ => interaction.guild.appearance.read().clone(),
+    };
+
+    if let Some(CommandOptionValue::String(color)) = option_value(options, "color") {
+        let Some(parsed_color) = parse_color(&color) else {
+            return Err(interaction
+                .locale
+                .format("settings.appearance.invalid_color", &[("color", &color)]));
+        };
+
+        appearance.color = Some(parsed_color);
+    }
+
+    if let Some(CommandOptionValue::String(thumbnail_url)) = option_value(options, "thumbnail") {
+        if ImageSource::url(&thumbnail_url).is_err() {
+            return Err(interaction.locale.format(
+                "settings.appearance.invalid_thumbnail",
+                &[("url", &thumbnail_url)],
+            ));
+        }
+
+        appearance.thumbnail_url = Some(thumbnail_url);
+    }
+
+    if let Some(CommandOptionValue::String(title)) = option_value(options, "title") {
+        appearance.title = Some(title);
+    }
+
+    if let Some(CommandOptionValue::String(description)) = option_value(options, "description") {
+        appearance.description = Some(description);
+    }
+
+    if let Some(CommandOptionValue::String(footer)) = option_value(options, "footer") {
+        appearance.footer = Some(footer);
+    }
+
+    Ok(appearance)
+}
+
+// Accepts six hex digits with or without a leading `#`, such as `#5865F2`.
+fn parse_color(color: &str) -> Option<u32> {
+    let hex = color.strip_prefix('#').unwrap_or(color);
+
+    if hex.len() != 6 || !hex.chars().all(|character| character.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    u32::from_str_radix(hex, 16).ok()
+}
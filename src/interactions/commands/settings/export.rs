@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::http::attachment::Attachment;
+
+use crate::{
+    structs::{context::Context, interaction::ApplicationCommandInteraction},
+    utilities::{
+        guild_export::export_guild,
+        interaction::{create_deferred_interaction_response, create_response_embed},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let guild_export = export_guild(&context, interaction.guild.id).await?;
+    let attachments = [Attachment::from_bytes(
+        format!("meloetta-{}.json", interaction.guild.id),
+        serde_json::to_vec_pretty(&guild_export)?,
+        1,
+    )];
+    let embed = create_response_embed(
+        interaction.color,
+        interaction.locale.text("settings.export.exported"),
+    );
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .attachments(&attachments)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
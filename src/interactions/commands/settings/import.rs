@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use crate::{
+    structs::{
+        context::Context,
+        error::Error,
+        guild_export::{GuildExport, PendingImport},
+        interaction::ApplicationCommandInteraction,
+        locale::Locale,
+    },
+    utilities::{
+        guild_export::diff_guild_export,
+        interaction::{
+            create_deferred_interaction_response, create_import_confirmation_components,
+            create_response_embed, option_value,
+        },
+        request::{download, ObserveRequest},
+    },
+};
+
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+const MAX_DESCRIPTION_LENGTH: usize = 3900;
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let Some(CommandOptionValue::Attachment(attachment_id)) =
+        option_value(&interaction.data.options, "file")
+    else {
+        return Err(Error::MissingData("file").into());
+    };
+    let attachment = interaction
+        .data
+        .resolved
+        .as_ref()
+        .and_then(|resolved| resolved.attachments.get(&attachment_id))
+        .ok_or(Error::MissingData("attachment"))?;
+    let locale = interaction.locale;
+    let (description, components) = if attachment.size > MAX_FILE_SIZE {
+        (
+            locale.text("settings.import.too_large").to_owned(),
+            Vec::new(),
+        )
+    } else {
+        let bytes = download(&attachment.url).await?;
+        let channel_templates = context
+            .database
+            .guild_channel_templates(interaction.guild.id)
+            .await?;
+        let result =
+            GuildExport::parse(&bytes, &context.config.limits, locale).and_then(|guild_export| {
+                let changes = diff_guild_export(
+                    &context,
+                    &interaction.guild,
+                    &channel_templates,
+                    &guild_export,
+                    locale,
+                )?;
+
+                Ok((guild_export, changes))
+            });
+
+        match result {
+            Err(report) => (
+                locale.format("settings.import.invalid", &[("reason", &report)]),
+                Vec::new(),
+            ),
+            Ok((_, changes)) if changes.is_empty() => (
+                locale.text("settings.import.unchanged").to_owned(),
+                Vec::new(),
+            ),
+            Ok((guild_export, changes)) => {
+                context
+                    .pending_imports
+                    .retain(|_, pending_import| !pending_import.is_expired());
+                context.pending_imports.insert(
+                    interaction.id,
+                    PendingImport::new(interaction.guild.id, guild_export),
+                );
+
+                (
+                    changes_description(&changes, locale),
+                    create_import_confirmation_components(interaction.id, locale),
+                )
+            }
+        }
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .components(Some(&components))
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
+
+fn changes_description(changes: &[String], locale: Locale) -> String {
+    let mut description = locale.text("settings.import.summary").to_owned();
+
+    for (index, change) in changes.iter().enumerate() {
+        let line = format!("\n- {change}");
+
+        if description.len() + line.len() > MAX_DESCRIPTION_LENGTH {
+            description.push('\n');
+            description.push_str(&locale.format(
+                "settings.import.more",
+                &[("count", &(changes.len() - index))],
+            ));
+
+            break;
+        }
+
+        description.push_str(&line);
+    }
+
+    description
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use crate::{
+    structs::{context::Context, interaction::ApplicationCommandInteraction, locale::Locale},
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_interaction_response_embed,
+            create_response_embed,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let chosen_locale = match interaction.data.options.first().map(|option| &option.value) {
+        Some(CommandOptionValue::String(language)) if language == "automatic" => Some(None),
+        Some(CommandOptionValue::String(language)) => Locale::new(language).map(Some),
+        _ => None,
+    };
+    let Some(chosen_locale) = chosen_locale else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
+
+        context
+            .interaction_client()
+            .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
+            .await?;
+
+        return Ok(());
+    };
+    let locale_code = chosen_locale.map(|locale| locale.code().to_owned());
+    let description = if interaction.guild.locale.read().eq(&locale_code) {
+        interaction.locale.text("interaction.no_change").to_owned()
+    } else {
+        context
+            .database
+            .update_locale(interaction.guild.id, locale_code.clone())
+            .await?;
+        context
+            .cache
+            .update_locale(interaction.guild.id, locale_code);
+
+        match chosen_locale {
+            Some(locale) => locale.format("settings.language.set", &[("language", &locale.name())]),
+            None => interaction
+                .locale
+                .text("settings.language.automatic_set")
+                .to_owned(),
+        }
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
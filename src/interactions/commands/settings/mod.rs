@@ -1,3 +1,7 @@
+pub mod appearance;
+pub mod export;
+pub mod import;
+pub mod language;
 pub mod permanence;
 pub mod privacy;
 pub mod show;
@@ -10,8 +14,8 @@ use twilight_model::application::interaction::application_command::{
 };
 
 use crate::{
-    structs::{context::Context, interaction::ApplicationCommandInteraction},
-    utilities::interaction::create_interaction_response_embed,
+    structs::{context::Context, error::Error, interaction::ApplicationCommandInteraction},
+    utilities::{interaction::create_interaction_response_embed, request::ObserveRequest},
 };
 
 pub async fn run(
@@ -19,14 +23,23 @@ pub async fn run(
     mut interaction: ApplicationCommandInteraction,
 ) -> Result<()> {
     let command_options = interaction.data.options.clone();
-    let CommandDataOption { name, value } = command_options.iter().nth(0).unwrap();
+    let CommandDataOption { name, value } = command_options
+        .first()
+        .ok_or(Error::MissingData("subcommand"))?;
     let CommandOptionValue::SubCommand(options) = value.clone() else {
-        let interaction_response =
-            create_interaction_response_embed("I couldn't find a value!".to_owned(), true);
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
 
         return Ok(());
@@ -34,18 +47,26 @@ pub async fn run(
     let _ = replace(&mut interaction.data.options, options);
 
     match name.as_str() {
+        "appearance" => appearance::run(context, interaction).await?,
+        "export" => export::run(context, interaction).await?,
+        "import" => import::run(context, interaction).await?,
+        "language" => language::run(context, interaction).await?,
         "permanence" => permanence::run(context, interaction).await?,
         "privacy" => privacy::run(context, interaction).await?,
         "show" => show::run(context, interaction).await?,
         _ => {
             let interaction_response = create_interaction_response_embed(
-                format!("I don't have a subcommand with the name \"{name}\"!"),
+                interaction.color,
+                interaction
+                    .locale
+                    .format("interaction.unknown_subcommand", &[("name", &name)]),
                 true,
             );
 
             context
                 .interaction_client()
                 .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
                 .await?;
         }
     }
@@ -1,13 +1,16 @@
-use std::{future::IntoFuture, sync::Arc};
+use std::sync::Arc;
 
 use eyre::Result;
 use twilight_model::application::interaction::application_command::CommandOptionValue;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::ApplicationCommandInteraction},
-    utilities::interaction::{
-        create_deferred_interaction_response, create_interaction_response_embed,
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_interaction_response_embed,
+            create_response_embed,
+        },
+        request::ObserveRequest,
     },
 };
 
@@ -17,21 +20,31 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let CommandOptionValue::Boolean(permanence) = interaction.data.options[0].value else {
-        let interaction_response =
-            create_interaction_response_embed("I couldn't find a value!".to_owned(), true);
+    let Some(&CommandOptionValue::Boolean(permanence)) =
+        interaction.data.options.first().map(|option| &option.value)
+    else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
 
         return Ok(());
     };
     let description = if interaction.guild.permanence.read().eq(&permanence) {
-        "No change has been applied."
+        interaction.locale.text("interaction.no_change")
     } else {
         context
             .database
@@ -42,7 +55,7 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
             .update_permanence(interaction.guild.id, permanence);
 
         if permanence {
-            "Empty voice channels (created by me) will not be deleted."
+            interaction.locale.text("settings.permanence.enabled")
         } else {
             for category_channel_id in interaction.guild.category_channel_ids.read().iter() {
                 let Some(category_channel) = context.cache.category_channel(*category_channel_id)
@@ -58,28 +71,26 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
                     if voice_channel.connected_user_ids.read().len().eq(&0) {
                         let command_context = Arc::clone(&context);
 
-                        tokio::spawn(
+                        context.tasks.spawn(
                             command_context
                                 .client
                                 .delete_channel(*voice_channel_id)
-                                .into_future(),
+                                .observe(&command_context),
                         );
                     }
                 }
             }
 
-            "Voice channels (created by me) will now deleted when empty."
+            interaction.locale.text("settings.permanence.disabled")
         }
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description.to_owned())
-        .build();
+    let embed = create_response_embed(interaction.color, description.to_owned());
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
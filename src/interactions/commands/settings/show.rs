@@ -4,8 +4,11 @@ use eyre::Result;
 use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
 
 use crate::{
-    structs::{context::Context, interaction::ApplicationCommandInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    structs::{
+        cache::GuildAppearance, context::Context, interaction::ApplicationCommandInteraction,
+        locale::Locale,
+    },
+    utilities::{interaction::create_deferred_interaction_response, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
@@ -14,10 +17,12 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
+    let locale = interaction.locale;
     let categories_text = if interaction.guild.category_channel_ids.read().is_empty() {
-        "No voice channel categories have been created.".to_owned()
+        locale.text("settings.show.no_categories").to_owned()
     } else {
         interaction
             .guild
@@ -25,37 +30,73 @@ pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteracti
             .read()
             .iter()
             .map(|channel_id| {
-                context
-                    .cache
-                    .category_channel(*channel_id)
-                    .map_or(format!("- {channel_id} **(no longer exists)**"), |_| {
-                        format!("- <#{channel_id}>")
-                    })
+                context.cache.category_channel(*channel_id).map_or(
+                    locale.format(
+                        "settings.show.deleted_category",
+                        &[("channel_id", channel_id)],
+                    ),
+                    |_| format!("- <#{channel_id}>"),
+                )
             })
             .collect::<Vec<String>>()
             .join("\n")
     };
-    let permanence_text = if interaction.guild.permanence.read().clone() {
-        "Voice channels **will not be deleted** when empty.".to_owned()
+    let permanence_text = if *interaction.guild.permanence.read() {
+        locale.text("settings.show.permanent")
     } else {
-        "Voice channels **will be deleted** when empty.".to_owned()
+        locale.text("settings.show.temporary")
     };
     let privacy_text = match interaction.guild.privacy.read().clone().as_str() {
-        "invisible" => "Voice channels are **invisible** by default.",
-        "locked" => "Voice channels are **locked and visible** by default.",
-        _ => "Voice channels are **not locked and visible** by default.",
+        "invisible" => locale.text("settings.show.invisible"),
+        "locked" => locale.text("settings.show.locked"),
+        _ => locale.text("settings.show.unlocked"),
+    };
+    let language_text = interaction
+        .guild
+        .locale
+        .read()
+        .as_deref()
+        .and_then(Locale::new)
+        .map_or(
+            locale.text("settings.show.automatic_language"),
+            Locale::name,
+        );
+    let appearance_text = if interaction
+        .guild
+        .appearance
+        .read()
+        .eq(&GuildAppearance::default())
+    {
+        locale.text("settings.show.default_appearance").to_owned()
+    } else {
+        locale.format(
+            "settings.show.custom_appearance",
+            &[("color", &format!("{:06X}", interaction.color))],
+        )
     };
     let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .field(EmbedFieldBuilder::new("Categories", categories_text).build())
-        .field(EmbedFieldBuilder::new("Permanence", permanence_text).build())
-        .field(EmbedFieldBuilder::new("Privacy", privacy_text).build())
+        .color(interaction.color)
+        .field(
+            EmbedFieldBuilder::new(locale.text("settings.show.appearance"), appearance_text)
+                .build(),
+        )
+        .field(
+            EmbedFieldBuilder::new(locale.text("settings.show.categories"), categories_text)
+                .build(),
+        )
+        .field(EmbedFieldBuilder::new(locale.text("settings.show.language"), language_text).build())
+        .field(
+            EmbedFieldBuilder::new(locale.text("settings.show.permanence"), permanence_text)
+                .build(),
+        )
+        .field(EmbedFieldBuilder::new(locale.text("settings.show.privacy"), privacy_text).build())
         .build();
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
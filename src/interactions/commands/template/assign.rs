@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use crate::{
+    structs::{
+        cache::CachedJoinChannel, context::Context, error::Error,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, option_value},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let options = &interaction.data.options;
+    let Some(CommandOptionValue::Channel(channel_id)) = option_value(options, "join-channel")
+    else {
+        return Err(Error::MissingData("join-channel").into());
+    };
+    let template_name = match option_value(options, "name") {
+        Some(CommandOptionValue::String(name)) => Some(name),
+        _ => None,
+    };
+    let join_channel = context
+        .cache
+        .join_channel(channel_id)
+        .filter(|join_channel| join_channel.guild_id == interaction.guild.id);
+    let channel_template_exists = match &template_name {
+        Some(template_name) => context
+            .database
+            .channel_template(interaction.guild.id, template_name.clone())
+            .await?
+            .is_some(),
+        None => true,
+    };
+    let locale = interaction.locale;
+    let description = match join_channel {
+        None => locale.text("template.not_join_channel").to_owned(),
+        Some(_) if !channel_template_exists => locale.format(
+            "template.not_found",
+            &[("name", &template_name.unwrap_or_default())],
+        ),
+        Some(join_channel) => {
+            context
+                .database
+                .update_join_channel_template(channel_id, template_name.clone())
+                .await?;
+            context.cache.insert_join_channel(CachedJoinChannel {
+                template_name: template_name.clone(),
+                ..CachedJoinChannel::clone(&join_channel)
+            });
+
+            match template_name {
+                Some(template_name) => locale.format(
+                    "template.assigned",
+                    &[("channel_id", &channel_id), ("name", &template_name)],
+                ),
+                None => locale.format("template.unassigned", &[("channel_id", &channel_id)]),
+            }
+        }
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use super::{bitrate_out_of_range, update_channel_template_from_options};
+use crate::{
+    structs::{
+        config::MAX_CHANNEL_TEMPLATES, context::Context, database::DatabaseChannelTemplate,
+        error::Error, interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{
+            channel_template_label, create_deferred_interaction_response, create_response_embed,
+            option_value,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let Some(CommandOptionValue::String(name)) = option_value(&interaction.data.options, "name")
+    else {
+        return Err(Error::MissingData("name").into());
+    };
+    let channel_templates = context
+        .database
+        .guild_channel_templates(interaction.guild.id)
+        .await?;
+    let description = if channel_templates
+        .iter()
+        .any(|channel_template| channel_template.name == name)
+    {
+        interaction
+            .locale
+            .format("template.exists", &[("name", &name)])
+    } else if channel_templates.len() >= MAX_CHANNEL_TEMPLATES {
+        interaction.locale.text("template.maximum").to_owned()
+    } else if let Some(description) = bitrate_out_of_range(&context, &interaction) {
+        description
+    } else {
+        let mut channel_template = DatabaseChannelTemplate::new(interaction.guild.id, name);
+
+        update_channel_template_from_options(&context, &interaction, &mut channel_template).await?;
+        context
+            .database
+            .insert_channel_template(channel_template.clone())
+            .await?;
+
+        interaction.locale.format(
+            "template.created",
+            &[
+                ("name", &channel_template.name),
+                (
+                    "settings",
+                    &channel_template_label(&channel_template, interaction.locale),
+                ),
+            ],
+        )
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use crate::{
+    structs::{
+        cache::CachedJoinChannel, context::Context, error::Error,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, option_value},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let Some(CommandOptionValue::String(name)) = option_value(&interaction.data.options, "name")
+    else {
+        return Err(Error::MissingData("name").into());
+    };
+    let description = if context
+        .database
+        .channel_template(interaction.guild.id, name.clone())
+        .await?
+        .is_some()
+    {
+        context
+            .database
+            .remove_channel_template(interaction.guild.id, name.clone())
+            .await?;
+
+        for join_channel in context.cache.guild_join_channels(interaction.guild.id) {
+            if join_channel.template_name.as_ref() == Some(&name) {
+                context.cache.insert_join_channel(CachedJoinChannel {
+                    template_name: None,
+                    ..CachedJoinChannel::clone(&join_channel)
+                });
+            }
+        }
+
+        interaction
+            .locale
+            .format("template.deleted", &[("name", &name)])
+    } else {
+        interaction
+            .locale
+            .format("template.not_found", &[("name", &name)])
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
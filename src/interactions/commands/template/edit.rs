@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::application::interaction::application_command::CommandOptionValue;
+
+use super::{bitrate_out_of_range, update_channel_template_from_options};
+use crate::{
+    structs::{context::Context, error::Error, interaction::ApplicationCommandInteraction},
+    utilities::{
+        interaction::{
+            channel_template_label, create_deferred_interaction_response, create_response_embed,
+            option_value,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let Some(CommandOptionValue::String(name)) = option_value(&interaction.data.options, "name")
+    else {
+        return Err(Error::MissingData("name").into());
+    };
+    let description = if let Some(description) = bitrate_out_of_range(&context, &interaction) {
+        description
+    } else if let Some(mut channel_template) = context
+        .database
+        .channel_template(interaction.guild.id, name.clone())
+        .await?
+    {
+        update_channel_template_from_options(&context, &interaction, &mut channel_template).await?;
+        context
+            .database
+            .update_channel_template(channel_template.clone())
+            .await?;
+
+        interaction.locale.format(
+            "template.edited",
+            &[
+                ("name", &name),
+                (
+                    "settings",
+                    &channel_template_label(&channel_template, interaction.locale),
+                ),
+            ],
+        )
+    } else {
+        interaction
+            .locale
+            .format("template.not_found", &[("name", &name)])
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
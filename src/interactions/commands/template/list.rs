@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use eyre::Result;
+
+use crate::{
+    structs::{context::Context, interaction::ApplicationCommandInteraction},
+    utilities::{
+        interaction::{
+            channel_template_label, create_deferred_interaction_response, create_response_embed,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: ApplicationCommandInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let channel_templates = context
+        .database
+        .guild_channel_templates(interaction.guild.id)
+        .await?;
+    let locale = interaction.locale;
+    let description = if channel_templates.is_empty() {
+        locale.text("template.none").to_owned()
+    } else {
+        channel_templates
+            .iter()
+            .map(|channel_template| {
+                let join_channels_text = context
+                    .cache
+                    .guild_join_channels(interaction.guild.id)
+                    .iter()
+                    .filter(|join_channel| {
+                        join_channel.template_name.as_ref() == Some(&channel_template.name)
+                    })
+                    .map(|join_channel| format!("<#{}>", join_channel.id))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let settings = channel_template_label(channel_template, locale);
+
+                if join_channels_text.is_empty() {
+                    locale.format(
+                        "template.listed",
+                        &[("name", &channel_template.name), ("settings", &settings)],
+                    )
+                } else {
+                    locale.format(
+                        "template.listed_with_join_channels",
+                        &[
+                            ("name", &channel_template.name),
+                            ("settings", &settings),
+                            ("join_channels", &join_channels_text),
+                        ],
+                    )
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+pub mod assign;
+pub mod create;
+pub mod delete;
+pub mod edit;
+pub mod list;
+
+use std::{mem::replace, sync::Arc};
+
+use eyre::Result;
+use twilight_model::{
+    application::interaction::application_command::{CommandDataOption, CommandOptionValue},
+    channel::permission_overwrite::PermissionOverwriteType as ChannelPermissionOverwriteType,
+};
+
+use crate::{
+    structs::{
+        context::Context, database::DatabaseChannelTemplate, error::Error,
+        interaction::ApplicationCommandInteraction,
+    },
+    utilities::{
+        interaction::{create_interaction_response_embed, option_value},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(
+    context: Arc<Context>,
+    mut interaction: ApplicationCommandInteraction,
+) -> Result<()> {
+    let command_options = interaction.data.options.clone();
+    let CommandDataOption { name, value } = command_options
+        .first()
+        .ok_or(Error::MissingData("subcommand"))?;
+    let CommandOptionValue::SubCommand(options) = value.clone() else {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.missing_value")
+                .to_owned(),
+            true,
+        );
+
+        context
+            .interaction_client()
+            .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
+            .await?;
+
+        return Ok(());
+    };
+    let _ = replace(&mut interaction.data.options, options);
+
+    match name.as_str() {
+        "assign" => assign::run(context, interaction).await?,
+        "create" => create::run(context, interaction).await?,
+        "delete" => delete::run(context, interaction).await?,
+        "edit" => edit::run(context, interaction).await?,
+        "list" => list::run(context, interaction).await?,
+        _ => {
+            let interaction_response = create_interaction_response_embed(
+                interaction.color,
+                interaction
+                    .locale
+                    .format("interaction.unknown_subcommand", &[("name", &name)]),
+                true,
+            );
+
+            context
+                .interaction_client()
+                .create_response(interaction.id, &interaction.token, &interaction_response)
+                .observe(&context)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+// Describes why the `bitrate` option can't be used, if it's outside the configured limits.
+pub fn bitrate_out_of_range(
+    context: &Context,
+    interaction: &ApplicationCommandInteraction,
+) -> Option<String> {
+    let min_bitrate = context.config.limits.min_bitrate;
+    let max_bitrate = context.config.limits.max_bitrate;
+
+    match option_value(&interaction.data.options, "bitrate") {
+        Some(CommandOptionValue::Integer(kilobits))
+            if !(i64::from(min_bitrate)..=i64::from(max_bitrate)).contains(&kilobits) =>
+        {
+            Some(interaction.locale.format(
+                "interaction.bitrate_out_of_range",
+                &[("min_bitrate", &min_bitrate), ("max_bitrate", &max_bitrate)],
+            ))
+        }
+        _ => None,
+    }
+}
+
+pub async fn update_channel_template_from_options(
+    context: &Context,
+    interaction: &ApplicationCommandInteraction,
+    channel_template: &mut DatabaseChannelTemplate,
+) -> Result<()> {
+    let options = &interaction.data.options;
+
+    if let Some(CommandOptionValue::Integer(user_limit)) = option_value(options, "user-limit") {
+        channel_template.user_limit = Some(user_limit as u16);
+    }
+
+    if let Some(CommandOptionValue::Integer(kilobits)) = option_value(options, "bitrate") {
+        channel_template.bitrate = Some(kilobits as u32 * 1000);
+    }
+
+    if let Some(CommandOptionValue::String(privacy)) = option_value(options, "privacy") {
+        channel_template.privacy = Some(privacy);
+    }
+
+    if let Some(CommandOptionValue::Channel(channel_id)) = option_value(options, "permissions-from")
+    {
+        let channel = context
+            .client
+            .channel(channel_id)
+            .observe(context)
+            .await?
+            .model()
+            .await?;
+
+        channel_template.permission_overwrites = channel
+            .permission_overwrites
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|permission_overwrite| {
+                permission_overwrite.kind == ChannelPermissionOverwriteType::Role
+                    && permission_overwrite.id != interaction.guild.id.cast()
+                    && permission_overwrite.id != interaction.guild.bot_role_id.cast()
+            })
+            .collect();
+    }
+
+    Ok(())
+}
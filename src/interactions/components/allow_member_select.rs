@@ -11,11 +11,13 @@ use twilight_model::{
     },
     id::{marker::UserMarker, Id},
 };
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
-    structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    structs::{context::Context, error::Error, interaction::MessageComponentInteraction},
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, resolved_user},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -24,23 +26,20 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let user_id = interaction
-        .data
-        .resolved
-        .unwrap()
-        .users
-        .values()
-        .nth(0)
-        .unwrap()
-        .id;
+    let user_id = resolved_user(&interaction.data)?.id;
     let protected_user_ids: HashSet<Id<UserMarker>> = HashSet::from_iter(vec![
-        interaction.voice_channel.owner_id.read().clone().unwrap(),
+        (*interaction.voice_channel.owner_id.read())
+            .ok_or(Error::MissingData("voice channel owner"))?,
         context.application_id.cast(),
     ]);
     let description = if protected_user_ids.contains(&user_id) {
-        "This user may not be added!".to_owned()
+        interaction
+            .locale
+            .text("voice_channel.allow_member.protected")
+            .to_owned()
     } else {
         let (mut member_allow, member_deny) = interaction
             .voice_channel
@@ -77,23 +76,28 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
                     kind: HttpPermissionOverwriteType::Member,
                 },
             )
+            .observe(&context)
             .await
             .is_err()
         {
-            "I don't have permissions to add users to this voice channel!".to_owned()
+            interaction
+                .locale
+                .text("voice_channel.allow_member.failed")
+                .to_owned()
         } else {
-            format!("I've added permissions for <@{user_id}>!")
+            interaction.locale.format(
+                "voice_channel.allow_member.allowed",
+                &[("user_id", &user_id)],
+            )
         }
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
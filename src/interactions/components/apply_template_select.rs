@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use eyre::Result;
+
+use crate::{
+    structs::{context::Context, interaction::MessageComponentInteraction},
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, select_value},
+        permission::{apply_privacy, merge_permission_overwrites},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
+    let interaction_response = create_deferred_interaction_response(true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let template_name = select_value(&interaction.data)?;
+    let description = if let Some(channel_template) = context
+        .database
+        .channel_template(interaction.voice_channel.guild_id, template_name.clone())
+        .await?
+    {
+        let mut voice_channel_permission_overwrites = interaction
+            .voice_channel
+            .permission_overwrites
+            .read()
+            .clone();
+
+        merge_permission_overwrites(
+            &mut voice_channel_permission_overwrites,
+            channel_template.permission_overwrites,
+        );
+
+        if let Some(privacy_option) = &channel_template.privacy {
+            apply_privacy(
+                &mut voice_channel_permission_overwrites,
+                interaction.voice_channel.guild_id,
+                *interaction.voice_channel.owner_id.read(),
+                privacy_option,
+            );
+        }
+
+        let mut request = context
+            .client
+            .update_channel(interaction.voice_channel.id)
+            .permission_overwrites(&voice_channel_permission_overwrites);
+
+        if let Some(bitrate) = channel_template.bitrate {
+            request = request.bitrate(bitrate);
+        }
+
+        if let Some(user_limit) = channel_template.user_limit {
+            request = request.user_limit(user_limit);
+        }
+
+        if request.observe(&context).await.is_err() {
+            interaction
+                .locale
+                .text("voice_channel.apply_template.failed")
+                .to_owned()
+        } else {
+            interaction.locale.format(
+                "voice_channel.apply_template.applied",
+                &[("name", &template_name)],
+            )
+        }
+    } else {
+        interaction
+            .locale
+            .format("template.not_found", &[("name", &template_name)])
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::channel::message::{
+    component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+    Component,
+};
+
+use crate::{
+    structs::{context::Context, interaction::MessageComponentInteraction},
+    utilities::{
+        interaction::{
+            channel_template_label, create_interaction_response_embed,
+            create_interaction_response_select,
+        },
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
+    let channel_templates = context
+        .database
+        .guild_channel_templates(interaction.voice_channel.guild_id)
+        .await?;
+
+    if channel_templates.is_empty() {
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("voice_channel.apply_template.no_templates")
+                .to_owned(),
+            true,
+        );
+
+        context
+            .interaction_client()
+            .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
+            .await?;
+
+        return Ok(());
+    }
+
+    let options = channel_templates
+        .iter()
+        .map(|channel_template| SelectMenuOption {
+            default: false,
+            description: Some(channel_template_label(channel_template, interaction.locale)),
+            emoji: None,
+            label: channel_template.name.clone(),
+            value: channel_template.name.clone(),
+        })
+        .collect::<Vec<SelectMenuOption>>();
+    let components = vec![Component::ActionRow(ActionRow {
+        components: vec![Component::SelectMenu(SelectMenu {
+            channel_types: None,
+            custom_id: "apply-template-select".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Text,
+            max_values: Some(1),
+            min_values: Some(1),
+            options: Some(options),
+            placeholder: Some(
+                interaction
+                    .locale
+                    .text("voice_channel.apply_template.placeholder")
+                    .to_owned(),
+            ),
+        })],
+    })];
+    let interaction_response = create_interaction_response_select(components, true);
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -14,6 +16,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
     let description = if context
@@ -21,9 +24,9 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
         .voice_channel_owner(interaction.voice_channel.guild_id, interaction.user_id)
         .is_some()
     {
-        "You already own a voice channel!"
+        interaction.locale.text("voice_channel.claim.already_owner")
     } else if interaction.voice_channel.owner_id.read().is_some() {
-        "You may only claim voice channels without owners."
+        interaction.locale.text("voice_channel.claim.owned")
     } else {
         context
             .database
@@ -33,17 +36,15 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             .cache
             .update_voice_channel_owner(interaction.voice_channel.id, Some(interaction.user_id));
 
-        "You now own this voice channel!"
+        interaction.locale.text("voice_channel.claim.claimed")
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description.to_owned())
-        .build();
+    let embed = create_response_embed(interaction.color, description.to_owned());
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
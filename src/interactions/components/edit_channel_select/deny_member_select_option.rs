@@ -8,7 +8,7 @@ use twilight_model::channel::message::{
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_interaction_response_select,
+    utilities::{interaction::create_interaction_response_select, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -22,8 +22,12 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             max_values: Some(1),
             min_values: Some(1),
             options: None,
-            placeholder: Some("Select a member to deny permissions for...".to_owned()),
-            
+            placeholder: Some(
+                interaction
+                    .locale
+                    .text("voice_channel.deny_member.placeholder")
+                    .to_owned(),
+            ),
         })],
     })];
     let interaction_response = create_interaction_response_select(components, true);
@@ -31,6 +35,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,4 +1,5 @@
 mod allow_member_select_option;
+mod apply_template_select_option;
 mod claim_select_option;
 mod deny_member_select_option;
 mod kick_member_select_option;
@@ -13,32 +14,41 @@ mod remove_member_select_option;
 mod transfer_select_option;
 mod view_information_select_option;
 
-use std::{future::IntoFuture, sync::Arc};
+use std::sync::Arc;
 
 use eyre::Result;
 
 use crate::{
-    structs::{context::Context, interaction::MessageComponentInteraction},
+    structs::{context::Context, interaction::MessageComponentInteraction, locale::Locale},
     utilities::{
-        constants::{NON_VOICE_CHANNEL_OWNER_SELECT_OPTIONS, PANEL_MESSAGE_COMPONENTS},
-        interaction::create_interaction_response_embed,
+        constants::NON_VOICE_CHANNEL_OWNER_SELECT_OPTIONS,
+        interaction::{
+            create_interaction_response_embed, create_panel_message_components, guild_locale,
+            select_value,
+        },
+        request::ObserveRequest,
     },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
     if let Some(panel_message_id) = &interaction.voice_channel.panel_message_id.read().clone() {
         let context_clone = Arc::clone(&context);
+        let locale = context
+            .cache
+            .guild(interaction.voice_channel.guild_id)
+            .map_or(Locale::DEFAULT, |guild| guild_locale(&guild));
+        let components = create_panel_message_components(locale);
 
-        tokio::spawn(
+        context.tasks.spawn(
             context_clone
                 .client
                 .update_message(interaction.voice_channel.id, *panel_message_id)
-                .components(Some(&*PANEL_MESSAGE_COMPONENTS.clone()))
-                .into_future(),
+                .components(Some(&components))
+                .observe(&context_clone),
         );
     }
 
-    let select_option = interaction.data.values.clone().into_iter().nth(0).unwrap();
+    let select_option = select_value(&interaction.data)?;
     let is_user_owner = interaction
         .voice_channel
         .owner_id
@@ -51,18 +61,28 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     };
 
     if !has_permissions {
-        let interaction_response =
-            create_interaction_response_embed("You are not allowed to do this!".to_owned(), true);
+        let interaction_response = create_interaction_response_embed(
+            interaction.color,
+            interaction
+                .locale
+                .text("interaction.not_allowed")
+                .to_owned(),
+            true,
+        );
 
         context
             .interaction_client()
             .create_response(interaction.id, &interaction.token, &interaction_response)
+            .observe(&context)
             .await?;
     } else {
         match select_option.as_str() {
             "allow-member-select-option" => {
                 allow_member_select_option::run(context, interaction).await?
             }
+            "apply-template-select-option" => {
+                apply_template_select_option::run(context, interaction).await?
+            }
             "claim-select-option" => claim_select_option::run(context, interaction).await?,
             "deny-member-select-option" => {
                 deny_member_select_option::run(context, interaction).await?
@@ -100,13 +120,18 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             }
             _ => {
                 let interaction_response = create_interaction_response_embed(
-                    format!("I don't have a select option with the name \"{select_option}\"!"),
+                    interaction.color,
+                    interaction.locale.format(
+                        "interaction.unknown_select_option",
+                        &[("name", &select_option)],
+                    ),
                     true,
                 );
 
                 context
                     .interaction_client()
                     .create_response(interaction.id, &interaction.token, &interaction_response)
+                    .observe(&context)
                     .await?;
             }
         }
@@ -8,17 +8,25 @@ use twilight_model::channel::message::{
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_interaction_response_modal,
+    utilities::{interaction::create_interaction_response_modal, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
+    let min_bitrate = context.config.limits.min_bitrate;
+    let max_bitrate = context.config.limits.max_bitrate.to_string();
     let components = vec![Component::ActionRow(ActionRow {
         components: vec![Component::TextInput(TextInput {
             custom_id: "modify-bitrate-text-input".to_owned(),
             required: Some(true),
-            placeholder: Some("Enter a new bitrate (between 8-96)...".to_owned()),
-            label: "Bitrate".to_owned(),
-            max_length: Some(2),
+            placeholder: Some(interaction.locale.format(
+                "voice_channel.modify_bitrate.placeholder",
+                &[("min_bitrate", &min_bitrate), ("max_bitrate", &max_bitrate)],
+            )),
+            label: interaction
+                .locale
+                .text("voice_channel.modify_bitrate.label")
+                .to_owned(),
+            max_length: Some(max_bitrate.len() as u16),
             min_length: Some(1),
             style: TextInputStyle::Short,
             value: None,
@@ -27,13 +35,17 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     let interaction_response = create_interaction_response_modal(
         "modify-bitrate-modal".to_owned(),
         components,
-        "Modify bitrate".to_owned(),
+        interaction
+            .locale
+            .text("voice_channel.modify_bitrate.title")
+            .to_owned(),
     );
 
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
-    
+
     Ok(())
 }
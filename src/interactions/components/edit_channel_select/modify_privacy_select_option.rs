@@ -8,21 +8,21 @@ use twilight_model::channel::message::{
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_interaction_response_select,
+    utilities::{interaction::create_interaction_response_select, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
     let options = [
-        ("Invisible", "invisible"),
-        ("Locked (and visible)", "locked"),
-        ("Unlocked (and visible)", "unlocked"),
+        ("privacy.invisible", "invisible"),
+        ("privacy.locked", "locked"),
+        ("privacy.unlocked", "unlocked"),
     ]
     .into_iter()
-    .map(|(label, value)| SelectMenuOption {
+    .map(|(key, value)| SelectMenuOption {
         default: false,
         description: None,
         emoji: None,
-        label: label.to_owned(),
+        label: interaction.locale.text(key).to_owned(),
         value: value.to_owned(),
     })
     .collect::<Vec<SelectMenuOption>>();
@@ -36,7 +36,12 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             max_values: Some(1),
             min_values: Some(1),
             options: Some(options),
-            placeholder: Some("Modify privacy...".to_owned()),
+            placeholder: Some(
+                interaction
+                    .locale
+                    .text("voice_channel.modify_privacy.placeholder")
+                    .to_owned(),
+            ),
         })],
     })];
     let interaction_response = create_interaction_response_select(components, true);
@@ -44,6 +49,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
     Ok(())
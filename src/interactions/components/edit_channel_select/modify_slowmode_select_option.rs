@@ -8,35 +8,26 @@ use twilight_model::channel::message::{
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_interaction_response_select,
+    utilities::{
+        interaction::{create_interaction_response_select, slowmode_label},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
-    let options = [
-        ("Off", "0"),
-        ("5s", "5"),
-        ("10s", "10"),
-        ("15s", "15"),
-        ("30s", "30"),
-        ("1m", "60"),
-        ("2m", "120"),
-        ("5m", "300"),
-        ("10m", "600"),
-        ("15m", "900"),
-        ("30m", "1800"),
-        ("1h", "3600"),
-        ("2h", "7200"),
-        ("6h", "21600"),
-    ]
-    .into_iter()
-    .map(|(label, value)| SelectMenuOption {
-        default: false,
-        description: None,
-        emoji: None,
-        label: label.to_owned(),
-        value: value.to_owned(),
-    })
-    .collect::<Vec<SelectMenuOption>>();
+    let options = context
+        .config
+        .limits
+        .slowmode_options
+        .iter()
+        .map(|slowmode| SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: slowmode_label(*slowmode, interaction.locale),
+            value: slowmode.to_string(),
+        })
+        .collect::<Vec<SelectMenuOption>>();
     let components = vec![Component::ActionRow(ActionRow {
         components: vec![Component::SelectMenu(SelectMenu {
             channel_types: None,
@@ -47,7 +38,12 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             max_values: Some(1),
             min_values: Some(1),
             options: Some(options),
-            placeholder: Some("Modify slowmode...".to_owned()),
+            placeholder: Some(
+                interaction
+                    .locale
+                    .text("voice_channel.modify_slowmode.placeholder")
+                    .to_owned(),
+            ),
         })],
     })];
     let interaction_response = create_interaction_response_select(components, true);
@@ -55,6 +51,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
     Ok(())
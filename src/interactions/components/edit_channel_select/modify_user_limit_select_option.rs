@@ -8,7 +8,7 @@ use twilight_model::channel::message::{
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_interaction_response_modal,
+    utilities::{interaction::create_interaction_response_modal, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -16,8 +16,16 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
         components: vec![Component::TextInput(TextInput {
             custom_id: "modify-user-limit-text-input".to_owned(),
             required: Some(true),
-            placeholder: Some("Enter a new user limit (Set to 0 for no limit!)...".to_owned()),
-            label: "User limit".to_owned(),
+            placeholder: Some(
+                interaction
+                    .locale
+                    .text("voice_channel.modify_user_limit.placeholder")
+                    .to_owned(),
+            ),
+            label: interaction
+                .locale
+                .text("voice_channel.modify_user_limit.label")
+                .to_owned(),
             max_length: Some(2),
             min_length: Some(1),
             style: TextInputStyle::Short,
@@ -27,13 +35,17 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     let interaction_response = create_interaction_response_modal(
         "modify-user-limit-modal".to_owned(),
         components,
-        "Modify user limit".to_owned(),
+        interaction
+            .locale
+            .text("voice_channel.modify_user_limit.title")
+            .to_owned(),
     );
 
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
-    
+
     Ok(())
 }
@@ -8,20 +8,28 @@ use twilight_model::channel::message::{
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_interaction_response_select,
+    utilities::{interaction::create_interaction_response_select, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
-    let options = [("Auto", "1"), ("720p", "2")]
-        .into_iter()
-        .map(|(label, value)| SelectMenuOption {
-            default: false,
-            description: None,
-            emoji: None,
-            label: label.to_owned(),
-            value: value.to_owned(),
-        })
-        .collect::<Vec<SelectMenuOption>>();
+    let options = [
+        (
+            interaction
+                .locale
+                .text("voice_channel.modify_video_quality.auto"),
+            "1",
+        ),
+        ("720p", "2"),
+    ]
+    .into_iter()
+    .map(|(label, value)| SelectMenuOption {
+        default: false,
+        description: None,
+        emoji: None,
+        label: label.to_owned(),
+        value: value.to_owned(),
+    })
+    .collect::<Vec<SelectMenuOption>>();
     let components = vec![Component::ActionRow(ActionRow {
         components: vec![Component::SelectMenu(SelectMenu {
             channel_types: None,
@@ -32,7 +40,12 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             max_values: Some(1),
             min_values: Some(1),
             options: Some(options),
-            placeholder: Some("Modify video quality...".to_owned()),
+            placeholder: Some(
+                interaction
+                    .locale
+                    .text("voice_channel.modify_video_quality.placeholder")
+                    .to_owned(),
+            ),
         })],
     })];
     let interaction_response = create_interaction_response_select(components, true);
@@ -40,6 +53,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::utilities::request::ObserveRequest;
 use eyre::Result;
 
 use crate::structs::{context::Context, interaction::MessageComponentInteraction};
@@ -8,6 +9,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .client
         .delete_channel(interaction.voice_channel.id)
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -10,7 +10,7 @@ use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    utilities::{interaction::create_deferred_interaction_response, request::ObserveRequest},
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -19,6 +19,7 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
     let mut allowed_member_ids = interaction
@@ -31,11 +32,14 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             permission_overwrite
                 .kind
                 .eq(&ChannelPermissionOverwriteType::Member)
-                .then(|| permission_overwrite.id)
+                .then_some(permission_overwrite.id)
         })
         .collect::<Vec<Id<GenericMarker>>>();
     let members_text = if allowed_member_ids.is_empty() {
-        "No members have been added to this voice channel.".to_owned()
+        interaction
+            .locale
+            .text("voice_channel.view_information.no_members")
+            .to_owned()
     } else {
         let mut text = allowed_member_ids
             .iter()
@@ -46,7 +50,11 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
         if allowed_member_ids.len() > 5 {
             let remaining_members = allowed_member_ids.split_off(5);
 
-            text.push_str(&format!("\n +{} more", remaining_members.len()));
+            text.push('\n');
+            text.push_str(&interaction.locale.format(
+                "voice_channel.view_information.more",
+                &[("count", &remaining_members.len())],
+            ));
         }
 
         text
@@ -69,22 +77,45 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             permission_overwrite.deny
         });
     let privacy_text = if everyone_deny.contains(Permissions::VIEW_CHANNEL) {
-        "This voice channel is invisible."
+        interaction
+            .locale
+            .text("voice_channel.view_information.invisible")
     } else if everyone_deny.contains(Permissions::CONNECT) {
-        "This voice channel is locked and visible."
+        interaction
+            .locale
+            .text("voice_channel.view_information.locked")
     } else {
-        "This voice channel is unlocked and visible."
+        interaction
+            .locale
+            .text("voice_channel.view_information.unlocked")
     };
     let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .field(EmbedFieldBuilder::new("Allowed member(s)", members_text).build())
-        .field(EmbedFieldBuilder::new("Privacy", privacy_text).build())
+        .color(interaction.color)
+        .field(
+            EmbedFieldBuilder::new(
+                interaction
+                    .locale
+                    .text("voice_channel.view_information.members"),
+                members_text,
+            )
+            .build(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                interaction
+                    .locale
+                    .text("voice_channel.view_information.privacy"),
+                privacy_text,
+            )
+            .build(),
+        )
         .build();
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
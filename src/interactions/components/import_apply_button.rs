@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use eyre::Result;
+
+use crate::{
+    structs::{context::Context, interaction::GuildMessageComponentInteraction},
+    utilities::{
+        guild_export::{diff_guild_export, import_guild},
+        interaction::{create_deferred_update_response, create_response_embed, import_id},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(
+    context: Arc<Context>,
+    interaction: GuildMessageComponentInteraction,
+) -> Result<()> {
+    let interaction_response = create_deferred_update_response();
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+
+    let locale = interaction.locale;
+    let description = match context
+        .pending_imports
+        .remove_if(&import_id(&interaction)?, |_, pending_import| {
+            pending_import.guild_id == interaction.guild.id
+        })
+        .map(|(_, pending_import)| pending_import)
+        .filter(|pending_import| !pending_import.is_expired())
+    {
+        Some(pending_import) => {
+            let guild_export = pending_import.guild_export;
+            let channel_templates = context
+                .database
+                .guild_channel_templates(interaction.guild.id)
+                .await?;
+            // The limits or the server may have changed since the import was previewed.
+            let result = guild_export
+                .validate(&context.config.limits, locale)
+                .and_then(|()| {
+                    diff_guild_export(
+                        &context,
+                        &interaction.guild,
+                        &channel_templates,
+                        &guild_export,
+                        locale,
+                    )
+                });
+
+            match result {
+                Ok(_) => {
+                    import_guild(&context, &interaction.guild, guild_export).await?;
+
+                    locale.text("settings.import.applied").to_owned()
+                }
+                Err(report) => locale.format("settings.import.invalid", &[("reason", &report)]),
+            }
+        }
+        None => locale.text("settings.import.expired").to_owned(),
+    };
+    let embed = create_response_embed(interaction.color, description);
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .components(Some(&[]))
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
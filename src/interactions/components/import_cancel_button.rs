@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use eyre::Result;
+
+use crate::{
+    structs::{context::Context, interaction::GuildMessageComponentInteraction},
+    utilities::{
+        interaction::{create_deferred_update_response, create_response_embed, import_id},
+        request::ObserveRequest,
+    },
+};
+
+pub async fn run(
+    context: Arc<Context>,
+    interaction: GuildMessageComponentInteraction,
+) -> Result<()> {
+    let interaction_response = create_deferred_update_response();
+
+    context
+        .interaction_client()
+        .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
+        .await?;
+    context
+        .pending_imports
+        .remove_if(&import_id(&interaction)?, |_, pending_import| {
+            pending_import.guild_id == interaction.guild.id
+        });
+
+    let embed = create_response_embed(
+        interaction.color,
+        interaction.locale.text("settings.import.cancelled"),
+    );
+
+    context
+        .interaction_client()
+        .update_response(&interaction.token)
+        .components(Some(&[]))
+        .embeds(Some(&[embed]))
+        .observe(&context)
+        .await?;
+
+    Ok(())
+}
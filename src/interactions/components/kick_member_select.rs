@@ -2,11 +2,13 @@ use std::sync::Arc;
 
 use eyre::Result;
 use twilight_model::id::{marker::UserMarker, Id};
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, select_value},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -15,9 +17,10 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let user_id: Id<UserMarker> = interaction.data.values.iter().nth(0).unwrap().parse()?;
+    let user_id: Id<UserMarker> = select_value(&interaction.data)?.parse()?;
     let description = if interaction
         .voice_channel
         .connected_user_ids
@@ -28,21 +31,25 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             .client
             .update_guild_member(interaction.voice_channel.guild_id, user_id)
             .channel_id(None)
+            .observe(&context)
             .await?;
 
-        format!("I've removed <@{user_id}> from this voice channel!")
+        interaction
+            .locale
+            .format("voice_channel.kick_member.kicked", &[("user_id", &user_id)])
     } else {
-        format!("<@{user_id}> isn't in this voice channel!")
+        interaction.locale.format(
+            "voice_channel.kick_member.not_connected",
+            &[("user_id", &user_id)],
+        )
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,9 +1,12 @@
 pub mod allow_member_select;
+pub mod apply_template_select;
 pub mod deny_member_select;
 pub mod edit_channel_select;
+pub mod import_apply_button;
+pub mod import_cancel_button;
 pub mod kick_member_select;
 pub mod modify_privacy_select;
 pub mod modify_slowmode_select;
 pub mod modify_video_quality_select;
 pub mod remove_member_select;
-pub mod transfer_select;
\ No newline at end of file
+pub mod transfer_select;
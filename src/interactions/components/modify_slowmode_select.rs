@@ -1,11 +1,16 @@
-use std::{collections::HashMap, sync::Arc};
+use std::sync::Arc;
 
 use eyre::Result;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
-    structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    structs::{context::Context, error::Error, interaction::MessageComponentInteraction},
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_response_embed, select_value,
+            slowmode_label,
+        },
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -14,43 +19,34 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let slowmode_option = interaction.data.values.clone().into_iter().nth(0).unwrap();
+    let slowmode_option = select_value(&interaction.data)?;
     let amount = slowmode_option.parse::<u16>()?;
 
+    if !context.config.limits.slowmode_options.contains(&amount) {
+        return Err(Error::MissingData("slowmode option").into());
+    }
+
     context
         .client
         .update_channel(interaction.voice_channel.id)
         .rate_limit_per_user(amount)
+        .observe(&context)
         .await?;
 
-    let options: HashMap<u16, &str> = HashMap::from_iter(vec![
-        (0, "Off"),
-        (5, "5s"),
-        (10, "10s"),
-        (15, "15s"),
-        (30, "30s"),
-        (60, "1m"),
-        (120, "2m"),
-        (300, "5m"),
-        (600, "10m"),
-        (900, "15m"),
-        (1800, "30m"),
-        (3600, "1h"),
-        (7200, "2h"),
-        (21600, "6h"),
-    ]);
-    let description = format!(
-        "I've modified the slowmode to {} in this voice channel!",
-        options.get(&amount).cloned().unwrap().to_owned()
+    let description = interaction.locale.format(
+        "voice_channel.modify_slowmode.modified",
+        &[("slowmode", &slowmode_label(amount, interaction.locale))],
     );
-    let embed = EmbedBuilder::new().color(0xF8F8FF).description(description).build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
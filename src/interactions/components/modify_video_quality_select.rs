@@ -2,11 +2,13 @@ use std::sync::Arc;
 
 use eyre::Result;
 use twilight_model::channel::VideoQualityMode;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, select_value},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -15,31 +17,38 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let video_quality_option = interaction.data.values.clone().into_iter().nth(0).unwrap();
+    let video_quality_option = select_value(&interaction.data)?;
     let (mode, text) = match video_quality_option.as_str() {
         "2" => (VideoQualityMode::Full, "720p"),
-        _ => (VideoQualityMode::Auto, "Auto"),
+        _ => (
+            VideoQualityMode::Auto,
+            interaction
+                .locale
+                .text("voice_channel.modify_video_quality.auto"),
+        ),
     };
 
     context
         .client
         .update_channel(interaction.voice_channel.id)
         .video_quality_mode(mode)
+        .observe(&context)
         .await?;
 
-    let description =
-        format!("I've modified the video quality to **{text}** in this voice channel!");
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let description = interaction.locale.format(
+        "voice_channel.modify_video_quality.modified",
+        &[("quality", &text)],
+    );
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
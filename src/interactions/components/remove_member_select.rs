@@ -1,12 +1,17 @@
 use std::{collections::HashSet, sync::Arc};
 
 use eyre::Result;
-use twilight_model::{channel::permission_overwrite::PermissionOverwriteType as ChannelPermissionOverwriteType, id::{marker::UserMarker, Id}};
-use twilight_util::builder::embed::EmbedBuilder;
+use twilight_model::{
+    channel::permission_overwrite::PermissionOverwriteType as ChannelPermissionOverwriteType,
+    id::{marker::UserMarker, Id},
+};
 
 use crate::{
-    structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    structs::{context::Context, error::Error, interaction::MessageComponentInteraction},
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, resolved_user},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -15,23 +20,20 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let user_id = interaction
-        .data
-        .resolved
-        .unwrap()
-        .users
-        .values()
-        .nth(0)
-        .unwrap()
-        .id;
+    let user_id = resolved_user(&interaction.data)?.id;
     let protected_user_ids: HashSet<Id<UserMarker>> = HashSet::from_iter(vec![
-        interaction.voice_channel.owner_id.read().clone().unwrap(),
+        (*interaction.voice_channel.owner_id.read())
+            .ok_or(Error::MissingData("voice channel owner"))?,
         context.application_id.cast(),
     ]);
     let description = if protected_user_ids.contains(&user_id) {
-        "This user may not be removed!".to_owned()
+        interaction
+            .locale
+            .text("voice_channel.remove_member.protected")
+            .to_owned()
     } else if interaction
         .voice_channel
         .permission_overwrites
@@ -46,25 +48,30 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
         })
         .is_none()
     {
-        "This user does not have permissions!".to_owned()
+        interaction
+            .locale
+            .text("voice_channel.remove_member.no_permissions")
+            .to_owned()
     } else {
         context
             .client
             .delete_channel_permission(interaction.voice_channel.id)
             .member(user_id)
+            .observe(&context)
             .await?;
 
-        format!("I've removed permissions for <@{user_id}>!")
+        interaction.locale.format(
+            "voice_channel.remove_member.removed",
+            &[("user_id", &user_id)],
+        )
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description.to_owned())
-        .build();
+    let embed = create_response_embed(interaction.color, description.to_owned());
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::MessageComponentInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    utilities::{
+        interaction::{create_deferred_interaction_response, create_response_embed, resolved_user},
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction) -> Result<()> {
@@ -14,26 +16,25 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let user = interaction
-        .data
-        .resolved
-        .unwrap()
-        .users
-        .values()
-        .cloned()
-        .nth(0)
-        .unwrap();
+    let user = resolved_user(&interaction.data)?;
     let user_id = user.id;
     let description = if user.bot {
-        format!("You can't transfer this voice channel to this user!")
+        interaction
+            .locale
+            .text("voice_channel.transfer.bot")
+            .to_owned()
     } else if context
         .cache
         .voice_channel_owner(interaction.voice_channel.guild_id, user_id)
         .is_some()
     {
-        format!("<@{user_id}> already owns a voice channel!")
+        interaction.locale.format(
+            "voice_channel.transfer.already_owner",
+            &[("user_id", &user_id)],
+        )
     } else {
         context
             .database
@@ -43,17 +44,18 @@ pub async fn run(context: Arc<Context>, interaction: MessageComponentInteraction
             .cache
             .update_voice_channel_owner(interaction.voice_channel.id, Some(user_id));
 
-        format!("<@{user_id}> now owns this voice channel!")
+        interaction.locale.format(
+            "voice_channel.transfer.transferred",
+            &[("user_id", &user_id)],
+        )
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
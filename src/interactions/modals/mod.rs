@@ -1,3 +1,3 @@
 pub mod modify_bitrate_modal;
 pub mod modify_name_modal;
-pub mod modify_user_limit_modal;
\ No newline at end of file
+pub mod modify_user_limit_modal;
@@ -1,11 +1,15 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     structs::{context::Context, interaction::ModalSubmitInteraction},
-    utilities::interaction::create_deferred_interaction_response,
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_response_embed, text_input_value,
+        },
+        request::ObserveRequest,
+    },
 };
 
 pub async fn run(context: Arc<Context>, interaction: ModalSubmitInteraction) -> Result<()> {
@@ -14,36 +18,43 @@ pub async fn run(context: Arc<Context>, interaction: ModalSubmitInteraction) ->
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let bitrate_value = interaction.data.components[0].components[0]
-        .value
-        .to_owned()
-        .unwrap();
+    let min_bitrate = context.config.limits.min_bitrate;
+    let max_bitrate = context.config.limits.max_bitrate;
+    let bitrate_value = text_input_value(&interaction.data)?;
     let description = if let Ok(bitrate) = bitrate_value.parse::<u32>() {
-        if (8..=96).contains(&bitrate) {
+        if (min_bitrate..=max_bitrate).contains(&bitrate) {
             context
                 .client
                 .update_channel(interaction.voice_channel.id)
-                .bitrate(u32::from(bitrate * 1000))
+                .bitrate(bitrate * 1000)
+                .observe(&context)
                 .await?;
 
-            format!("I've changed the bitrate to {bitrate}kbps!")
+            interaction.locale.format(
+                "voice_channel.modify_bitrate.modified",
+                &[("bitrate", &bitrate)],
+            )
         } else {
-            "The bitrate must be between 8 and 96, inclusive.".to_owned()
+            interaction.locale.format(
+                "interaction.bitrate_out_of_range",
+                &[("min_bitrate", &min_bitrate), ("max_bitrate", &max_bitrate)],
+            )
         }
     } else {
-        format!("{bitrate_value} is not a valid integer!")
+        interaction
+            .locale
+            .format("interaction.invalid_integer", &[("value", &bitrate_value)])
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,9 +1,16 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use twilight_util::builder::embed::EmbedBuilder;
 
-use crate::{structs::{context::Context, interaction::ModalSubmitInteraction}, utilities::interaction::create_deferred_interaction_response};
+use crate::{
+    structs::{context::Context, interaction::ModalSubmitInteraction},
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_response_embed, text_input_value,
+        },
+        request::ObserveRequest,
+    },
+};
 
 pub async fn run(context: Arc<Context>, interaction: ModalSubmitInteraction) -> Result<()> {
     let interaction_response = create_deferred_interaction_response(true);
@@ -11,31 +18,32 @@ pub async fn run(context: Arc<Context>, interaction: ModalSubmitInteraction) ->
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let name = interaction.data.components[0].components[0]
-        .value
-        .to_owned()
-        .unwrap();
+    let name = text_input_value(&interaction.data)?;
     let description = context
         .client
         .update_channel(interaction.voice_channel.id)
         .name(&name)
+        .observe(&context)
         .await
         .map_or(
-            "I'm unable to rename the voice channel right now, try again in ten minutes",
-            |_| "I've changed the name!",
+            interaction.locale.text("voice_channel.modify_name.failed"),
+            |_| {
+                interaction
+                    .locale
+                    .text("voice_channel.modify_name.modified")
+            },
         )
         .to_owned();
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
@@ -1,9 +1,16 @@
 use std::sync::Arc;
 
 use eyre::Result;
-use twilight_util::builder::embed::EmbedBuilder;
 
-use crate::{structs::{context::Context, interaction::ModalSubmitInteraction}, utilities::interaction::create_deferred_interaction_response};
+use crate::{
+    structs::{context::Context, interaction::ModalSubmitInteraction},
+    utilities::{
+        interaction::{
+            create_deferred_interaction_response, create_response_embed, text_input_value,
+        },
+        request::ObserveRequest,
+    },
+};
 
 pub async fn run(context: Arc<Context>, interaction: ModalSubmitInteraction) -> Result<()> {
     let interaction_response = create_deferred_interaction_response(true);
@@ -11,34 +18,42 @@ pub async fn run(context: Arc<Context>, interaction: ModalSubmitInteraction) ->
     context
         .interaction_client()
         .create_response(interaction.id, &interaction.token, &interaction_response)
+        .observe(&context)
         .await?;
 
-    let user_limit_value = interaction.data.components[0].components[0]
-        .value
-        .to_owned()
-        .unwrap();
+    let user_limit_value = text_input_value(&interaction.data)?;
     let description = if let Ok(user_limit) = user_limit_value.parse::<u16>() {
         context
             .client
             .update_channel(interaction.voice_channel.id)
             .user_limit(user_limit)
+            .observe(&context)
             .await
             .map_or(
-                "The user limit must be between 0 and 99, inclusive.".to_owned(),
-                |_| format!("I've changed the user limit to {user_limit}!"),
+                interaction
+                    .locale
+                    .text("voice_channel.modify_user_limit.out_of_range")
+                    .to_owned(),
+                |_| {
+                    interaction.locale.format(
+                        "voice_channel.modify_user_limit.modified",
+                        &[("user_limit", &user_limit)],
+                    )
+                },
             )
     } else {
-        format!("{user_limit_value} is not a valid integer!")
+        interaction.locale.format(
+            "interaction.invalid_integer",
+            &[("value", &user_limit_value)],
+        )
     };
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(interaction.color, description);
 
     context
         .interaction_client()
         .update_response(&interaction.token)
         .embeds(Some(&[embed]))
+        .observe(&context)
         .await?;
 
     Ok(())
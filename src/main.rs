@@ -1,79 +1,279 @@
+mod cli;
 mod events;
 mod interactions;
+mod server;
 mod structs;
+#[cfg(test)]
+mod tests;
 mod utilities;
 
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+use std::{
+    collections::HashMap,
+    env,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
 
-use events::handle_event;
-use eyre::Result;
-use structs::context::Context;
-use tokio::signal;
-use twilight_gateway::{create_recommended, CloseFrame, Config, Event, Shard, StreamExt};
+use cli::{Command, USAGE};
+use eyre::{Result, WrapErr};
+use structs::{cache::CacheSnapshot, config::Config, context::Context};
+use tokio::{signal, time::timeout};
+use tracing::{info, info_span, warn, Instrument};
+use twilight_gateway::{
+    create_recommended, CloseFrame, Config as ShardConfig, Event, Session, Shard, StreamExt,
+};
 use twilight_http::Client;
-use utilities::constants::{COMMANDS, DISCORD_TOKEN, INTENTS, WANTED_EVENT_TYPES};
+use utilities::{
+    consistency,
+    constants::{INTENTS, WANTED_EVENT_TYPES},
+    error::install_panic_hook,
+    logging::init_logging,
+    registration,
+};
 
 static SHUTDOWN: AtomicBool = AtomicBool::new(false);
 
-async fn runner(mut shard: Shard, context: Arc<Context>) -> Result<()> {
+async fn runner(mut shard: Shard, context: Arc<Context>) -> Shard {
     while let Some(item) = shard.next_event(*WANTED_EVENT_TYPES).await {
+        context
+            .health
+            .update_shard_state(shard.id().number(), shard.state());
+
         match item {
             Ok(Event::GatewayClose(_)) if SHUTDOWN.load(Ordering::Relaxed) => break,
             Ok(event) => {
-                let event_context = Arc::clone(&context);
-
-                tokio::spawn(async move { handle_event(event_context, event).await.unwrap() })
+                context.health.record_event();
+                context
+                    .metrics
+                    .gateway_events
+                    .with_label_values(&[event.kind().name().unwrap_or("GATEWAY")])
+                    .inc();
+                context.event_queue.push(Arc::clone(&context), event);
             }
-            Err(_source) => {
+            Err(source) => {
+                warn!(?source, "Unable to receive gateway event");
+
                 continue;
             }
         };
     }
 
+    shard
+}
+
+async fn save_session(context: &Context, shard: &Shard) -> Result<()> {
+    let Some(session) = shard.session() else {
+        return Ok(());
+    };
+    let shard_id = shard.id();
+    let cache = context.cache.snapshot(|guild_id| {
+        (guild_id.get() >> 22) % u64::from(shard_id.total()) == u64::from(shard_id.number())
+    });
+
+    context
+        .database
+        .insert_shard_session(
+            shard_id.number(),
+            shard_id.total(),
+            session.id(),
+            session.sequence(),
+            shard.resume_url(),
+            &serde_json::to_string(&cache)?,
+        )
+        .await?;
+    info!(sequence = session.sequence(), "Saved gateway session");
+
+    Ok(())
+}
+
+async fn shutdown_signal() -> Result<()> {
+    #[cfg(unix)]
+    {
+        let mut terminate = signal::unix::signal(signal::unix::SignalKind::terminate())?;
+
+        tokio::select! {
+            result = signal::ctrl_c() => result?,
+            _ = terminate.recv() => {}
+        }
+    }
+
+    #[cfg(not(unix))]
+    signal::ctrl_c().await?;
+
     Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    dotenvy::dotenv()?;
+    install_panic_hook();
+
+    let command = Command::parse(env::args().skip(1))?;
+
+    if command == Some(Command::Help) {
+        println!("{USAGE}");
+
+        return Ok(());
+    }
+
+    if let Err(source) = dotenvy::dotenv() {
+        if !source.not_found() {
+            return Err(source.into());
+        }
+    }
+
+    let config = Config::load()?;
+
+    init_logging(&config.log)?;
+
+    match command {
+        Some(command) => cli::run(command, config).await,
+        None => run(config).await,
+    }
+}
 
-    let client = Client::new(DISCORD_TOKEN.to_owned());
+async fn run(config: Config) -> Result<()> {
+    let token = config.discord.token.clone();
+    let client = Client::new(token.clone());
     let application_id = client.current_user_application().await?.model().await?.id;
-    let config = Config::new(DISCORD_TOKEN.to_owned(), *INTENTS);
-    let shards = create_recommended(&client, config, |_, builder| builder.build()).await?;
+    let context = Arc::new(Context::new(client, application_id, config)?);
+    let session_resumption = context.config.features.session_resumption;
+
+    context
+        .database
+        .ping()
+        .await
+        .wrap_err("Unable to connect to the database.")?;
+    context.database.create_tables().await?;
+    events::recover_voice_channel_creations(&context).await?;
+
+    let stored_sessions = if session_resumption {
+        context.database.take_shard_sessions().await?
+    } else {
+        Vec::new()
+    };
+    let mut sessions = stored_sessions
+        .into_iter()
+        .filter_map(
+            |session| match serde_json::from_str::<CacheSnapshot>(&session.cache) {
+                Ok(cache) => Some((session.id, (session, cache))),
+                Err(source) => {
+                    warn!(
+                        shard_id = session.id,
+                        ?source,
+                        "Unable to restore gateway session"
+                    );
+
+                    None
+                }
+            },
+        )
+        .collect::<HashMap<_, _>>();
+    let shard_config = ShardConfig::new(token, *INTENTS);
+    let shards = create_recommended(&context.client, shard_config, |shard_id, builder| {
+        let session = sessions
+            .get(&shard_id.number())
+            .map(|(session, _)| session)
+            .filter(|session| session.total == shard_id.total());
+
+        match session {
+            Some(session) => {
+                let builder =
+                    builder.session(Session::new(session.sequence, session.session_id.clone()));
+
+                match session.resume_url.clone() {
+                    Some(resume_url) => builder.resume_url(resume_url).build(),
+                    None => builder.build(),
+                }
+            }
+            None => builder.build(),
+        }
+    })
+    .await?
+    .collect::<Vec<_>>();
     let shard_count = shards.len();
     let mut senders = Vec::with_capacity(shard_count);
     let mut tasks = Vec::with_capacity(shard_count);
 
-    let context = Arc::new(Context::new(client, application_id));
+    server::serve(Arc::clone(&context)).await?;
 
-    context.database.create_tables().await?;
-
-    context
-        .interaction_client()
-        .set_global_commands(&COMMANDS)
-        .await?;
+    registration::sync_commands(&context).await?;
 
     for shard in shards {
         let shard_context = Arc::clone(&context);
+        let span = info_span!("shard", id = shard.id().number());
 
+        if let (Some(session), Some((_, cache))) =
+            (shard.session(), sessions.remove(&shard.id().number()))
+        {
+            context.cache.restore(cache);
+            info!(parent: &span, sequence = session.sequence(), "Resuming gateway session");
+        }
+
+        context
+            .health
+            .update_shard_state(shard.id().number(), shard.state());
         senders.push(shard.sender());
-        tasks.push(tokio::spawn(runner(shard, shard_context)))
+        tasks.push(tokio::spawn(runner(shard, shard_context).instrument(span)))
     }
 
-    signal::ctrl_c().await?;
+    info!(shard_count, "Started shards");
+
+    if context.config.consistency.interval_seconds > 0 {
+        tokio::spawn(consistency::run_periodically(Arc::clone(&context)));
+    }
+
+    shutdown_signal().await?;
+    info!("Shutting down");
     SHUTDOWN.store(true, Ordering::Relaxed);
 
+    let close_frame = if session_resumption {
+        CloseFrame::RESUME
+    } else {
+        CloseFrame::NORMAL
+    };
+
     for sender in senders {
-        _ = sender.close(CloseFrame::NORMAL);
+        _ = sender.close(close_frame.clone());
     }
 
+    let mut shards = Vec::with_capacity(shard_count);
+
     for join_handle in tasks {
-        _ = join_handle.await;
+        if let Ok(shard) = join_handle.await {
+            shards.push(shard);
+        }
+    }
+
+    context.event_queue.close();
+    context.tasks.close();
+
+    let drain_timeout = Duration::from_secs(context.config.shutdown.drain_timeout_seconds);
+
+    if timeout(drain_timeout, context.tasks.wait()).await.is_err() {
+        warn!(
+            task_count = context.tasks.len(),
+            "Timed out waiting for event handlers"
+        );
+        shards.clear();
     }
 
+    if !session_resumption {
+        shards.clear();
+    }
+
+    for shard in shards {
+        let span = info_span!("shard", id = shard.id().number());
+
+        if let Err(source) = save_session(&context, &shard).instrument(span).await {
+            warn!(?source, "Unable to save gateway session");
+        }
+    }
+
+    context.database.close();
+    info!("Shut down");
+
     Ok(())
 }
@@ -0,0 +1,34 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use serde::Serialize;
+use twilight_gateway::ShardState;
+
+use crate::{server::shard_state_name, structs::context::Context};
+
+#[derive(Serialize)]
+struct HealthResponse {
+    last_event_seconds_ago: Option<f64>,
+    shards: BTreeMap<u32, &'static str>,
+}
+
+pub async fn run(State(context): State<Arc<Context>>) -> impl IntoResponse {
+    let shard_states = context.health.shard_states();
+    let status = if shard_states
+        .values()
+        .any(|state| state.eq(&ShardState::FatallyClosed))
+    {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+    let response = HealthResponse {
+        last_event_seconds_ago: context.health.last_event_age().map(|age| age.as_secs_f64()),
+        shards: shard_states
+            .into_iter()
+            .map(|(shard_id, state)| (shard_id, shard_state_name(state)))
+            .collect(),
+    };
+
+    (status, Json(response))
+}
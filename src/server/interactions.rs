@@ -0,0 +1,85 @@
+use std::{sync::Arc, time::Duration};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use tokio::{sync::oneshot, time::timeout};
+use tracing::warn;
+use twilight_gateway::Event;
+use twilight_model::{
+    application::interaction::{Interaction, InteractionType},
+    gateway::payload::incoming::InteractionCreate,
+    http::interaction::{InteractionResponse, InteractionResponseType},
+};
+
+use crate::{events::handle_event, structs::context::Context};
+
+const INITIAL_RESPONSE_TIMEOUT: Duration = Duration::from_millis(2500);
+
+pub async fn run(State(context): State<Arc<Context>>, headers: HeaderMap, body: Bytes) -> Response {
+    let Some(public_key) = &context.config.discord.public_key else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    if !is_signature_valid(public_key, &headers, &body) {
+        return (StatusCode::UNAUTHORIZED, "invalid request signature").into_response();
+    }
+
+    let interaction = match serde_json::from_slice::<Interaction>(&body) {
+        Ok(interaction) => interaction,
+        Err(source) => {
+            warn!(%source, "Unable to deserialize HTTP interaction");
+
+            return StatusCode::BAD_REQUEST.into_response();
+        }
+    };
+
+    if interaction.kind == InteractionType::Ping {
+        return Json(InteractionResponse {
+            data: None,
+            kind: InteractionResponseType::Pong,
+        })
+        .into_response();
+    }
+
+    let guild_id = interaction.guild_id;
+    let event = Event::InteractionCreate(Box::new(InteractionCreate(interaction)));
+    let (handled_sender, handled_receiver) = oneshot::channel();
+    let handler = handle_event(Arc::clone(&context), event);
+
+    context
+        .event_queue
+        .push_job(Arc::clone(&context), guild_id, async move {
+            handler.await;
+            _ = handled_sender.send(());
+        });
+
+    _ = timeout(INITIAL_RESPONSE_TIMEOUT, handled_receiver).await;
+
+    StatusCode::ACCEPTED.into_response()
+}
+
+fn is_signature_valid(public_key: &VerifyingKey, headers: &HeaderMap, body: &[u8]) -> bool {
+    let (Some(signature), Some(timestamp)) = (
+        headers.get("x-signature-ed25519"),
+        headers.get("x-signature-timestamp"),
+    ) else {
+        return false;
+    };
+    let mut signature_bytes = [0; 64];
+
+    if hex::decode_to_slice(signature.as_bytes(), &mut signature_bytes).is_err() {
+        return false;
+    }
+
+    let message = [timestamp.as_bytes(), body].concat();
+
+    public_key
+        .verify(&message, &Signature::from_bytes(&signature_bytes))
+        .is_ok()
+}
@@ -0,0 +1,72 @@
+use std::sync::{atomic::Ordering, Arc};
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+};
+
+use crate::{structs::context::Context, utilities::error::PANIC_COUNT};
+
+pub async fn run(State(context): State<Arc<Context>>) -> impl IntoResponse {
+    let metrics = &context.metrics;
+    let cache_stats = context.cache.stats();
+    let event_queue_depth = context.event_queue.depth();
+
+    for (map, entries) in [
+        ("category_channels", cache_stats.category_channels),
+        ("guilds", cache_stats.guilds),
+        ("join_channels", cache_stats.join_channels),
+        ("unavailable_guilds", cache_stats.unavailable_guilds),
+        ("voice_channel_owners", cache_stats.voice_channel_owners),
+        ("voice_channels", cache_stats.voice_channels),
+        ("voice_states", cache_stats.voice_states),
+    ] {
+        metrics
+            .cache_entries
+            .with_label_values(&[map])
+            .set(entries as i64);
+    }
+
+    if let Some(connections) = context.database.connections() {
+        for (state, count) in [
+            ("available", connections.available),
+            ("max", connections.max_size),
+            ("size", connections.size),
+            ("waiting", connections.waiting),
+        ] {
+            metrics
+                .database_connections
+                .with_label_values(&[state])
+                .set(count as i64);
+        }
+    }
+
+    for (aggregate, depth) in [
+        ("guilds", event_queue_depth.guild_count),
+        ("max", event_queue_depth.max_depth),
+        ("total", event_queue_depth.total_depth),
+    ] {
+        metrics
+            .event_queue_depth
+            .with_label_values(&[aggregate])
+            .set(depth as i64);
+    }
+
+    // Panics are counted by the panic hook, which has no context, so the counter catches up here.
+    metrics.panics.inc_by(
+        PANIC_COUNT
+            .load(Ordering::Relaxed)
+            .saturating_sub(metrics.panics.get()),
+    );
+
+    match metrics.encode() {
+        Ok(body) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+            body,
+        )
+            .into_response(),
+        Err(source) => (StatusCode::INTERNAL_SERVER_ERROR, source.to_string()).into_response(),
+    }
+}
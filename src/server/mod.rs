@@ -0,0 +1,61 @@
+mod healthz;
+mod interactions;
+mod metrics;
+mod readyz;
+
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+use eyre::Result;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+use twilight_gateway::ShardState;
+
+use crate::structs::context::Context;
+
+pub async fn serve(context: Arc<Context>) -> Result<()> {
+    let features = &context.config.features;
+
+    if !features.http_interactions && !features.monitoring {
+        return Ok(());
+    }
+
+    let address = context.config.http.address;
+    let mut router = Router::new();
+
+    if features.http_interactions {
+        router = router.route("/interactions", post(interactions::run));
+    }
+
+    if features.monitoring {
+        router = router
+            .route("/healthz", get(healthz::run))
+            .route("/metrics", get(metrics::run))
+            .route("/readyz", get(readyz::run));
+    }
+
+    let router = router.with_state(Arc::clone(&context));
+    let listener = TcpListener::bind(address).await?;
+
+    info!(address = %listener.local_addr()?, "Serving HTTP");
+    tokio::spawn(async move {
+        if let Err(source) = axum::serve(listener, router).await {
+            error!(%source, "HTTP server stopped");
+        }
+    });
+
+    Ok(())
+}
+
+fn shard_state_name(state: ShardState) -> &'static str {
+    match state {
+        ShardState::Active => "active",
+        ShardState::Disconnected { .. } => "disconnected",
+        ShardState::FatallyClosed => "fatally_closed",
+        ShardState::Identifying => "identifying",
+        ShardState::Resuming => "resuming",
+    }
+}
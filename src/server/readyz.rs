@@ -0,0 +1,48 @@
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use serde::Serialize;
+use tokio::time::timeout;
+use twilight_gateway::ShardState;
+
+use crate::{server::shard_state_name, structs::context::Context};
+
+const DATABASE_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Serialize)]
+struct ReadinessResponse {
+    database_connected: bool,
+    last_event_seconds_ago: Option<f64>,
+    shards: BTreeMap<u32, &'static str>,
+    unavailable_guilds: usize,
+}
+
+pub async fn run(State(context): State<Arc<Context>>) -> impl IntoResponse {
+    let shard_states = context.health.shard_states();
+    let database_connected = timeout(DATABASE_TIMEOUT, context.database.ping())
+        .await
+        .is_ok_and(|result| result.is_ok());
+    let unavailable_guilds = context.cache.stats().unavailable_guilds;
+    let status = if database_connected
+        && unavailable_guilds.eq(&0)
+        && !shard_states.is_empty()
+        && shard_states
+            .values()
+            .all(|state| state.eq(&ShardState::Active))
+    {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let response = ReadinessResponse {
+        database_connected,
+        last_event_seconds_ago: context.health.last_event_age().map(|age| age.as_secs_f64()),
+        shards: shard_states
+            .into_iter()
+            .map(|(shard_id, state)| (shard_id, shard_state_name(state)))
+            .collect(),
+        unavailable_guilds,
+    };
+
+    (status, Json(response))
+}
@@ -1,9 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
+    mem::{replace, take},
     sync::Arc,
 };
 
+use dashmap::{DashMap, DashSet};
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
 use twilight_model::{
     channel::permission_overwrite::PermissionOverwrite as ChannelPermissionOverwrite,
     id::{
@@ -12,30 +15,127 @@ use twilight_model::{
     },
 };
 
+type GuildUserChannelMap = DashMap<(Id<GuildMarker>, Id<UserMarker>), Arc<Id<ChannelMarker>>>;
+
+// Every map is sharded, so handlers for different guilds rarely contend. Entries are cloned
+// out of the maps before another map is touched, which keeps shard guards from overlapping.
+// Each cached guild indexes its own entries, so per-guild lookups never walk the whole map.
 pub struct Cache {
-    category_channels: RwLock<HashMap<Id<ChannelMarker>, Arc<CachedCategoryChannel>>>,
-    guilds: RwLock<HashMap<Id<GuildMarker>, Arc<CachedGuild>>>,
-    unavailable_guilds: RwLock<HashSet<Id<GuildMarker>>>,
-    voice_channels: RwLock<HashMap<Id<ChannelMarker>, Arc<CachedVoiceChannel>>>,
-    voice_channel_owners:
-        RwLock<HashMap<(Id<GuildMarker>, Id<UserMarker>), Arc<Id<ChannelMarker>>>>,
-    voice_states: RwLock<HashMap<(Id<GuildMarker>, Id<UserMarker>), Arc<Id<ChannelMarker>>>>,
+    category_channels: DashMap<Id<ChannelMarker>, Arc<CachedCategoryChannel>>,
+    guilds: DashMap<Id<GuildMarker>, Arc<CachedGuild>>,
+    join_channels: DashMap<Id<ChannelMarker>, Arc<CachedJoinChannel>>,
+    unavailable_guilds: DashSet<Id<GuildMarker>>,
+    voice_channels: DashMap<Id<ChannelMarker>, Arc<CachedVoiceChannel>>,
+    voice_channel_owners: GuildUserChannelMap,
+    voice_states: GuildUserChannelMap,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CacheSnapshot {
+    category_channels: Vec<CategoryChannelSnapshot>,
+    guilds: Vec<GuildSnapshot>,
+    #[serde(default)]
+    join_channels: Vec<JoinChannelSnapshot>,
+    unavailable_guilds: Vec<Id<GuildMarker>>,
+    voice_channels: Vec<VoiceChannelSnapshot>,
+    voice_states: Vec<(Id<GuildMarker>, Id<UserMarker>, Id<ChannelMarker>)>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CategoryChannelSnapshot {
+    guild_id: Id<GuildMarker>,
+    id: Id<ChannelMarker>,
+    // Only read from snapshots taken before join channels had presets.
+    #[serde(default, skip_serializing)]
+    join_channel_id: Option<Id<ChannelMarker>>,
+    permission_overwrites: Vec<ChannelPermissionOverwrite>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct GuildSnapshot {
+    #[serde(default)]
+    appearance: GuildAppearance,
+    bot_role_id: Id<RoleMarker>,
+    id: Id<GuildMarker>,
+    #[serde(default)]
+    locale: Option<String>,
+    permanence: bool,
+    privacy: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct JoinChannelSnapshot {
+    bitrate: Option<u32>,
+    category_channel_id: Id<ChannelMarker>,
+    guild_id: Id<GuildMarker>,
+    id: Id<ChannelMarker>,
+    name_template: Option<String>,
+    privacy: Option<String>,
+    template_name: Option<String>,
+    user_limit: Option<u16>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct VoiceChannelSnapshot {
+    guild_id: Id<GuildMarker>,
+    id: Id<ChannelMarker>,
+    owner_id: Option<Id<UserMarker>>,
+    panel_message_id: Option<Id<MessageMarker>>,
+    parent_id: Id<ChannelMarker>,
+    permission_overwrites: Vec<ChannelPermissionOverwrite>,
 }
 
 pub struct CachedCategoryChannel {
     pub guild_id: Id<GuildMarker>,
     pub id: Id<ChannelMarker>,
-    pub join_channel_id: RwLock<Option<Id<ChannelMarker>>>,
+    pub join_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
     pub permission_overwrites: RwLock<Vec<ChannelPermissionOverwrite>>,
     pub voice_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
 }
 
 pub struct CachedGuild {
+    pub appearance: RwLock<GuildAppearance>,
     pub bot_role_id: Id<RoleMarker>,
     pub category_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
     pub id: Id<GuildMarker>,
+    pub join_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub locale: RwLock<Option<String>>,
     pub permanence: RwLock<bool>,
     pub privacy: RwLock<String>,
+    pub voice_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub voice_channel_owner_ids: RwLock<HashSet<Id<UserMarker>>>,
+    pub voice_state_user_ids: RwLock<HashSet<Id<UserMarker>>>,
+}
+
+#[derive(Clone)]
+pub struct CachedJoinChannel {
+    pub bitrate: Option<u32>,
+    pub category_channel_id: Id<ChannelMarker>,
+    pub guild_id: Id<GuildMarker>,
+    pub id: Id<ChannelMarker>,
+    pub name_template: Option<String>,
+    pub privacy: Option<String>,
+    pub template_name: Option<String>,
+    pub user_limit: Option<u16>,
+}
+
+#[derive(Clone, Default, Deserialize, PartialEq, Serialize)]
+pub struct GuildAppearance {
+    pub color: Option<u32>,
+    pub description: Option<String>,
+    pub footer: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub title: Option<String>,
+}
+
+pub struct CacheStats {
+    pub category_channels: usize,
+    pub guilds: usize,
+    pub join_channels: usize,
+    pub unavailable_guilds: usize,
+    pub voice_channel_owners: usize,
+    pub voice_channels: usize,
+    pub voice_states: usize,
 }
 
 #[derive(Debug)]
@@ -45,24 +145,173 @@ pub struct CachedVoiceChannel {
     pub id: Id<ChannelMarker>,
     pub owner_id: RwLock<Option<Id<UserMarker>>>,
     pub panel_message_id: RwLock<Option<Id<MessageMarker>>>,
-    pub parent_id: Id<ChannelMarker>,
+    pub parent_id: RwLock<Id<ChannelMarker>>,
     pub permission_overwrites: RwLock<Vec<ChannelPermissionOverwrite>>,
 }
 
 impl Cache {
     pub fn new() -> Self {
         Self {
-            category_channels: RwLock::new(HashMap::new()),
-            guilds: RwLock::new(HashMap::new()),
-            unavailable_guilds: RwLock::new(HashSet::new()),
-            voice_channel_owners: RwLock::new(HashMap::new()),
-            voice_channels: RwLock::new(HashMap::new()),
-            voice_states: RwLock::new(HashMap::new()),
+            category_channels: DashMap::new(),
+            guilds: DashMap::new(),
+            join_channels: DashMap::new(),
+            unavailable_guilds: DashSet::new(),
+            voice_channel_owners: DashMap::new(),
+            voice_channels: DashMap::new(),
+            voice_states: DashMap::new(),
+        }
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            category_channels: self.category_channels.len(),
+            guilds: self.guilds.len(),
+            join_channels: self.join_channels.len(),
+            unavailable_guilds: self.unavailable_guilds.len(),
+            voice_channel_owners: self.voice_channel_owners.len(),
+            voice_channels: self.voice_channels.len(),
+            voice_states: self.voice_states.len(),
+        }
+    }
+
+    pub fn snapshot(&self, is_included: impl Fn(Id<GuildMarker>) -> bool) -> CacheSnapshot {
+        CacheSnapshot {
+            category_channels: self
+                .category_channels
+                .iter()
+                .filter(|category_channel| is_included(category_channel.guild_id))
+                .map(|category_channel| CategoryChannelSnapshot {
+                    guild_id: category_channel.guild_id,
+                    id: category_channel.id,
+                    join_channel_id: None,
+                    permission_overwrites: category_channel.permission_overwrites.read().clone(),
+                })
+                .collect(),
+            guilds: self
+                .guilds
+                .iter()
+                .filter(|guild| is_included(guild.id))
+                .map(|guild| GuildSnapshot {
+                    appearance: guild.appearance.read().clone(),
+                    bot_role_id: guild.bot_role_id,
+                    id: guild.id,
+                    locale: guild.locale.read().clone(),
+                    permanence: *guild.permanence.read(),
+                    privacy: guild.privacy.read().clone(),
+                })
+                .collect(),
+            join_channels: self
+                .join_channels
+                .iter()
+                .filter(|join_channel| is_included(join_channel.guild_id))
+                .map(|join_channel| JoinChannelSnapshot {
+                    bitrate: join_channel.bitrate,
+                    category_channel_id: join_channel.category_channel_id,
+                    guild_id: join_channel.guild_id,
+                    id: join_channel.id,
+                    name_template: join_channel.name_template.clone(),
+                    privacy: join_channel.privacy.clone(),
+                    template_name: join_channel.template_name.clone(),
+                    user_limit: join_channel.user_limit,
+                })
+                .collect(),
+            unavailable_guilds: self
+                .unavailable_guilds
+                .iter()
+                .map(|guild_id| *guild_id)
+                .filter(|guild_id| is_included(*guild_id))
+                .collect(),
+            voice_channels: self
+                .voice_channels
+                .iter()
+                .filter(|voice_channel| is_included(voice_channel.guild_id))
+                .map(|voice_channel| VoiceChannelSnapshot {
+                    guild_id: voice_channel.guild_id,
+                    id: voice_channel.id,
+                    owner_id: *voice_channel.owner_id.read(),
+                    panel_message_id: *voice_channel.panel_message_id.read(),
+                    parent_id: *voice_channel.parent_id.read(),
+                    permission_overwrites: voice_channel.permission_overwrites.read().clone(),
+                })
+                .collect(),
+            voice_states: self
+                .voice_states
+                .iter()
+                .filter(|entry| is_included(entry.key().0))
+                .map(|entry| {
+                    let (guild_id, user_id) = *entry.key();
+
+                    (guild_id, user_id, **entry.value())
+                })
+                .collect(),
+        }
+    }
+
+    pub fn restore(&self, snapshot: CacheSnapshot) {
+        for guild_id in snapshot.unavailable_guilds {
+            self.insert_unavailable_guild(guild_id);
+        }
+
+        for guild in snapshot.guilds {
+            self.insert_guild(guild.id, guild.bot_role_id, guild.permanence, guild.privacy);
+            self.update_locale(guild.id, guild.locale);
+            self.update_appearance(guild.id, guild.appearance);
+        }
+
+        for category_channel in snapshot.category_channels {
+            self.insert_category_channel(
+                category_channel.guild_id,
+                category_channel.id,
+                category_channel.permission_overwrites,
+                [],
+            );
+
+            if let Some(join_channel_id) = category_channel.join_channel_id {
+                self.insert_join_channel(CachedJoinChannel {
+                    bitrate: None,
+                    category_channel_id: category_channel.id,
+                    guild_id: category_channel.guild_id,
+                    id: join_channel_id,
+                    name_template: None,
+                    privacy: None,
+                    template_name: None,
+                    user_limit: None,
+                });
+            }
+        }
+
+        for join_channel in snapshot.join_channels {
+            self.insert_join_channel(CachedJoinChannel {
+                bitrate: join_channel.bitrate,
+                category_channel_id: join_channel.category_channel_id,
+                guild_id: join_channel.guild_id,
+                id: join_channel.id,
+                name_template: join_channel.name_template,
+                privacy: join_channel.privacy,
+                template_name: join_channel.template_name,
+                user_limit: join_channel.user_limit,
+            });
+        }
+
+        for voice_channel in snapshot.voice_channels {
+            self.insert_voice_channel(
+                [],
+                voice_channel.guild_id,
+                voice_channel.id,
+                voice_channel.owner_id,
+                voice_channel.panel_message_id,
+                voice_channel.parent_id,
+                voice_channel.permission_overwrites,
+            );
+        }
+
+        for (guild_id, user_id, channel_id) in snapshot.voice_states {
+            self.insert_voice_state(guild_id, channel_id, user_id);
         }
     }
 
     pub fn insert_unavailable_guild(&self, id: Id<GuildMarker>) {
-        self.unavailable_guilds.write().insert(id);
+        self.unavailable_guilds.insert(id);
     }
 
     pub fn insert_guild(
@@ -72,24 +321,33 @@ impl Cache {
         permanence: bool,
         privacy: String,
     ) {
-        self.unavailable_guilds.write().remove(&id);
-        self.guilds.write().insert(
+        // The guild's channels and voice states outlive a replaced entry, so their indexes do too.
+        let previous_guild = self.guild(id);
+        let previous_guild = previous_guild.as_deref();
+        let guild = CachedGuild {
+            appearance: RwLock::new(GuildAppearance::default()),
+            bot_role_id,
+            category_channel_ids: take_index(previous_guild, |guild| &guild.category_channel_ids),
             id,
-            Arc::new(CachedGuild {
-                bot_role_id,
-                category_channel_ids: RwLock::new(HashSet::new()),
-                id,
-                permanence: RwLock::new(permanence),
-                privacy: RwLock::new(privacy),
+            join_channel_ids: take_index(previous_guild, |guild| &guild.join_channel_ids),
+            locale: RwLock::new(None),
+            permanence: RwLock::new(permanence),
+            privacy: RwLock::new(privacy),
+            voice_channel_ids: take_index(previous_guild, |guild| &guild.voice_channel_ids),
+            voice_channel_owner_ids: take_index(previous_guild, |guild| {
+                &guild.voice_channel_owner_ids
             }),
-        );
+            voice_state_user_ids: take_index(previous_guild, |guild| &guild.voice_state_user_ids),
+        };
+
+        self.unavailable_guilds.remove(&id);
+        self.guilds.insert(id, Arc::new(guild));
     }
 
     pub fn insert_category_channel(
         &self,
         guild_id: Id<GuildMarker>,
         id: Id<ChannelMarker>,
-        join_channel_id: Option<Id<ChannelMarker>>,
         permission_overwrites: Vec<ChannelPermissionOverwrite>,
         voice_channel_ids: impl IntoIterator<Item = Id<ChannelMarker>>,
     ) {
@@ -97,22 +355,164 @@ impl Cache {
             guild.category_channel_ids.write().insert(id);
         }
 
-        self.category_channels.write().insert(
+        self.category_channels.insert(
             id,
             Arc::new(CachedCategoryChannel {
                 guild_id,
                 id,
-                join_channel_id: RwLock::new(join_channel_id),
+                join_channel_ids: RwLock::new(HashSet::new()),
                 permission_overwrites: RwLock::new(permission_overwrites),
                 voice_channel_ids: RwLock::new(HashSet::from_iter(voice_channel_ids)),
             }),
         );
     }
 
+    pub fn insert_join_channel(&self, join_channel: CachedJoinChannel) {
+        if let Some(guild) = self.guild(join_channel.guild_id) {
+            guild.join_channel_ids.write().insert(join_channel.id);
+        }
+
+        if let Some(category_channel) = self.category_channel(join_channel.category_channel_id) {
+            category_channel
+                .join_channel_ids
+                .write()
+                .insert(join_channel.id);
+        }
+
+        self.join_channels
+            .insert(join_channel.id, Arc::new(join_channel));
+    }
+
+    pub fn join_channel(&self, channel_id: Id<ChannelMarker>) -> Option<Arc<CachedJoinChannel>> {
+        self.join_channels
+            .get(&channel_id)
+            .map(|join_channel| Arc::clone(join_channel.value()))
+    }
+
+    pub fn guild_join_channels(&self, guild_id: Id<GuildMarker>) -> Vec<Arc<CachedJoinChannel>> {
+        guild_index(self.guild(guild_id), |guild| &guild.join_channel_ids)
+            .into_iter()
+            .filter_map(|channel_id| self.join_channel(channel_id))
+            .collect()
+    }
+
+    pub fn update_join_channel_category(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        category_channel_id: Id<ChannelMarker>,
+    ) {
+        let Some(join_channel) = self.join_channel(channel_id) else {
+            return;
+        };
+
+        self.remove_join_channel(channel_id);
+        self.insert_join_channel(CachedJoinChannel {
+            category_channel_id,
+            ..(*join_channel).clone()
+        });
+    }
+
+    pub fn remove_join_channel(&self, channel_id: Id<ChannelMarker>) {
+        if let Some((_, join_channel)) = self.join_channels.remove(&channel_id) {
+            if let Some(guild) = self.guild(join_channel.guild_id) {
+                guild.join_channel_ids.write().remove(&channel_id);
+            }
+
+            if let Some(category_channel) = self.category_channel(join_channel.category_channel_id)
+            {
+                category_channel
+                    .join_channel_ids
+                    .write()
+                    .remove(&channel_id);
+            }
+        }
+    }
+
     pub fn guild(&self, guild_id: Id<GuildMarker>) -> Option<Arc<CachedGuild>> {
-        self.guilds.read().get(&guild_id).cloned()
+        self.guilds
+            .get(&guild_id)
+            .map(|guild| Arc::clone(guild.value()))
+    }
+
+    pub fn guild_ids(&self) -> Vec<Id<GuildMarker>> {
+        self.guilds.iter().map(|guild| *guild.key()).collect()
+    }
+
+    pub fn guild_category_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Vec<Arc<CachedCategoryChannel>> {
+        guild_index(self.guild(guild_id), |guild| &guild.category_channel_ids)
+            .into_iter()
+            .filter_map(|channel_id| self.category_channel(channel_id))
+            .collect()
+    }
+
+    pub fn guild_voice_channels(&self, guild_id: Id<GuildMarker>) -> Vec<Arc<CachedVoiceChannel>> {
+        guild_index(self.guild(guild_id), |guild| &guild.voice_channel_ids)
+            .into_iter()
+            .filter_map(|channel_id| self.voice_channel(channel_id))
+            .collect()
+    }
+
+    pub fn guild_voice_channel_owners(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Vec<(Id<UserMarker>, Id<ChannelMarker>)> {
+        guild_entries(
+            &self.voice_channel_owners,
+            guild_id,
+            guild_index(self.guild(guild_id), |guild| &guild.voice_channel_owner_ids),
+        )
+    }
+
+    pub fn guild_voice_states(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Vec<(Id<UserMarker>, Id<ChannelMarker>)> {
+        guild_entries(
+            &self.voice_states,
+            guild_id,
+            guild_index(self.guild(guild_id), |guild| &guild.voice_state_user_ids),
+        )
+    }
+
+    pub fn clear_guild_voice_states(&self, guild_id: Id<GuildMarker>) {
+        let user_ids = guild_index(self.guild(guild_id), |guild| &guild.voice_state_user_ids);
+
+        for user_id in user_ids {
+            self.remove_voice_state(guild_id, user_id);
+        }
     }
 
+    // Drops the guild's channels and owners without touching its voice states.
+    pub fn clear_guild_channels(&self, guild_id: Id<GuildMarker>) {
+        let Some(guild) = self.guild(guild_id) else {
+            return;
+        };
+        let category_channel_ids = take(&mut *guild.category_channel_ids.write());
+        let join_channel_ids = take(&mut *guild.join_channel_ids.write());
+        let voice_channel_ids = take(&mut *guild.voice_channel_ids.write());
+        let owner_ids = take(&mut *guild.voice_channel_owner_ids.write());
+
+        for channel_id in category_channel_ids {
+            self.category_channels.remove(&channel_id);
+        }
+
+        for channel_id in join_channel_ids {
+            self.join_channels.remove(&channel_id);
+        }
+
+        for channel_id in voice_channel_ids {
+            self.voice_channels.remove(&channel_id);
+        }
+
+        for owner_id in owner_ids {
+            self.voice_channel_owners.remove(&(guild_id, owner_id));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn insert_voice_channel(
         &self,
         connected_user_ids: impl IntoIterator<Item = Id<UserMarker>>,
@@ -123,11 +523,19 @@ impl Cache {
         parent_id: Id<ChannelMarker>,
         permission_overwrites: Vec<ChannelPermissionOverwrite>,
     ) {
+        if let Some(guild) = self.guild(guild_id) {
+            guild.voice_channel_ids.write().insert(id);
+
+            if let Some(owner_id) = owner_id {
+                guild.voice_channel_owner_ids.write().insert(owner_id);
+            }
+        }
+
         if let Some(category_channel) = self.category_channel(parent_id) {
             category_channel.voice_channel_ids.write().insert(id);
         };
 
-        self.voice_channels.write().insert(
+        self.voice_channels.insert(
             id,
             Arc::new(CachedVoiceChannel {
                 connected_user_ids: RwLock::new(HashSet::from_iter(connected_user_ids)),
@@ -135,14 +543,13 @@ impl Cache {
                 id,
                 owner_id: RwLock::new(owner_id),
                 panel_message_id: RwLock::new(panel_message_id),
-                parent_id,
+                parent_id: RwLock::new(parent_id),
                 permission_overwrites: RwLock::new(permission_overwrites),
             }),
         );
 
         if let Some(owner_id) = owner_id {
             self.voice_channel_owners
-                .write()
                 .insert((guild_id, owner_id), Arc::new(id));
         }
     }
@@ -151,7 +558,9 @@ impl Cache {
         &self,
         channel_id: Id<ChannelMarker>,
     ) -> Option<Arc<CachedCategoryChannel>> {
-        self.category_channels.read().get(&channel_id).cloned()
+        self.category_channels
+            .get(&channel_id)
+            .map(|category_channel| Arc::clone(category_channel.value()))
     }
 
     pub fn insert_voice_state(
@@ -160,12 +569,15 @@ impl Cache {
         channel_id: Id<ChannelMarker>,
         user_id: Id<UserMarker>,
     ) {
+        if let Some(guild) = self.guild(guild_id) {
+            guild.voice_state_user_ids.write().insert(user_id);
+        }
+
         if let Some(voice_channel) = self.voice_channel(channel_id) {
             voice_channel.connected_user_ids.write().insert(user_id);
         }
 
         self.voice_states
-            .write()
             .insert((guild_id, user_id), Arc::new(channel_id));
     }
 
@@ -174,15 +586,23 @@ impl Cache {
         guild_id: Id<GuildMarker>,
         user_id: Id<UserMarker>,
     ) -> Option<Arc<Id<ChannelMarker>>> {
-        self.voice_states.read().get(&(guild_id, user_id)).cloned()
+        self.voice_states
+            .get(&(guild_id, user_id))
+            .map(|channel_id| Arc::clone(channel_id.value()))
     }
 
     pub fn voice_channel(&self, channel_id: Id<ChannelMarker>) -> Option<Arc<CachedVoiceChannel>> {
-        self.voice_channels.read().get(&channel_id).cloned()
+        self.voice_channels
+            .get(&channel_id)
+            .map(|voice_channel| Arc::clone(voice_channel.value()))
     }
 
     pub fn remove_voice_state(&self, guild_id: Id<GuildMarker>, user_id: Id<UserMarker>) {
-        if let Some(voice_channel_id) = self.voice_states.write().remove(&(guild_id, user_id)) {
+        if let Some((_, voice_channel_id)) = self.voice_states.remove(&(guild_id, user_id)) {
+            if let Some(guild) = self.guild(guild_id) {
+                guild.voice_state_user_ids.write().remove(&user_id);
+            }
+
             if let Some(voice_channel) = self.voice_channel(*voice_channel_id) {
                 voice_channel.connected_user_ids.write().remove(&user_id);
             }
@@ -195,52 +615,128 @@ impl Cache {
         user_id: Id<UserMarker>,
     ) -> Option<Arc<Id<ChannelMarker>>> {
         self.voice_channel_owners
-            .read()
             .get(&(guild_id, user_id))
-            .cloned()
+            .map(|channel_id| Arc::clone(channel_id.value()))
     }
 
+    // Removes the guild first, so the cascading removals below skip its now unreachable indexes.
     pub fn remove_guild(&self, guild_id: Id<GuildMarker>) {
-        if let Some(guild) = self.guilds.write().remove(&guild_id) {
-            for category_channel_id in guild.category_channel_ids.read().iter() {
-                self.remove_category_channel(*category_channel_id);
-            }
+        let Some((_, guild)) = self.guilds.remove(&guild_id) else {
+            return;
+        };
+
+        self.remove_category_channels(take(&mut *guild.category_channel_ids.write()));
+        self.remove_voice_channels(take(&mut *guild.voice_channel_ids.write()));
+
+        for channel_id in take(&mut *guild.join_channel_ids.write()) {
+            self.join_channels.remove(&channel_id);
+        }
+
+        for owner_id in take(&mut *guild.voice_channel_owner_ids.write()) {
+            self.voice_channel_owners.remove(&(guild_id, owner_id));
+        }
+
+        for user_id in take(&mut *guild.voice_state_user_ids.write()) {
+            self.voice_states.remove(&(guild_id, user_id));
         }
     }
 
     pub fn remove_voice_channel(&self, channel_id: Id<ChannelMarker>) {
-        if let Some(voice_channel) = self.voice_channels.write().remove(&channel_id) {
-            if let Some(owner_id) = voice_channel.owner_id.read().clone() {
-                self.voice_channel_owners
-                    .write()
-                    .remove(&(voice_channel.guild_id, owner_id));
+        self.remove_voice_channels([channel_id]);
+    }
+
+    // Removes the channels first, then unlinks them from every index in one pass per map.
+    pub fn remove_voice_channels(&self, channel_ids: impl IntoIterator<Item = Id<ChannelMarker>>) {
+        let voice_channels = channel_ids
+            .into_iter()
+            .filter_map(|channel_id| self.voice_channels.remove(&channel_id))
+            .map(|(_, voice_channel)| voice_channel)
+            .collect::<Vec<_>>();
+        let mut removed_channel_ids = HashMap::<_, Vec<_>>::new();
+
+        for voice_channel in &voice_channels {
+            let guild = self.guild(voice_channel.guild_id);
+
+            if let Some(guild) = &guild {
+                guild.voice_channel_ids.write().remove(&voice_channel.id);
             }
 
-            for connected_user_id in voice_channel.connected_user_ids.read().iter() {
-                self.voice_states
-                    .write()
-                    .remove(&(voice_channel.guild_id, *connected_user_id));
+            if let Some(owner_id) = *voice_channel.owner_id.read() {
+                let is_removed = self
+                    .voice_channel_owners
+                    .remove_if(&(voice_channel.guild_id, owner_id), |_, channel_id| {
+                        **channel_id == voice_channel.id
+                    })
+                    .is_some();
+
+                if let Some(guild) = guild.as_ref().filter(|_| is_removed) {
+                    guild.voice_channel_owner_ids.write().remove(&owner_id);
+                }
             }
 
-            if let Some(category_channel) = self.category_channel(voice_channel.parent_id) {
-                category_channel
-                    .voice_channel_ids
-                    .write()
-                    .remove(&channel_id);
+            for user_id in voice_channel.connected_user_ids.read().iter() {
+                let is_removed = self
+                    .voice_states
+                    .remove_if(&(voice_channel.guild_id, *user_id), |_, channel_id| {
+                        **channel_id == voice_channel.id
+                    })
+                    .is_some();
+
+                if let Some(guild) = guild.as_ref().filter(|_| is_removed) {
+                    guild.voice_state_user_ids.write().remove(user_id);
+                }
             }
-        };
+
+            removed_channel_ids
+                .entry(*voice_channel.parent_id.read())
+                .or_default()
+                .push(voice_channel.id);
+        }
+
+        for (parent_id, channel_ids) in removed_channel_ids {
+            if let Some(category_channel) = self.category_channel(parent_id) {
+                let mut voice_channel_ids = category_channel.voice_channel_ids.write();
+
+                for channel_id in channel_ids {
+                    voice_channel_ids.remove(&channel_id);
+                }
+            }
+        }
     }
 
     pub fn remove_category_channel(&self, channel_id: Id<ChannelMarker>) {
-        if let Some(category_channel) = self.category_channels.write().remove(&channel_id) {
-            for voice_channel_id in category_channel.voice_channel_ids.read().iter() {
-                self.remove_voice_channel(*voice_channel_id);
+        self.remove_category_channels([channel_id]);
+    }
+
+    pub fn remove_category_channels(
+        &self,
+        channel_ids: impl IntoIterator<Item = Id<ChannelMarker>>,
+    ) {
+        let mut voice_channel_ids = Vec::new();
+
+        for channel_id in channel_ids {
+            let Some((_, category_channel)) = self.category_channels.remove(&channel_id) else {
+                continue;
+            };
+
+            voice_channel_ids.extend(category_channel.voice_channel_ids.read().iter().copied());
+
+            let join_channel_ids = category_channel.join_channel_ids.read().clone();
+
+            for join_channel_id in &join_channel_ids {
+                self.join_channels.remove(join_channel_id);
             }
 
             if let Some(guild) = self.guild(category_channel.guild_id) {
                 guild.category_channel_ids.write().remove(&channel_id);
+                guild
+                    .join_channel_ids
+                    .write()
+                    .retain(|join_channel_id| !join_channel_ids.contains(join_channel_id));
             }
         }
+
+        self.remove_voice_channels(voice_channel_ids);
     }
 
     pub fn update_panel_message(
@@ -253,6 +749,18 @@ impl Cache {
         }
     }
 
+    pub fn update_appearance(&self, guild_id: Id<GuildMarker>, appearance: GuildAppearance) {
+        if let Some(guild) = self.guild(guild_id) {
+            *guild.appearance.write() = appearance;
+        }
+    }
+
+    pub fn update_locale(&self, guild_id: Id<GuildMarker>, locale: Option<String>) {
+        if let Some(guild) = self.guild(guild_id) {
+            *guild.locale.write() = locale;
+        }
+    }
+
     pub fn update_permanence(&self, guild_id: Id<GuildMarker>, permanence: bool) {
         if let Some(guild) = self.guild(guild_id) {
             *guild.permanence.write() = permanence;
@@ -265,13 +773,32 @@ impl Cache {
         }
     }
 
-    pub fn update_join_channel(
+    pub fn update_voice_channel_parent(
         &self,
         channel_id: Id<ChannelMarker>,
-        join_channel_id: Option<Id<ChannelMarker>>,
+        parent_id: Id<ChannelMarker>,
     ) {
-        if let Some(category_channel) = self.category_channel(channel_id) {
-            *category_channel.join_channel_id.write() = join_channel_id;
+        let Some(voice_channel) = self.voice_channel(channel_id) else {
+            return;
+        };
+        let previous_parent_id = replace(&mut *voice_channel.parent_id.write(), parent_id);
+
+        if previous_parent_id == parent_id {
+            return;
+        }
+
+        if let Some(category_channel) = self.category_channel(previous_parent_id) {
+            category_channel
+                .voice_channel_ids
+                .write()
+                .remove(&channel_id);
+        }
+
+        if let Some(category_channel) = self.category_channel(parent_id) {
+            category_channel
+                .voice_channel_ids
+                .write()
+                .insert(channel_id);
         }
     }
 
@@ -281,19 +808,66 @@ impl Cache {
         owner_id: Option<Id<UserMarker>>,
     ) {
         if let Some(voice_channel) = self.voice_channel(channel_id) {
-            if let Some(current_owner_id) = voice_channel.owner_id.read().clone() {
+            let guild = self.guild(voice_channel.guild_id);
+
+            if let Some(current_owner_id) = *voice_channel.owner_id.read() {
                 self.voice_channel_owners
-                    .write()
                     .remove(&(voice_channel.guild_id, current_owner_id));
+
+                if let Some(guild) = &guild {
+                    guild
+                        .voice_channel_owner_ids
+                        .write()
+                        .remove(&current_owner_id);
+                }
             }
             if let Some(new_owner_id) = owner_id {
-                self.voice_channel_owners.write().insert(
+                self.voice_channel_owners.insert(
                     (voice_channel.guild_id, new_owner_id),
                     Arc::new(voice_channel.id),
                 );
+
+                if let Some(guild) = &guild {
+                    guild.voice_channel_owner_ids.write().insert(new_owner_id);
+                }
             }
 
             *voice_channel.owner_id.write() = owner_id;
         }
     }
 }
+
+// Copies an index out of its lock, so no lock is held while the maps are read.
+fn guild_index<T>(
+    guild: Option<Arc<CachedGuild>>,
+    index: impl Fn(&CachedGuild) -> &RwLock<HashSet<Id<T>>>,
+) -> Vec<Id<T>> {
+    guild
+        .map(|guild| index(&guild).read().iter().copied().collect())
+        .unwrap_or_default()
+}
+
+fn guild_entries(
+    map: &GuildUserChannelMap,
+    guild_id: Id<GuildMarker>,
+    user_ids: Vec<Id<UserMarker>>,
+) -> Vec<(Id<UserMarker>, Id<ChannelMarker>)> {
+    user_ids
+        .into_iter()
+        .filter_map(|user_id| {
+            map.get(&(guild_id, user_id))
+                .map(|channel_id| (user_id, **channel_id))
+        })
+        .collect()
+}
+
+fn take_index<T>(
+    guild: Option<&CachedGuild>,
+    index: impl Fn(&CachedGuild) -> &RwLock<HashSet<Id<T>>>,
+) -> RwLock<HashSet<Id<T>>> {
+    RwLock::new(
+        guild
+            .map(|guild| take(&mut *index(guild).write()))
+            .unwrap_or_default(),
+    )
+}
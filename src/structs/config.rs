@@ -0,0 +1,383 @@
+use std::{collections::HashSet, env, fs, io::ErrorKind, net::SocketAddr, path::PathBuf};
+
+use ed25519_dalek::VerifyingKey;
+use eyre::{bail, eyre, Result, WrapErr};
+use serde::{de::Error as DeserializeError, Deserialize, Deserializer};
+use toml::{Table, Value};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use super::database::DatabaseBackend;
+#[cfg(feature = "postgres")]
+use super::database::PostgresConnectOptions;
+
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+const ENV_PREFIX: &str = "MELOETTA_";
+const LEGACY_ENV_VARS: [(&str, &str, &str); 5] = [
+    ("DATABASE_URL", "database", "url"),
+    ("DISCORD_TOKEN", "discord", "token"),
+    ("HTTP_ADDRESS", "http", "address"),
+    ("LOG_FORMAT", "log", "format"),
+    ("LOG_LEVEL", "log", "level"),
+];
+const MAX_BITRATE: u32 = 384;
+pub const MAX_CATEGORY_CHANNELS: usize = 3;
+pub const MAX_CHANNEL_TEMPLATES: usize = 25;
+pub const MAX_JOIN_CHANNELS: usize = 5;
+const MAX_SELECT_MENU_OPTIONS: usize = 25;
+const MAX_SLOWMODE: u16 = 21600;
+const MIN_BITRATE: u32 = 8;
+pub const PRIVACY_OPTIONS: [&str; 3] = ["invisible", "locked", "unlocked"];
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub appearance: AppearanceConfig,
+    pub consistency: ConsistencyConfig,
+    pub database: DatabaseConfig,
+    pub defaults: DefaultsConfig,
+    pub development: DevelopmentConfig,
+    pub discord: DiscordConfig,
+    pub features: FeaturesConfig,
+    pub http: HttpConfig,
+    pub limits: LimitsConfig,
+    pub log: LogConfig,
+    pub shutdown: ShutdownConfig,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AppearanceConfig {
+    pub color: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConsistencyConfig {
+    pub interval_seconds: u64,
+    pub repair: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub pool_size: usize,
+    pub ssl_cert: Option<PathBuf>,
+    pub ssl_key: Option<PathBuf>,
+    pub ssl_root_cert: Option<PathBuf>,
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DefaultsConfig {
+    pub permanence: bool,
+    pub privacy: String,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DevelopmentConfig {
+    pub guild_ids: Vec<Id<GuildMarker>>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiscordConfig {
+    #[serde(deserialize_with = "deserialize_public_key")]
+    pub public_key: Option<VerifyingKey>,
+    pub token: String,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeaturesConfig {
+    pub http_interactions: bool,
+    pub monitoring: bool,
+    pub session_resumption: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpConfig {
+    pub address: SocketAddr,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    pub max_bitrate: u32,
+    pub min_bitrate: u32,
+    pub slowmode_options: Vec<u16>,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    pub level: String,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    Json,
+    Text,
+}
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShutdownConfig {
+    pub drain_timeout_seconds: u64,
+}
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let path = env::var("CONFIG_PATH").ok();
+        let mut table = match fs::read_to_string(path.as_deref().unwrap_or(DEFAULT_CONFIG_PATH)) {
+            Ok(contents) => toml::from_str::<Table>(&contents)
+                .wrap_err("Unable to parse the configuration file.")?,
+            Err(source) if source.kind() == ErrorKind::NotFound && path.is_none() => Table::new(),
+            Err(source) => {
+                return Err(source).wrap_err("Unable to read the configuration file.");
+            }
+        };
+
+        for (name, section, key) in LEGACY_ENV_VARS {
+            if let Ok(value) = env::var(name) {
+                set_value(&mut table, section, key, Value::String(value))?;
+            }
+        }
+
+        for (name, value) in env::vars() {
+            let Some((section, key)) = name
+                .strip_prefix(ENV_PREFIX)
+                .and_then(|name| name.split_once('_'))
+            else {
+                continue;
+            };
+
+            set_value(
+                &mut table,
+                &section.to_lowercase(),
+                &key.to_lowercase(),
+                parse_env_value(value),
+            )?;
+        }
+
+        let config = table
+            .try_into::<Self>()
+            .map_err(|source| eyre!("Invalid configuration: {}", source.to_string().trim()))?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.appearance.color > 0xFFFFFF {
+            bail!("`appearance.color` must be a 24-bit RGB color.");
+        }
+
+        if self.database.pool_size == 0 {
+            bail!("`database.pool_size` must be at least 1.");
+        }
+
+        if self.database.url.is_empty() {
+            bail!("`database.url` (or `DATABASE_URL`) must be set.");
+        }
+
+        let backend = DatabaseBackend::from_url(&self.database.url);
+
+        if !backend.is_enabled() {
+            bail!(
+                "`database.url` needs the `{}` feature, which this build does not include.",
+                backend.name()
+            );
+        }
+
+        #[cfg(feature = "postgres")]
+        if backend == DatabaseBackend::Postgres {
+            PostgresConnectOptions::new(&self.database)
+                .wrap_err("`database` does not describe a valid PostgreSQL connection.")?;
+        }
+
+        if !PRIVACY_OPTIONS.contains(&self.defaults.privacy.as_str()) {
+            bail!(
+                "`defaults.privacy` must be one of {}.",
+                PRIVACY_OPTIONS.join(", ")
+            );
+        }
+
+        if self.discord.token.is_empty() {
+            bail!("`discord.token` (or `DISCORD_TOKEN`) must be set.");
+        }
+
+        if self.features.http_interactions && self.discord.public_key.is_none() {
+            bail!("`discord.public_key` must be set when `features.http_interactions` is enabled.");
+        }
+
+        if self.limits.min_bitrate < MIN_BITRATE || self.limits.max_bitrate > MAX_BITRATE {
+            bail!(
+                "`limits.min_bitrate` and `limits.max_bitrate` must be between {MIN_BITRATE} and \
+                 {MAX_BITRATE}."
+            );
+        }
+
+        if self.limits.min_bitrate > self.limits.max_bitrate {
+            bail!("`limits.min_bitrate` must not be greater than `limits.max_bitrate`.");
+        }
+
+        if self.limits.slowmode_options.is_empty()
+            || self.limits.slowmode_options.len() > MAX_SELECT_MENU_OPTIONS
+        {
+            bail!(
+                "`limits.slowmode_options` must contain between 1 and {MAX_SELECT_MENU_OPTIONS} \
+                 values."
+            );
+        }
+
+        if self
+            .limits
+            .slowmode_options
+            .iter()
+            .any(|slowmode| *slowmode > MAX_SLOWMODE)
+        {
+            bail!("`limits.slowmode_options` values must not be greater than {MAX_SLOWMODE}.");
+        }
+
+        if self
+            .limits
+            .slowmode_options
+            .iter()
+            .collect::<HashSet<_>>()
+            .len()
+            != self.limits.slowmode_options.len()
+        {
+            bail!("`limits.slowmode_options` must not contain duplicates.");
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for AppearanceConfig {
+    fn default() -> Self {
+        Self { color: 0xF8F8FF }
+    }
+}
+
+impl Default for ConsistencyConfig {
+    fn default() -> Self {
+        Self {
+            interval_seconds: 3600,
+            repair: false,
+        }
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            pool_size: 16,
+            ssl_cert: None,
+            ssl_key: None,
+            ssl_root_cert: None,
+            url: String::new(),
+        }
+    }
+}
+
+impl Default for DefaultsConfig {
+    fn default() -> Self {
+        Self {
+            permanence: false,
+            privacy: "unlocked".to_owned(),
+        }
+    }
+}
+
+impl Default for FeaturesConfig {
+    fn default() -> Self {
+        Self {
+            http_interactions: false,
+            monitoring: true,
+            session_resumption: true,
+        }
+    }
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::from(([127, 0, 0, 1], 9090)),
+        }
+    }
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_bitrate: 96,
+            min_bitrate: 8,
+            slowmode_options: vec![
+                0, 5, 10, 15, 30, 60, 120, 300, 600, 900, 1800, 3600, 7200, 21600,
+            ],
+        }
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::Text,
+            level: "info".to_owned(),
+        }
+    }
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            drain_timeout_seconds: 30,
+        }
+    }
+}
+
+fn deserialize_public_key<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<VerifyingKey>, D::Error> {
+    let public_key = String::deserialize(deserializer)?;
+
+    if public_key.is_empty() {
+        return Ok(None);
+    }
+
+    let mut bytes = [0; 32];
+
+    hex::decode_to_slice(&public_key, &mut bytes)
+        .map_err(|_| D::Error::custom("expected a 64 character hex encoded public key"))?;
+
+    VerifyingKey::from_bytes(&bytes)
+        .map(Some)
+        .map_err(|_| D::Error::custom("expected a valid Ed25519 public key"))
+}
+
+fn parse_env_value(value: String) -> Value {
+    toml::from_str::<Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or(Value::String(value))
+}
+
+fn set_value(table: &mut Table, section: &str, key: &str, value: Value) -> Result<()> {
+    let Value::Table(section_table) = table
+        .entry(section)
+        .or_insert_with(|| Value::Table(Table::new()))
+    else {
+        bail!("`{section}` must be a table.");
+    };
+
+    section_table.insert(key.to_owned(), value);
+
+    Ok(())
+}
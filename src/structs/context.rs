@@ -1,28 +1,59 @@
 use std::sync::Arc;
 
+use dashmap::DashMap;
+use eyre::Result;
+use tokio_util::task::TaskTracker;
 use twilight_http::{client::InteractionClient, Client};
-use twilight_model::id::{marker::ApplicationMarker, Id};
+use twilight_model::id::{
+    marker::{ApplicationMarker, InteractionMarker},
+    Id,
+};
 
-use super::{cache::Cache, database::Database};
+use super::{
+    cache::Cache,
+    config::Config,
+    database::{self, Database},
+    event_queue::EventQueue,
+    guild_export::PendingImport,
+    health::Health,
+    metrics::Metrics,
+};
 
 pub struct Context {
     pub application_id: Id<ApplicationMarker>,
     pub cache: Cache,
     pub client: Arc<Client>,
-    pub database: Database,
+    pub config: Config,
+    pub database: Box<dyn Database>,
+    pub event_queue: EventQueue,
+    pub health: Health,
+    pub metrics: Metrics,
+    // Keyed by the ID of the import command's interaction, which its buttons carry.
+    pub pending_imports: DashMap<Id<InteractionMarker>, PendingImport>,
+    pub tasks: TaskTracker,
 }
 
 impl Context {
-    pub fn interaction_client(&self) -> InteractionClient {
+    pub fn interaction_client(&self) -> InteractionClient<'_> {
         self.client.interaction(self.application_id)
     }
 
-    pub fn new(client: Client, application_id: Id<ApplicationMarker>) -> Self {
-        Self {
+    pub fn new(
+        client: Client,
+        application_id: Id<ApplicationMarker>,
+        config: Config,
+    ) -> Result<Self> {
+        Ok(Self {
             application_id,
             cache: Cache::new(),
-            database: Database::new(),
+            database: database::connect(&config.database)?,
             client: Arc::new(client),
-        }
+            config,
+            event_queue: EventQueue::new(),
+            health: Health::new(),
+            metrics: Metrics::new()?,
+            pending_imports: DashMap::new(),
+            tasks: TaskTracker::new(),
+        })
     }
 }
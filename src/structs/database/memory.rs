@@ -0,0 +1,665 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use eyre::Result;
+use parking_lot::Mutex;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+use super::{
+    ConstraintViolation, Database, DatabaseCategoryChannel, DatabaseChannelTemplate,
+    DatabaseConnections, DatabaseGuild, DatabaseJoinChannel, DatabaseShardSession, DatabaseStats,
+    DatabaseVoiceChannel, DatabaseVoiceChannelCreation,
+};
+use crate::structs::cache::GuildAppearance;
+
+const SHARD_SESSION_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
+pub struct MemoryDatabase {
+    tables: Mutex<Tables>,
+}
+
+#[derive(Default)]
+struct Tables {
+    category_channels: HashMap<Id<ChannelMarker>, DatabaseCategoryChannel>,
+    channel_templates: HashMap<(Id<GuildMarker>, String), DatabaseChannelTemplate>,
+    command_guild_ids: HashSet<Id<GuildMarker>>,
+    guilds: HashMap<Id<GuildMarker>, DatabaseGuild>,
+    join_channels: HashMap<Id<ChannelMarker>, DatabaseJoinChannel>,
+    shard_sessions: HashMap<u32, (DatabaseShardSession, Instant)>,
+    voice_channel_creations:
+        HashMap<(Id<GuildMarker>, Id<UserMarker>), DatabaseVoiceChannelCreation>,
+    voice_channels: HashMap<Id<ChannelMarker>, DatabaseVoiceChannel>,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        Self {
+            tables: Mutex::new(Tables::default()),
+        }
+    }
+}
+
+impl Tables {
+    fn remove_category_channel(&mut self, channel_id: Id<ChannelMarker>) -> bool {
+        if self.category_channels.remove(&channel_id).is_none() {
+            return false;
+        }
+
+        self.join_channels
+            .retain(|_, join_channel| join_channel.category_channel_id != channel_id);
+        self.voice_channels
+            .retain(|_, voice_channel| voice_channel.parent_id != channel_id);
+
+        true
+    }
+}
+
+#[async_trait]
+impl Database for MemoryDatabase {
+    fn close(&self) {}
+
+    fn connections(&self) -> Option<DatabaseConnections> {
+        None
+    }
+
+    async fn create_tables(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<DatabaseGuild>> {
+        Ok(self.tables.lock().guilds.get(&guild_id).cloned())
+    }
+
+    async fn guilds(&self) -> Result<Vec<DatabaseGuild>> {
+        Ok(self.tables.lock().guilds.values().cloned().collect())
+    }
+
+    async fn insert_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        permanence: bool,
+        privacy: &str,
+    ) -> Result<()> {
+        self.tables
+            .lock()
+            .guilds
+            .entry(guild_id)
+            .or_insert_with(|| DatabaseGuild {
+                appearance: GuildAppearance::default(),
+                id: guild_id,
+                locale: None,
+                permanence,
+                privacy: privacy.to_owned(),
+            });
+
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn take_shard_sessions(&self) -> Result<Vec<DatabaseShardSession>> {
+        let shard_sessions = self
+            .tables
+            .lock()
+            .shard_sessions
+            .drain()
+            .filter(|(_, (_, saved_at))| saved_at.elapsed() < SHARD_SESSION_MAX_AGE)
+            .map(|(_, (shard_session, _))| shard_session)
+            .collect();
+
+        Ok(shard_sessions)
+    }
+
+    async fn insert_shard_session(
+        &self,
+        id: u32,
+        total: u32,
+        session_id: &str,
+        sequence: u64,
+        resume_url: Option<&str>,
+        cache: &str,
+    ) -> Result<()> {
+        let shard_session = DatabaseShardSession {
+            cache: cache.to_owned(),
+            id,
+            resume_url: resume_url.map(ToOwned::to_owned),
+            sequence,
+            session_id: session_id.to_owned(),
+            total,
+        };
+
+        self.tables
+            .lock()
+            .shard_sessions
+            .insert(id, (shard_session, Instant::now()));
+
+        Ok(())
+    }
+
+    async fn command_guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        Ok(self
+            .tables
+            .lock()
+            .command_guild_ids
+            .iter()
+            .copied()
+            .collect())
+    }
+
+    async fn insert_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        self.tables.lock().command_guild_ids.insert(guild_id);
+
+        Ok(())
+    }
+
+    async fn remove_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        self.tables.lock().command_guild_ids.remove(&guild_id);
+
+        Ok(())
+    }
+
+    async fn remove_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_ids: Vec<Id<ChannelMarker>>,
+    ) -> Result<Vec<Id<ChannelMarker>>> {
+        let mut tables = self.tables.lock();
+        let mut removed_channel_ids = Vec::new();
+        let category_channel_ids = tables
+            .category_channels
+            .values()
+            .filter(|category_channel| {
+                category_channel.guild_id == guild_id && !channel_ids.contains(&category_channel.id)
+            })
+            .map(|category_channel| category_channel.id)
+            .collect::<Vec<Id<ChannelMarker>>>();
+
+        for category_channel_id in category_channel_ids {
+            tables.remove_category_channel(category_channel_id);
+            removed_channel_ids.push(category_channel_id);
+        }
+
+        tables.join_channels.retain(|_, join_channel| {
+            if join_channel.guild_id != guild_id || channel_ids.contains(&join_channel.id) {
+                return true;
+            }
+
+            removed_channel_ids.push(join_channel.id);
+
+            false
+        });
+        tables.voice_channels.retain(|_, voice_channel| {
+            if voice_channel.guild_id != guild_id || channel_ids.contains(&voice_channel.id) {
+                return true;
+            }
+
+            removed_channel_ids.push(voice_channel.id);
+
+            false
+        });
+
+        Ok(removed_channel_ids)
+    }
+
+    async fn update_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_channel_and_parent_ids: Vec<(Id<ChannelMarker>, Id<ChannelMarker>)>,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+        let updates = voice_channel_and_parent_ids
+            .into_iter()
+            .filter(|(id, _)| {
+                tables
+                    .voice_channels
+                    .get(id)
+                    .is_some_and(|voice_channel| voice_channel.guild_id == guild_id)
+            })
+            .collect::<Vec<(Id<ChannelMarker>, Id<ChannelMarker>)>>();
+
+        if updates
+            .iter()
+            .any(|(_, parent_id)| !tables.category_channels.contains_key(parent_id))
+        {
+            return Err(ConstraintViolation("voice_channel.parent_id").into());
+        }
+
+        for (id, parent_id) in updates {
+            if let Some(voice_channel) = tables.voice_channels.get_mut(&id) {
+                voice_channel.parent_id = parent_id;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn update_panel_message(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        panel_message_id: Option<Id<MessageMarker>>,
+    ) -> Result<()> {
+        if let Some(voice_channel) = self.tables.lock().voice_channels.get_mut(&voice_channel_id) {
+            voice_channel.panel_message_id = panel_message_id;
+        }
+
+        Ok(())
+    }
+
+    async fn guild_category_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseCategoryChannel>> {
+        let guild_category_channels = self
+            .tables
+            .lock()
+            .category_channels
+            .values()
+            .filter(|category_channel| category_channel.guild_id == guild_id)
+            .cloned()
+            .collect();
+
+        Ok(guild_category_channels)
+    }
+
+    async fn guild_voice_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseVoiceChannel>> {
+        let guild_voice_channels = self
+            .tables
+            .lock()
+            .voice_channels
+            .values()
+            .filter(|voice_channel| voice_channel.guild_id == guild_id)
+            .cloned()
+            .collect();
+
+        Ok(guild_voice_channels)
+    }
+
+    async fn insert_voice_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+        parent_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if !tables.guilds.contains_key(&guild_id) {
+            return Err(ConstraintViolation("voice_channel.guild_id").into());
+        }
+
+        if !tables.category_channels.contains_key(&parent_id) {
+            return Err(ConstraintViolation("voice_channel.parent_id").into());
+        }
+
+        tables
+            .voice_channels
+            .entry(id)
+            .or_insert_with(|| DatabaseVoiceChannel {
+                id,
+                guild_id,
+                parent_id,
+                owner_id,
+                panel_message_id: None,
+            });
+
+        Ok(())
+    }
+
+    async fn remove_voice_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        self.tables.lock().voice_channels.remove(&channel_id);
+
+        Ok(())
+    }
+
+    async fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if tables.guilds.remove(&guild_id).is_some() {
+            tables
+                .category_channels
+                .retain(|_, category_channel| category_channel.guild_id != guild_id);
+            tables
+                .channel_templates
+                .retain(|(template_guild_id, _), _| *template_guild_id != guild_id);
+            tables
+                .join_channels
+                .retain(|_, join_channel| join_channel.guild_id != guild_id);
+            tables
+                .voice_channel_creations
+                .retain(|_, voice_channel_creation| voice_channel_creation.guild_id != guild_id);
+            tables
+                .voice_channels
+                .retain(|_, voice_channel| voice_channel.guild_id != guild_id);
+        }
+
+        Ok(())
+    }
+
+    async fn remove_category_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        self.tables.lock().remove_category_channel(channel_id);
+
+        Ok(())
+    }
+
+    async fn update_appearance(
+        &self,
+        guild_id: Id<GuildMarker>,
+        appearance: GuildAppearance,
+    ) -> Result<()> {
+        if let Some(guild) = self.tables.lock().guilds.get_mut(&guild_id) {
+            guild.appearance = appearance;
+        }
+
+        Ok(())
+    }
+
+    async fn update_locale(&self, guild_id: Id<GuildMarker>, locale: Option<String>) -> Result<()> {
+        if let Some(guild) = self.tables.lock().guilds.get_mut(&guild_id) {
+            guild.locale = locale;
+        }
+
+        Ok(())
+    }
+
+    async fn update_permanence(&self, guild_id: Id<GuildMarker>, permanence: bool) -> Result<()> {
+        if let Some(guild) = self.tables.lock().guilds.get_mut(&guild_id) {
+            guild.permanence = permanence;
+        }
+
+        Ok(())
+    }
+
+    async fn update_privacy(&self, guild_id: Id<GuildMarker>, privacy: String) -> Result<()> {
+        if let Some(guild) = self.tables.lock().guilds.get_mut(&guild_id) {
+            guild.privacy = privacy;
+        }
+
+        Ok(())
+    }
+
+    async fn update_voice_channel_owner(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        if let Some(voice_channel) = self.tables.lock().voice_channels.get_mut(&voice_channel_id) {
+            voice_channel.owner_id = owner_id;
+        }
+
+        Ok(())
+    }
+
+    async fn insert_category_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if !tables.guilds.contains_key(&guild_id) {
+            return Err(ConstraintViolation("category_channel.guild_id").into());
+        }
+
+        tables
+            .category_channels
+            .entry(id)
+            .or_insert(DatabaseCategoryChannel { guild_id, id });
+
+        Ok(())
+    }
+
+    async fn guild_join_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseJoinChannel>> {
+        let guild_join_channels = self
+            .tables
+            .lock()
+            .join_channels
+            .values()
+            .filter(|join_channel| join_channel.guild_id == guild_id)
+            .cloned()
+            .collect();
+
+        Ok(guild_join_channels)
+    }
+
+    async fn insert_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if !tables.guilds.contains_key(&join_channel.guild_id) {
+            return Err(ConstraintViolation("join_channel.guild_id").into());
+        }
+
+        if !tables
+            .category_channels
+            .contains_key(&join_channel.category_channel_id)
+        {
+            return Err(ConstraintViolation("join_channel.category_channel_id").into());
+        }
+
+        tables
+            .join_channels
+            .entry(join_channel.id)
+            .or_insert(join_channel);
+
+        Ok(())
+    }
+
+    async fn remove_join_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        self.tables.lock().join_channels.remove(&channel_id);
+
+        Ok(())
+    }
+
+    async fn update_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()> {
+        if let Some(existing_join_channel) =
+            self.tables.lock().join_channels.get_mut(&join_channel.id)
+        {
+            *existing_join_channel = DatabaseJoinChannel {
+                category_channel_id: existing_join_channel.category_channel_id,
+                guild_id: existing_join_channel.guild_id,
+                ..join_channel
+            };
+        }
+
+        Ok(())
+    }
+
+    async fn update_join_channel_category(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        category_channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if !tables.category_channels.contains_key(&category_channel_id) {
+            return Err(ConstraintViolation("join_channel.category_channel_id").into());
+        }
+
+        if let Some(join_channel) = tables.join_channels.get_mut(&channel_id) {
+            join_channel.category_channel_id = category_channel_id;
+        }
+
+        Ok(())
+    }
+
+    async fn update_join_channel_template(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        template_name: Option<String>,
+    ) -> Result<()> {
+        if let Some(join_channel) = self.tables.lock().join_channels.get_mut(&channel_id) {
+            join_channel.template_name = template_name;
+        }
+
+        Ok(())
+    }
+
+    async fn channel_template(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: String,
+    ) -> Result<Option<DatabaseChannelTemplate>> {
+        Ok(self
+            .tables
+            .lock()
+            .channel_templates
+            .get(&(guild_id, name))
+            .cloned())
+    }
+
+    async fn guild_channel_templates(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseChannelTemplate>> {
+        let mut guild_channel_templates = self
+            .tables
+            .lock()
+            .channel_templates
+            .values()
+            .filter(|channel_template| channel_template.guild_id == guild_id)
+            .cloned()
+            .collect::<Vec<DatabaseChannelTemplate>>();
+
+        guild_channel_templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(guild_channel_templates)
+    }
+
+    async fn insert_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if !tables.guilds.contains_key(&channel_template.guild_id) {
+            return Err(ConstraintViolation("channel_template.guild_id").into());
+        }
+
+        tables
+            .channel_templates
+            .entry((channel_template.guild_id, channel_template.name.clone()))
+            .or_insert(channel_template);
+
+        Ok(())
+    }
+
+    async fn update_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()> {
+        if let Some(stored_channel_template) = self
+            .tables
+            .lock()
+            .channel_templates
+            .get_mut(&(channel_template.guild_id, channel_template.name.clone()))
+        {
+            *stored_channel_template = channel_template;
+        }
+
+        Ok(())
+    }
+
+    async fn remove_channel_template(&self, guild_id: Id<GuildMarker>, name: String) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        for join_channel in tables.join_channels.values_mut() {
+            if join_channel.guild_id == guild_id
+                && join_channel.template_name.as_ref() == Some(&name)
+            {
+                join_channel.template_name = None;
+            }
+        }
+
+        tables.channel_templates.remove(&(guild_id, name));
+
+        Ok(())
+    }
+
+    async fn insert_voice_channel_creation(
+        &self,
+        voice_channel_creation: DatabaseVoiceChannelCreation,
+    ) -> Result<()> {
+        let mut tables = self.tables.lock();
+
+        if !tables.guilds.contains_key(&voice_channel_creation.guild_id) {
+            return Err(ConstraintViolation("pending_voice_channel.guild_id").into());
+        }
+
+        tables.voice_channel_creations.insert(
+            (
+                voice_channel_creation.guild_id,
+                voice_channel_creation.owner_id,
+            ),
+            voice_channel_creation,
+        );
+
+        Ok(())
+    }
+
+    async fn update_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+        id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        if let Some(voice_channel_creation) = self
+            .tables
+            .lock()
+            .voice_channel_creations
+            .get_mut(&(guild_id, owner_id))
+        {
+            voice_channel_creation.id = Some(id);
+        }
+
+        Ok(())
+    }
+
+    async fn remove_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+    ) -> Result<()> {
+        self.tables
+            .lock()
+            .voice_channel_creations
+            .remove(&(guild_id, owner_id));
+
+        Ok(())
+    }
+
+    async fn voice_channel_creations(&self) -> Result<Vec<DatabaseVoiceChannelCreation>> {
+        Ok(self
+            .tables
+            .lock()
+            .voice_channel_creations
+            .values()
+            .cloned()
+            .collect())
+    }
+    async fn stats(&self) -> Result<DatabaseStats> {
+        let tables = self.tables.lock();
+
+        Ok(DatabaseStats {
+            category_channels: tables.category_channels.len() as u64,
+            channel_templates: tables.channel_templates.len() as u64,
+            guilds: tables.guilds.len() as u64,
+            join_channels: tables.join_channels.len() as u64,
+            shard_sessions: tables.shard_sessions.len() as u64,
+            voice_channel_creations: tables.voice_channel_creations.len() as u64,
+            voice_channels: tables.voice_channels.len() as u64,
+        })
+    }
+}
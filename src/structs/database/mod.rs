@@ -0,0 +1,405 @@
+mod memory;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "tls")]
+mod tls;
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use async_trait::async_trait;
+use eyre::{bail, Result};
+use twilight_model::{
+    channel::permission_overwrite::PermissionOverwrite as ChannelPermissionOverwrite,
+    id::{
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+};
+
+use self::memory::MemoryDatabase;
+#[cfg(feature = "postgres")]
+pub use self::postgres::ConnectOptions as PostgresConnectOptions;
+#[cfg(feature = "postgres")]
+use self::postgres::PostgresDatabase;
+#[cfg(feature = "sqlite")]
+use self::sqlite::SqliteDatabase;
+use super::{
+    cache::{CachedJoinChannel, GuildAppearance},
+    config::DatabaseConfig,
+};
+
+const MEMORY_URL_SCHEME: &str = "memory:";
+const SQLITE_URL_SCHEME: &str = "sqlite:";
+
+#[async_trait]
+pub trait Database: Send + Sync {
+    fn close(&self);
+
+    fn connections(&self) -> Option<DatabaseConnections>;
+
+    async fn create_tables(&self) -> Result<()>;
+
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<DatabaseGuild>>;
+
+    async fn guilds(&self) -> Result<Vec<DatabaseGuild>>;
+
+    async fn insert_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        permanence: bool,
+        privacy: &str,
+    ) -> Result<()>;
+
+    async fn ping(&self) -> Result<()>;
+
+    async fn take_shard_sessions(&self) -> Result<Vec<DatabaseShardSession>>;
+
+    async fn insert_shard_session(
+        &self,
+        id: u32,
+        total: u32,
+        session_id: &str,
+        sequence: u64,
+        resume_url: Option<&str>,
+        cache: &str,
+    ) -> Result<()>;
+
+    async fn command_guild_ids(&self) -> Result<Vec<Id<GuildMarker>>>;
+
+    async fn insert_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()>;
+
+    async fn remove_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()>;
+
+    async fn remove_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_ids: Vec<Id<ChannelMarker>>,
+    ) -> Result<Vec<Id<ChannelMarker>>>;
+
+    async fn update_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_channel_and_parent_ids: Vec<(Id<ChannelMarker>, Id<ChannelMarker>)>,
+    ) -> Result<()>;
+
+    async fn update_panel_message(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        panel_message_id: Option<Id<MessageMarker>>,
+    ) -> Result<()>;
+
+    async fn guild_category_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseCategoryChannel>>;
+
+    async fn guild_voice_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseVoiceChannel>>;
+
+    async fn insert_voice_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+        parent_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()>;
+
+    async fn remove_voice_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()>;
+
+    async fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<()>;
+
+    async fn remove_category_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()>;
+
+    async fn update_appearance(
+        &self,
+        guild_id: Id<GuildMarker>,
+        appearance: GuildAppearance,
+    ) -> Result<()>;
+
+    async fn update_locale(&self, guild_id: Id<GuildMarker>, locale: Option<String>) -> Result<()>;
+
+    async fn update_permanence(&self, guild_id: Id<GuildMarker>, permanence: bool) -> Result<()>;
+
+    async fn update_privacy(&self, guild_id: Id<GuildMarker>, privacy: String) -> Result<()>;
+
+    async fn update_voice_channel_owner(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()>;
+
+    async fn insert_category_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()>;
+
+    async fn guild_join_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseJoinChannel>>;
+
+    async fn insert_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()>;
+
+    async fn remove_join_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()>;
+
+    async fn update_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()>;
+
+    async fn update_join_channel_category(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        category_channel_id: Id<ChannelMarker>,
+    ) -> Result<()>;
+
+    async fn update_join_channel_template(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        template_name: Option<String>,
+    ) -> Result<()>;
+
+    async fn channel_template(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: String,
+    ) -> Result<Option<DatabaseChannelTemplate>>;
+
+    async fn guild_channel_templates(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseChannelTemplate>>;
+
+    async fn insert_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()>;
+
+    async fn update_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()>;
+
+    async fn remove_channel_template(&self, guild_id: Id<GuildMarker>, name: String) -> Result<()>;
+
+    async fn insert_voice_channel_creation(
+        &self,
+        voice_channel_creation: DatabaseVoiceChannelCreation,
+    ) -> Result<()>;
+
+    async fn update_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+        id: Id<ChannelMarker>,
+    ) -> Result<()>;
+
+    async fn remove_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+    ) -> Result<()>;
+
+    async fn voice_channel_creations(&self) -> Result<Vec<DatabaseVoiceChannelCreation>>;
+
+    async fn stats(&self) -> Result<DatabaseStats>;
+}
+
+#[derive(Debug)]
+pub struct ConstraintViolation(pub &'static str);
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum DatabaseBackend {
+    Memory,
+    Postgres,
+    Sqlite,
+}
+
+#[derive(Clone)]
+pub struct DatabaseChannelTemplate {
+    pub bitrate: Option<u32>,
+    pub guild_id: Id<GuildMarker>,
+    pub name: String,
+    pub permission_overwrites: Vec<ChannelPermissionOverwrite>,
+    pub privacy: Option<String>,
+    pub user_limit: Option<u16>,
+}
+
+#[derive(Clone)]
+pub struct DatabaseCategoryChannel {
+    #[allow(dead_code)]
+    pub guild_id: Id<GuildMarker>,
+    pub id: Id<ChannelMarker>,
+}
+
+pub struct DatabaseConnections {
+    pub available: usize,
+    pub max_size: usize,
+    pub size: usize,
+    pub waiting: usize,
+}
+
+#[derive(Clone)]
+pub struct DatabaseGuild {
+    pub appearance: GuildAppearance,
+    pub id: Id<GuildMarker>,
+    pub locale: Option<String>,
+    pub permanence: bool,
+    pub privacy: String,
+}
+
+#[derive(Clone)]
+pub struct DatabaseJoinChannel {
+    pub bitrate: Option<u32>,
+    pub category_channel_id: Id<ChannelMarker>,
+    pub guild_id: Id<GuildMarker>,
+    pub id: Id<ChannelMarker>,
+    pub name_template: Option<String>,
+    pub privacy: Option<String>,
+    pub template_name: Option<String>,
+    pub user_limit: Option<u16>,
+}
+
+#[derive(Clone)]
+pub struct DatabaseShardSession {
+    pub cache: String,
+    pub id: u32,
+    pub resume_url: Option<String>,
+    pub sequence: u64,
+    pub session_id: String,
+    pub total: u32,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct DatabaseStats {
+    pub category_channels: u64,
+    pub channel_templates: u64,
+    pub guilds: u64,
+    pub join_channels: u64,
+    pub shard_sessions: u64,
+    pub voice_channel_creations: u64,
+    pub voice_channels: u64,
+}
+
+#[derive(Clone)]
+pub struct DatabaseVoiceChannel {
+    pub id: Id<ChannelMarker>,
+    #[allow(dead_code)]
+    pub guild_id: Id<GuildMarker>,
+    pub parent_id: Id<ChannelMarker>,
+    pub owner_id: Option<Id<UserMarker>>,
+    pub panel_message_id: Option<Id<MessageMarker>>,
+}
+
+// Recorded before the channel is requested, so `id` stays empty until Discord returns it.
+#[derive(Clone)]
+pub struct DatabaseVoiceChannelCreation {
+    pub guild_id: Id<GuildMarker>,
+    pub id: Option<Id<ChannelMarker>>,
+    pub name: String,
+    pub owner_id: Id<UserMarker>,
+    pub parent_id: Id<ChannelMarker>,
+}
+
+impl Display for ConstraintViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "foreign key constraint on `{}` violated", self.0)
+    }
+}
+
+impl Error for ConstraintViolation {}
+
+impl DatabaseChannelTemplate {
+    pub fn new(guild_id: Id<GuildMarker>, name: String) -> Self {
+        Self {
+            bitrate: None,
+            guild_id,
+            name,
+            permission_overwrites: Vec::new(),
+            privacy: None,
+            user_limit: None,
+        }
+    }
+}
+
+impl DatabaseJoinChannel {
+    pub fn new(
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+        category_channel_id: Id<ChannelMarker>,
+    ) -> Self {
+        Self {
+            bitrate: None,
+            category_channel_id,
+            guild_id,
+            id,
+            name_template: None,
+            privacy: None,
+            template_name: None,
+            user_limit: None,
+        }
+    }
+}
+
+impl From<DatabaseJoinChannel> for CachedJoinChannel {
+    fn from(join_channel: DatabaseJoinChannel) -> Self {
+        Self {
+            bitrate: join_channel.bitrate,
+            category_channel_id: join_channel.category_channel_id,
+            guild_id: join_channel.guild_id,
+            id: join_channel.id,
+            name_template: join_channel.name_template,
+            privacy: join_channel.privacy,
+            template_name: join_channel.template_name,
+            user_limit: join_channel.user_limit,
+        }
+    }
+}
+
+impl DatabaseBackend {
+    pub fn from_url(url: &str) -> Self {
+        if url.starts_with(MEMORY_URL_SCHEME) {
+            Self::Memory
+        } else if url.starts_with(SQLITE_URL_SCHEME) {
+            Self::Sqlite
+        } else {
+            Self::Postgres
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Self::Memory => true,
+            Self::Postgres => cfg!(feature = "postgres"),
+            Self::Sqlite => cfg!(feature = "sqlite"),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Memory => "memory",
+            Self::Postgres => "postgres",
+            Self::Sqlite => "sqlite",
+        }
+    }
+}
+
+pub fn connect(config: &DatabaseConfig) -> Result<Box<dyn Database>> {
+    Ok(match DatabaseBackend::from_url(&config.url) {
+        DatabaseBackend::Memory => Box::new(MemoryDatabase::new()),
+        #[cfg(feature = "postgres")]
+        DatabaseBackend::Postgres => Box::new(PostgresDatabase::new(config)?),
+        #[cfg(feature = "sqlite")]
+        DatabaseBackend::Sqlite => Box::new(SqliteDatabase::new(config)?),
+        #[allow(unreachable_patterns)]
+        backend => bail!(
+            "The `{}` database backend is not included in this build.",
+            backend.name()
+        ),
+    })
+}
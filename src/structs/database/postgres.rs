@@ -0,0 +1,1499 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use deadpool_postgres::{Manager, ManagerConfig, Pool, RecyclingMethod};
+#[cfg(not(feature = "tls"))]
+use eyre::ensure;
+use eyre::{bail, Context, Result};
+use futures::future::try_join_all;
+#[cfg(not(feature = "tls"))]
+use tokio_postgres::NoTls;
+use tokio_postgres::{config::SslMode as TokioSslMode, types::ToSql, Config, Row};
+use tracing::instrument;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+use super::{
+    Database, DatabaseCategoryChannel, DatabaseChannelTemplate, DatabaseConnections, DatabaseGuild,
+    DatabaseJoinChannel, DatabaseShardSession, DatabaseStats, DatabaseVoiceChannel,
+    DatabaseVoiceChannelCreation,
+};
+use crate::structs::{cache::GuildAppearance, config::DatabaseConfig};
+
+pub struct PostgresDatabase {
+    pool: Pool,
+}
+
+pub struct ConnectOptions {
+    pub config: Config,
+    pub ssl_cert: Option<PathBuf>,
+    pub ssl_key: Option<PathBuf>,
+    pub ssl_mode: SslMode,
+    pub ssl_root_cert: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SslMode {
+    Disable,
+    Prefer,
+    Require,
+    VerifyCa,
+    VerifyFull,
+}
+
+impl PostgresDatabase {
+    pub fn new(config: &DatabaseConfig) -> Result<Self> {
+        let options = ConnectOptions::new(config)?;
+        let manager_config = ManagerConfig {
+            recycling_method: RecyclingMethod::Fast,
+        };
+
+        #[cfg(feature = "tls")]
+        let manager = Manager::from_config(
+            options.config.clone(),
+            super::tls::connector(&options)?,
+            manager_config,
+        );
+
+        #[cfg(not(feature = "tls"))]
+        let manager = {
+            ensure!(
+                options.ssl_mode < SslMode::Require
+                    && options.ssl_cert.is_none()
+                    && options.ssl_key.is_none()
+                    && options.ssl_root_cert.is_none(),
+                "TLS connections to PostgreSQL need the `tls` feature, which this build does not \
+                 include."
+            );
+
+            Manager::from_config(options.config, NoTls, manager_config)
+        };
+
+        Ok(Self {
+            pool: Pool::builder(manager)
+                .max_size(config.pool_size)
+                .build()
+                .wrap_err("Unable to create connection pool.")?,
+        })
+    }
+}
+
+impl ConnectOptions {
+    pub fn new(config: &DatabaseConfig) -> Result<Self> {
+        let mut url = config.url.clone();
+        let ssl_mode = match take_param(&mut url, "sslmode").as_deref() {
+            Some("disable") => SslMode::Disable,
+            None | Some("allow" | "prefer") => SslMode::Prefer,
+            Some("require") => SslMode::Require,
+            Some("verify-ca") => SslMode::VerifyCa,
+            Some("verify-full") => SslMode::VerifyFull,
+            Some(ssl_mode) => bail!("`sslmode={ssl_mode}` is not supported."),
+        };
+        let ssl_cert = take_param(&mut url, "sslcert").map(PathBuf::from);
+        let ssl_key = take_param(&mut url, "sslkey").map(PathBuf::from);
+        let ssl_root_cert = take_param(&mut url, "sslrootcert").map(PathBuf::from);
+        let mut postgres_config = url.parse::<Config>()?;
+
+        postgres_config.ssl_mode(match ssl_mode {
+            SslMode::Disable => TokioSslMode::Disable,
+            SslMode::Prefer => TokioSslMode::Prefer,
+            SslMode::Require | SslMode::VerifyCa | SslMode::VerifyFull => TokioSslMode::Require,
+        });
+
+        let ssl_cert = config.ssl_cert.clone().or(ssl_cert);
+        let ssl_key = config.ssl_key.clone().or(ssl_key);
+
+        if ssl_cert.is_some() != ssl_key.is_some() {
+            bail!("A client certificate and its key must be provided together.");
+        }
+
+        Ok(Self {
+            config: postgres_config,
+            ssl_cert,
+            ssl_key,
+            ssl_mode,
+            ssl_root_cert: config.ssl_root_cert.clone().or(ssl_root_cert),
+        })
+    }
+}
+
+#[async_trait]
+impl Database for PostgresDatabase {
+    fn close(&self) {
+        self.pool.close();
+    }
+
+    fn connections(&self) -> Option<DatabaseConnections> {
+        let status = self.pool.status();
+
+        Some(DatabaseConnections {
+            available: status.available,
+            max_size: status.max_size,
+            size: status.size,
+            waiting: status.waiting,
+        })
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn create_tables(&self) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            -- guild table
+            CREATE TABLE IF NOT EXISTS public.guild (
+                id INT8 PRIMARY KEY,
+                permanence BOOLEAN NOT NULL DEFAULT FALSE,
+                privacy TEXT NOT NULL DEFAULT 'unlocked',
+                locale TEXT,
+                appearance_color INT8,
+                appearance_description TEXT,
+                appearance_footer TEXT,
+                appearance_thumbnail_url TEXT,
+                appearance_title TEXT
+            );
+
+            ALTER TABLE public.guild ADD COLUMN IF NOT EXISTS locale TEXT;
+            ALTER TABLE public.guild ADD COLUMN IF NOT EXISTS appearance_color INT8;
+            ALTER TABLE public.guild ADD COLUMN IF NOT EXISTS appearance_description TEXT;
+            ALTER TABLE public.guild ADD COLUMN IF NOT EXISTS appearance_footer TEXT;
+            ALTER TABLE public.guild ADD COLUMN IF NOT EXISTS appearance_thumbnail_url TEXT;
+            ALTER TABLE public.guild ADD COLUMN IF NOT EXISTS appearance_title TEXT;
+
+            -- category_channel table
+            CREATE TABLE IF NOT EXISTS public.category_channel (
+                id INT8 PRIMARY KEY,
+                guild_id INT8 NOT NULL REFERENCES public.guild(id) ON DELETE CASCADE,
+                join_channel_id INT8
+            );
+
+            -- voice_channel table
+            CREATE TABLE IF NOT EXISTS public.voice_channel (
+                id INT8 PRIMARY KEY NOT NULL,
+                guild_id INT8 NOT NULL REFERENCES public.guild(id) ON DELETE CASCADE,
+                parent_id INT8 NOT NULL REFERENCES public.category_channel(id) ON DELETE CASCADE,
+                owner_id INT8,
+                panel_message_id INT8
+            );
+
+            -- join_channel table
+            CREATE TABLE IF NOT EXISTS public.join_channel (
+                id INT8 PRIMARY KEY,
+                guild_id INT8 NOT NULL REFERENCES public.guild(id) ON DELETE CASCADE,
+                category_channel_id INT8 NOT NULL
+                    REFERENCES public.category_channel(id) ON DELETE CASCADE,
+                name_template TEXT,
+                user_limit INT4,
+                privacy TEXT,
+                bitrate INT4,
+                template_name TEXT
+            );
+
+            ALTER TABLE public.join_channel ADD COLUMN IF NOT EXISTS template_name TEXT;
+
+            -- join channels used to be stored in category_channel.join_channel_id
+            INSERT INTO
+                public.join_channel (id, guild_id, category_channel_id)
+            SELECT
+                join_channel_id, guild_id, id
+            FROM
+                public.category_channel
+            WHERE
+                join_channel_id IS NOT NULL
+            ON CONFLICT
+            DO NOTHING;
+
+            UPDATE
+                public.category_channel
+            SET
+                join_channel_id = NULL
+            WHERE
+                join_channel_id IS NOT NULL;
+
+            -- channel_template table
+            CREATE TABLE IF NOT EXISTS public.channel_template (
+                guild_id INT8 NOT NULL REFERENCES public.guild(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                user_limit INT4,
+                privacy TEXT,
+                bitrate INT4,
+                permission_overwrites TEXT NOT NULL DEFAULT '[]',
+                PRIMARY KEY (guild_id, name)
+            );
+
+            -- shard_session table
+            CREATE TABLE IF NOT EXISTS public.shard_session (
+                id INT4 PRIMARY KEY,
+                total INT4 NOT NULL,
+                cache TEXT NOT NULL,
+                session_id TEXT NOT NULL,
+                sequence INT8 NOT NULL,
+                saved_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                resume_url TEXT
+            );
+
+            ALTER TABLE public.shard_session ADD COLUMN IF NOT EXISTS resume_url TEXT;
+
+            -- command_guild table
+            CREATE TABLE IF NOT EXISTS public.command_guild (
+                id INT8 PRIMARY KEY
+            );
+
+            -- voice_channel_creation only recorded channels after Discord had created them
+            DROP TABLE IF EXISTS public.voice_channel_creation;
+
+            -- pending_voice_channel table
+            CREATE TABLE IF NOT EXISTS public.pending_voice_channel (
+                guild_id INT8 NOT NULL REFERENCES public.guild(id) ON DELETE CASCADE,
+                owner_id INT8 NOT NULL,
+                name TEXT NOT NULL,
+                parent_id INT8 NOT NULL,
+                id INT8,
+                started_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                PRIMARY KEY (guild_id, owner_id)
+            );
+        ";
+
+        client.batch_execute(statement).await?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<DatabaseGuild>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                guild
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let row = client
+            .query_one(statement, params)
+            .await
+            .wrap_err("Unable to run \"guild\" endpoint");
+        let guild = row.ok().map(DatabaseGuild::from);
+
+        Ok(guild)
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guilds(&self) -> Result<Vec<DatabaseGuild>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                guild;
+        ";
+        let rows = client
+            .query(statement, &[])
+            .await
+            .wrap_err("Unable to run \"guilds\" endpoint")?;
+
+        Ok(rows.into_iter().map(DatabaseGuild::from).collect())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        permanence: bool,
+        privacy: &str,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                guild (id, permanence, privacy)
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &permanence, &privacy];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn ping(&self) -> Result<()> {
+        let client = self.pool.get().await?;
+
+        client
+            .simple_query("SELECT 1;")
+            .await
+            .wrap_err("Unable to run \"ping\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn take_shard_sessions(&self) -> Result<Vec<DatabaseShardSession>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            WITH
+                shard_session AS (
+                    DELETE FROM
+                        shard_session
+                    RETURNING
+                        *
+                )
+            SELECT
+                *
+            FROM
+                shard_session
+            WHERE
+                saved_at > now() - INTERVAL '5 minutes';
+        ";
+        let rows = client
+            .query(statement, &[])
+            .await
+            .wrap_err("Unable to run \"take_shard_sessions\" endpoint")?;
+
+        Ok(rows.into_iter().map(DatabaseShardSession::from).collect())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_shard_session(
+        &self,
+        id: u32,
+        total: u32,
+        session_id: &str,
+        sequence: u64,
+        resume_url: Option<&str>,
+        cache: &str,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                shard_session (id, total, cache, session_id, sequence, resume_url)
+            VALUES
+                ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id)
+            DO UPDATE SET
+                total = EXCLUDED.total,
+                cache = EXCLUDED.cache,
+                session_id = EXCLUDED.session_id,
+                sequence = EXCLUDED.sequence,
+                resume_url = EXCLUDED.resume_url,
+                saved_at = now();
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(id as i32),
+            &(total as i32),
+            &cache,
+            &session_id,
+            &(sequence as i64),
+            &resume_url,
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_shard_session\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn command_guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                id
+            FROM
+                command_guild;
+        ";
+        let rows = client
+            .query(statement, &[])
+            .await
+            .wrap_err("Unable to run \"command_guild_ids\" endpoint")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Id::new(row.get::<_, i64>("id") as u64))
+            .collect())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                command_guild (id)
+            VALUES
+                ($1)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_command_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                command_guild
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_command_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_ids: Vec<Id<ChannelMarker>>,
+    ) -> Result<Vec<Id<ChannelMarker>>> {
+        let client = self.pool.get().await?;
+        let statements = vec![
+            "
+                DELETE FROM
+                    category_channel
+                WHERE
+                    guild_id = $1
+                    AND NOT(id = ANY($2::INT8[]))
+                RETURNING
+                    id;
+            ",
+            "
+                DELETE FROM
+                    join_channel
+                WHERE
+                    guild_id = $1
+                    AND NOT(id = ANY($2::INT8[]))
+                RETURNING
+                    id;
+            ",
+            "
+                DELETE FROM
+                    voice_channel
+                WHERE
+                    guild_id = $1
+                    AND NOT(id = ANY($2::INT8[]))
+                RETURNING
+                    id;
+            ",
+        ];
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &channel_ids
+                .into_iter()
+                .map(|id| id.get() as i64)
+                .collect::<Vec<i64>>(),
+        ];
+        let prepared_statements = try_join_all(
+            statements
+                .into_iter()
+                .map(|statement| client.prepare(statement)),
+        )
+        .await?;
+        let results = try_join_all(
+            prepared_statements
+                .iter()
+                .map(|prepared_statement| client.query(prepared_statement, params)),
+        )
+        .await
+        .wrap_err("Unable to run \"remove_channels\" endpoint")?;
+        let removed_channel_ids = results
+            .into_iter()
+            .flatten()
+            .map(|row| Id::new(row.get::<_, i64>("id") as u64))
+            .collect::<Vec<Id<ChannelMarker>>>();
+
+        Ok(removed_channel_ids)
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_channel_and_parent_ids: Vec<(Id<ChannelMarker>, Id<ChannelMarker>)>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let values_param = voice_channel_and_parent_ids
+            .into_iter()
+            .map(|(id, parent_id)| format!("({}, {})", id.get() as i64, parent_id.get() as i64))
+            .collect::<Vec<String>>()
+            .join(",");
+        let statement = format!(
+            "
+            UPDATE
+                voice_channel
+            SET
+                parent_id = channel.parent_id
+            FROM 
+                (VALUES {values_param}) AS channel(id, parent_id)
+            WHERE
+                voice_channel.guild_id = $1
+                AND voice_channel.id = channel.id;
+        "
+        );
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client
+            .execute(&statement, params)
+            .await
+            .wrap_err("Unable to run \"update_channels\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_panel_message(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        panel_message_id: Option<Id<MessageMarker>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                voice_channel
+            SET
+                panel_message_id = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(voice_channel_id.get() as i64),
+            &(panel_message_id.map(|id| id.get() as i64)),
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_panel_message\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_category_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseCategoryChannel>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                category_channel
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let rows = client
+            .query(statement, params)
+            .await
+            .wrap_err("Unable to run \"guild_category_channels\" endpoint");
+        let guild_category_channels = rows
+            .unwrap_or_default()
+            .into_iter()
+            .map(DatabaseCategoryChannel::from)
+            .collect::<Vec<DatabaseCategoryChannel>>();
+
+        Ok(guild_category_channels)
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_voice_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseVoiceChannel>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                voice_channel 
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let rows = client
+            .query(statement, params)
+            .await
+            .wrap_err("Unable to run \"guild_voice_channels\" endpoint");
+        let guild_voice_channels = rows
+            .unwrap_or_default()
+            .into_iter()
+            .map(DatabaseVoiceChannel::from)
+            .collect::<Vec<DatabaseVoiceChannel>>();
+
+        Ok(guild_voice_channels)
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_voice_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+        parent_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                voice_channel
+            VALUES
+                ($1, $2, $3, $4)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(id.get() as i64),
+            &(guild_id.get() as i64),
+            &(parent_id.get() as i64),
+            &(owner_id.map(|id| id.get() as i64)),
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_category_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_voice_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                voice_channel
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(channel_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_voice_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                guild
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_category_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                category_channel
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(channel_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_category_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_appearance(
+        &self,
+        guild_id: Id<GuildMarker>,
+        appearance: GuildAppearance,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                guild
+            SET
+                appearance_color = $2,
+                appearance_description = $3,
+                appearance_footer = $4,
+                appearance_thumbnail_url = $5,
+                appearance_title = $6
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &appearance.color.map(i64::from),
+            &appearance.description,
+            &appearance.footer,
+            &appearance.thumbnail_url,
+            &appearance.title,
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_appearance\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_locale(&self, guild_id: Id<GuildMarker>, locale: Option<String>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                guild
+            SET
+                locale = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &locale];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_locale\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_permanence(&self, guild_id: Id<GuildMarker>, permanence: bool) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                guild
+            SET
+                permanence = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &permanence];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_permanence\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_privacy(&self, guild_id: Id<GuildMarker>, privacy: String) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                guild
+            SET
+                privacy = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &privacy];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_privacy\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_voice_channel_owner(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                voice_channel
+            SET
+                owner_id = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(voice_channel_id.get() as i64),
+            &(owner_id.map(|id| id.get() as i64)),
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_voice_channel_owner\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_category_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                category_channel (id, guild_id)
+            VALUES
+                ($1, $2)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(id.get() as i64), &(guild_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_category_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_join_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseJoinChannel>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                join_channel
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let rows = client
+            .query(statement, params)
+            .await
+            .wrap_err("Unable to run \"guild_join_channels\" endpoint")?;
+
+        Ok(rows.into_iter().map(DatabaseJoinChannel::from).collect())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                join_channel (
+                    id,
+                    guild_id,
+                    category_channel_id,
+                    name_template,
+                    user_limit,
+                    privacy,
+                    bitrate,
+                    template_name
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(join_channel.id.get() as i64),
+            &(join_channel.guild_id.get() as i64),
+            &(join_channel.category_channel_id.get() as i64),
+            &join_channel.name_template,
+            &(join_channel.user_limit.map(i32::from)),
+            &join_channel.privacy,
+            &(join_channel.bitrate.map(|bitrate| bitrate as i32)),
+            &join_channel.template_name,
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_join_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_join_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                join_channel
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(channel_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_join_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                join_channel
+            SET
+                name_template = $2,
+                user_limit = $3,
+                privacy = $4,
+                bitrate = $5,
+                template_name = $6
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(join_channel.id.get() as i64),
+            &join_channel.name_template,
+            &(join_channel.user_limit.map(i32::from)),
+            &join_channel.privacy,
+            &(join_channel.bitrate.map(|bitrate| bitrate as i32)),
+            &join_channel.template_name,
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_join_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_join_channel_category(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        category_channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                join_channel
+            SET
+                category_channel_id = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(channel_id.get() as i64),
+            &(category_channel_id.get() as i64),
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_join_channel_category\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_join_channel_template(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        template_name: Option<String>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                join_channel
+            SET
+                template_name = $2
+            WHERE
+                id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(channel_id.get() as i64), &template_name];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_join_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn channel_template(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: String,
+    ) -> Result<Option<DatabaseChannelTemplate>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                channel_template
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name];
+        let row = client
+            .query_opt(statement, params)
+            .await
+            .wrap_err("Unable to run \"channel_template\" endpoint")?;
+
+        Ok(row.map(DatabaseChannelTemplate::from))
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_channel_templates(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseChannelTemplate>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                channel_template
+            WHERE
+                guild_id = $1
+            ORDER BY
+                name;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let rows = client
+            .query(statement, params)
+            .await
+            .wrap_err("Unable to run \"guild_channel_templates\" endpoint")?;
+
+        Ok(rows
+            .into_iter()
+            .map(DatabaseChannelTemplate::from)
+            .collect())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                channel_template (
+                    guild_id,
+                    name,
+                    user_limit,
+                    privacy,
+                    bitrate,
+                    permission_overwrites
+                )
+            VALUES
+                ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let permission_overwrites = serde_json::to_string(&channel_template.permission_overwrites)?;
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(channel_template.guild_id.get() as i64),
+            &channel_template.name,
+            &(channel_template.user_limit.map(i32::from)),
+            &channel_template.privacy,
+            &(channel_template.bitrate.map(|bitrate| bitrate as i32)),
+            &permission_overwrites,
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                channel_template
+            SET
+                user_limit = $3,
+                privacy = $4,
+                bitrate = $5,
+                permission_overwrites = $6
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let permission_overwrites = serde_json::to_string(&channel_template.permission_overwrites)?;
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(channel_template.guild_id.get() as i64),
+            &channel_template.name,
+            &(channel_template.user_limit.map(i32::from)),
+            &channel_template.privacy,
+            &(channel_template.bitrate.map(|bitrate| bitrate as i32)),
+            &permission_overwrites,
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_channel_template(&self, guild_id: Id<GuildMarker>, name: String) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            WITH
+                join_channel AS (
+                    UPDATE
+                        join_channel
+                    SET
+                        template_name = NULL
+                    WHERE
+                        guild_id = $1
+                        AND template_name = $2
+                )
+            DELETE FROM
+                channel_template
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_voice_channel_creation(
+        &self,
+        voice_channel_creation: DatabaseVoiceChannelCreation,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                pending_voice_channel (guild_id, owner_id, name, parent_id, id)
+            VALUES
+                ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, owner_id)
+            DO UPDATE SET
+                name = excluded.name,
+                parent_id = excluded.parent_id,
+                id = excluded.id,
+                started_at = now();
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(voice_channel_creation.guild_id.get() as i64),
+            &(voice_channel_creation.owner_id.get() as i64),
+            &voice_channel_creation.name,
+            &(voice_channel_creation.parent_id.get() as i64),
+            &voice_channel_creation.id.map(|id| id.get() as i64),
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"insert_voice_channel_creation\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+        id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                pending_voice_channel
+            SET
+                id = $3
+            WHERE
+                guild_id = $1
+                AND owner_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &(owner_id.get() as i64),
+            &(id.get() as i64),
+        ];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"update_voice_channel_creation\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                pending_voice_channel
+            WHERE
+                guild_id = $1
+                AND owner_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &(owner_id.get() as i64)];
+
+        client
+            .execute(statement, params)
+            .await
+            .wrap_err("Unable to run \"remove_voice_channel_creation\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn voice_channel_creations(&self) -> Result<Vec<DatabaseVoiceChannelCreation>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                pending_voice_channel;
+        ";
+        let rows = client
+            .query(statement, &[])
+            .await
+            .wrap_err("Unable to run \"voice_channel_creations\" endpoint")?;
+
+        Ok(rows
+            .into_iter()
+            .map(DatabaseVoiceChannelCreation::from)
+            .collect())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn stats(&self) -> Result<DatabaseStats> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                (SELECT COUNT(*) FROM category_channel) AS category_channels,
+                (SELECT COUNT(*) FROM channel_template) AS channel_templates,
+                (SELECT COUNT(*) FROM guild) AS guilds,
+                (SELECT COUNT(*) FROM join_channel) AS join_channels,
+                (SELECT COUNT(*) FROM shard_session) AS shard_sessions,
+                (SELECT COUNT(*) FROM pending_voice_channel) AS voice_channel_creations,
+                (SELECT COUNT(*) FROM voice_channel) AS voice_channels;
+        ";
+        let row = client
+            .query_one(statement, &[])
+            .await
+            .wrap_err("Unable to run \"stats\" endpoint")?;
+
+        Ok(DatabaseStats::from(row))
+    }
+}
+
+impl From<Row> for DatabaseCategoryChannel {
+    fn from(row: Row) -> Self {
+        Self {
+            guild_id: Id::new(row.get::<_, i64>("guild_id") as u64),
+            id: Id::new(row.get::<_, i64>("id") as u64),
+        }
+    }
+}
+
+impl From<Row> for DatabaseChannelTemplate {
+    fn from(row: Row) -> Self {
+        Self {
+            bitrate: row
+                .get::<_, Option<i32>>("bitrate")
+                .map(|bitrate| bitrate as u32),
+            guild_id: Id::new(row.get::<_, i64>("guild_id") as u64),
+            name: row.get::<_, String>("name"),
+            permission_overwrites: serde_json::from_str(
+                &row.get::<_, String>("permission_overwrites"),
+            )
+            .unwrap_or_default(),
+            privacy: row.get::<_, Option<String>>("privacy"),
+            user_limit: row
+                .get::<_, Option<i32>>("user_limit")
+                .map(|user_limit| user_limit as u16),
+        }
+    }
+}
+
+impl From<Row> for DatabaseGuild {
+    fn from(row: Row) -> Self {
+        Self {
+            appearance: GuildAppearance {
+                color: row
+                    .get::<_, Option<i64>>("appearance_color")
+                    .map(|color| color as u32),
+                description: row.get::<_, Option<String>>("appearance_description"),
+                footer: row.get::<_, Option<String>>("appearance_footer"),
+                thumbnail_url: row.get::<_, Option<String>>("appearance_thumbnail_url"),
+                title: row.get::<_, Option<String>>("appearance_title"),
+            },
+            id: Id::new(row.get::<_, i64>("id") as u64),
+            locale: row.get::<_, Option<String>>("locale"),
+            permanence: row.get::<_, bool>("permanence"),
+            privacy: row.get::<_, String>("privacy"),
+        }
+    }
+}
+
+impl From<Row> for DatabaseJoinChannel {
+    fn from(row: Row) -> Self {
+        Self {
+            bitrate: row
+                .get::<_, Option<i32>>("bitrate")
+                .map(|bitrate| bitrate as u32),
+            category_channel_id: Id::new(row.get::<_, i64>("category_channel_id") as u64),
+            guild_id: Id::new(row.get::<_, i64>("guild_id") as u64),
+            id: Id::new(row.get::<_, i64>("id") as u64),
+            name_template: row.get::<_, Option<String>>("name_template"),
+            privacy: row.get::<_, Option<String>>("privacy"),
+            template_name: row.get::<_, Option<String>>("template_name"),
+            user_limit: row
+                .get::<_, Option<i32>>("user_limit")
+                .map(|user_limit| user_limit as u16),
+        }
+    }
+}
+
+impl From<Row> for DatabaseShardSession {
+    fn from(row: Row) -> Self {
+        Self {
+            cache: row.get::<_, String>("cache"),
+            id: row.get::<_, i32>("id") as u32,
+            resume_url: row.get::<_, Option<String>>("resume_url"),
+            sequence: row.get::<_, i64>("sequence") as u64,
+            session_id: row.get::<_, String>("session_id"),
+            total: row.get::<_, i32>("total") as u32,
+        }
+    }
+}
+
+impl From<Row> for DatabaseStats {
+    fn from(row: Row) -> Self {
+        Self {
+            category_channels: row.get::<_, i64>("category_channels") as u64,
+            channel_templates: row.get::<_, i64>("channel_templates") as u64,
+            guilds: row.get::<_, i64>("guilds") as u64,
+            join_channels: row.get::<_, i64>("join_channels") as u64,
+            shard_sessions: row.get::<_, i64>("shard_sessions") as u64,
+            voice_channel_creations: row.get::<_, i64>("voice_channel_creations") as u64,
+            voice_channels: row.get::<_, i64>("voice_channels") as u64,
+        }
+    }
+}
+
+impl From<Row> for DatabaseVoiceChannel {
+    fn from(row: Row) -> Self {
+        Self {
+            id: Id::new(row.get::<_, i64>("id") as u64),
+            guild_id: Id::new(row.get::<_, i64>("guild_id") as u64),
+            parent_id: Id::new(row.get::<_, i64>("parent_id") as u64),
+            owner_id: row
+                .try_get::<_, i64>("owner_id")
+                .map_or(None, |id| Some(Id::new(id as u64))),
+            panel_message_id: row
+                .try_get::<_, i64>("panel_message_id")
+                .map_or(None, |id| Some(Id::new(id as u64))),
+        }
+    }
+}
+
+fn take_param(url: &mut String, name: &str) -> Option<String> {
+    let is_uri = url.starts_with("postgres://") || url.starts_with("postgresql://");
+    let (base, params, separator) = if is_uri {
+        let (base, query) = url.split_once('?')?;
+
+        (base, query, "&")
+    } else {
+        ("", url.as_str(), " ")
+    };
+    let mut value = None;
+    let remaining_params = params
+        .split(separator)
+        .filter(|param| match param.split_once('=') {
+            Some((key, param_value)) if key == name => {
+                value = Some(param_value.to_owned());
+
+                false
+            }
+            _ => true,
+        })
+        .collect::<Vec<&str>>()
+        .join(separator);
+
+    *url = match (is_uri, remaining_params.is_empty()) {
+        (true, true) => base.to_owned(),
+        (true, false) => format!("{base}?{remaining_params}"),
+        (false, _) => remaining_params,
+    };
+
+    value
+}
+
+impl From<Row> for DatabaseVoiceChannelCreation {
+    fn from(row: Row) -> Self {
+        Self {
+            guild_id: Id::new(row.get::<_, i64>("guild_id") as u64),
+            id: row.get::<_, Option<i64>>("id").map(|id| Id::new(id as u64)),
+            name: row.get("name"),
+            owner_id: Id::new(row.get::<_, i64>("owner_id") as u64),
+            parent_id: Id::new(row.get::<_, i64>("parent_id") as u64),
+        }
+    }
+}
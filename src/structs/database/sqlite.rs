@@ -0,0 +1,1431 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use eyre::{Context, Result};
+use parking_lot::Mutex;
+use rusqlite::{params, Connection, Error as SqliteError, Row};
+use tokio::task::spawn_blocking;
+use tracing::instrument;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+use super::{
+    Database, DatabaseCategoryChannel, DatabaseChannelTemplate, DatabaseConnections, DatabaseGuild,
+    DatabaseJoinChannel, DatabaseShardSession, DatabaseStats, DatabaseVoiceChannel,
+    DatabaseVoiceChannelCreation, SQLITE_URL_SCHEME,
+};
+use crate::structs::{cache::GuildAppearance, config::DatabaseConfig};
+
+// Columns added to tables after their creation, as SQLite has no `ADD COLUMN IF NOT EXISTS`.
+const ADDED_COLUMNS: [(&str, &str, &str); 8] = [
+    ("guild", "locale", "TEXT"),
+    ("guild", "appearance_color", "INTEGER"),
+    ("guild", "appearance_description", "TEXT"),
+    ("guild", "appearance_footer", "TEXT"),
+    ("guild", "appearance_thumbnail_url", "TEXT"),
+    ("guild", "appearance_title", "TEXT"),
+    ("join_channel", "template_name", "TEXT"),
+    ("shard_session", "resume_url", "TEXT"),
+];
+
+pub struct SqliteDatabase {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteDatabase {
+    pub fn new(config: &DatabaseConfig) -> Result<Self> {
+        let path = config
+            .url
+            .strip_prefix("sqlite://")
+            .or_else(|| config.url.strip_prefix(SQLITE_URL_SCHEME))
+            .unwrap_or(&config.url);
+        let connection = Connection::open(path).wrap_err("Unable to open SQLite database.")?;
+
+        connection
+            .pragma_update(None, "foreign_keys", true)
+            .wrap_err("Unable to enable SQLite foreign keys.")?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    async fn run<T: Send + 'static>(
+        &self,
+        query: impl FnOnce(&mut Connection) -> Result<T, SqliteError> + Send + 'static,
+    ) -> Result<T> {
+        let connection = Arc::clone(&self.connection);
+
+        Ok(spawn_blocking(move || query(&mut connection.lock())).await??)
+    }
+}
+
+#[async_trait]
+impl Database for SqliteDatabase {
+    fn close(&self) {}
+
+    fn connections(&self) -> Option<DatabaseConnections> {
+        None
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn create_tables(&self) -> Result<()> {
+        let statement = "
+            -- guild table
+            CREATE TABLE IF NOT EXISTS guild (
+                id INTEGER PRIMARY KEY,
+                permanence BOOLEAN NOT NULL DEFAULT FALSE,
+                privacy TEXT NOT NULL DEFAULT 'unlocked',
+                locale TEXT,
+                appearance_color INTEGER,
+                appearance_description TEXT,
+                appearance_footer TEXT,
+                appearance_thumbnail_url TEXT,
+                appearance_title TEXT
+            );
+
+            -- category_channel table
+            CREATE TABLE IF NOT EXISTS category_channel (
+                id INTEGER PRIMARY KEY,
+                guild_id INTEGER NOT NULL REFERENCES guild(id) ON DELETE CASCADE,
+                join_channel_id INTEGER
+            );
+
+            -- voice_channel table
+            CREATE TABLE IF NOT EXISTS voice_channel (
+                id INTEGER PRIMARY KEY NOT NULL,
+                guild_id INTEGER NOT NULL REFERENCES guild(id) ON DELETE CASCADE,
+                parent_id INTEGER NOT NULL REFERENCES category_channel(id) ON DELETE CASCADE,
+                owner_id INTEGER,
+                panel_message_id INTEGER
+            );
+
+            -- join_channel table
+            CREATE TABLE IF NOT EXISTS join_channel (
+                id INTEGER PRIMARY KEY,
+                guild_id INTEGER NOT NULL REFERENCES guild(id) ON DELETE CASCADE,
+                category_channel_id INTEGER NOT NULL
+                    REFERENCES category_channel(id) ON DELETE CASCADE,
+                name_template TEXT,
+                user_limit INTEGER,
+                privacy TEXT,
+                bitrate INTEGER,
+                template_name TEXT
+            );
+
+            -- join channels used to be stored in category_channel.join_channel_id
+            INSERT OR IGNORE INTO
+                join_channel (id, guild_id, category_channel_id)
+            SELECT
+                join_channel_id, guild_id, id
+            FROM
+                category_channel
+            WHERE
+                join_channel_id IS NOT NULL;
+
+            UPDATE
+                category_channel
+            SET
+                join_channel_id = NULL
+            WHERE
+                join_channel_id IS NOT NULL;
+
+            -- channel_template table
+            CREATE TABLE IF NOT EXISTS channel_template (
+                guild_id INTEGER NOT NULL REFERENCES guild(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                user_limit INTEGER,
+                privacy TEXT,
+                bitrate INTEGER,
+                permission_overwrites TEXT NOT NULL DEFAULT '[]',
+                PRIMARY KEY (guild_id, name)
+            );
+
+            -- shard_session table
+            CREATE TABLE IF NOT EXISTS shard_session (
+                id INTEGER PRIMARY KEY,
+                total INTEGER NOT NULL,
+                cache TEXT NOT NULL,
+                session_id TEXT NOT NULL,
+                sequence INTEGER NOT NULL,
+                saved_at INTEGER NOT NULL DEFAULT (unixepoch()),
+                resume_url TEXT
+            );
+
+            -- command_guild table
+            CREATE TABLE IF NOT EXISTS command_guild (
+                id INTEGER PRIMARY KEY
+            );
+
+            -- voice_channel_creation only recorded channels after Discord had created them
+            DROP TABLE IF EXISTS voice_channel_creation;
+
+            -- pending_voice_channel table
+            CREATE TABLE IF NOT EXISTS pending_voice_channel (
+                guild_id INTEGER NOT NULL REFERENCES guild(id) ON DELETE CASCADE,
+                owner_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                parent_id INTEGER NOT NULL,
+                id INTEGER,
+                started_at INTEGER NOT NULL DEFAULT (unixepoch()),
+                PRIMARY KEY (guild_id, owner_id)
+            );
+        ";
+
+        self.run(move |connection| {
+            connection.execute_batch(statement)?;
+
+            for (table, column, definition) in ADDED_COLUMNS {
+                let exists = connection
+                    .prepare_cached("SELECT 1 FROM pragma_table_info(?1) WHERE name = ?2;")?
+                    .exists(params![table, column])?;
+
+                if !exists {
+                    connection.execute_batch(&format!(
+                        "ALTER TABLE {table} ADD COLUMN {column} {definition};"
+                    ))?;
+                }
+            }
+
+            Ok(())
+        })
+        .await
+        .wrap_err("Unable to run \"create_tables\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild(&self, guild_id: Id<GuildMarker>) -> Result<Option<DatabaseGuild>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                guild
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map(params![guild_id.get() as i64], |row| {
+                    DatabaseGuild::try_from(row)
+                })?
+                .next()
+                .transpose()
+        })
+        .await
+        .wrap_err("Unable to run \"guild\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guilds(&self) -> Result<Vec<DatabaseGuild>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                guild;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map([], |row| DatabaseGuild::try_from(row))?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"guilds\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        permanence: bool,
+        privacy: &str,
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                guild (id, permanence, privacy)
+            VALUES
+                (?1, ?2, ?3)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let privacy = privacy.to_owned();
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![guild_id.get() as i64, permanence, privacy],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"insert_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn ping(&self) -> Result<()> {
+        self.run(|connection| connection.execute_batch("SELECT 1;"))
+            .await
+            .wrap_err("Unable to run \"ping\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn take_shard_sessions(&self) -> Result<Vec<DatabaseShardSession>> {
+        let statements = [
+            "
+                SELECT
+                    *
+                FROM
+                    shard_session
+                WHERE
+                    saved_at > unixepoch() - 5 * 60;
+            ",
+            "
+                DELETE FROM
+                    shard_session;
+            ",
+        ];
+
+        self.run(move |connection| {
+            let transaction = connection.transaction()?;
+            let shard_sessions = transaction
+                .prepare(statements[0])?
+                .query_map([], |row| DatabaseShardSession::try_from(row))?
+                .collect::<Result<Vec<DatabaseShardSession>, SqliteError>>()?;
+
+            transaction.execute(statements[1], [])?;
+            transaction.commit()?;
+
+            Ok(shard_sessions)
+        })
+        .await
+        .wrap_err("Unable to run \"take_shard_sessions\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_shard_session(
+        &self,
+        id: u32,
+        total: u32,
+        session_id: &str,
+        sequence: u64,
+        resume_url: Option<&str>,
+        cache: &str,
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                shard_session (id, total, cache, session_id, sequence, resume_url)
+            VALUES
+                (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT (id)
+            DO UPDATE SET
+                total = excluded.total,
+                cache = excluded.cache,
+                session_id = excluded.session_id,
+                sequence = excluded.sequence,
+                resume_url = excluded.resume_url,
+                saved_at = unixepoch();
+        ";
+        let session_id = session_id.to_owned();
+        let resume_url = resume_url.map(ToOwned::to_owned);
+        let cache = cache.to_owned();
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![id, total, cache, session_id, sequence as i64, resume_url],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"insert_shard_session\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn command_guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        let statement = "
+            SELECT
+                id
+            FROM
+                command_guild;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map([], |row| Ok(Id::new(row.get::<_, i64>("id")? as u64)))?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"command_guild_ids\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                command_guild (id)
+            VALUES
+                (?1)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+
+        self.run(move |connection| connection.execute(statement, params![guild_id.get() as i64]))
+            .await
+            .wrap_err("Unable to run \"insert_command_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_command_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let statement = "
+            DELETE FROM
+                command_guild
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| connection.execute(statement, params![guild_id.get() as i64]))
+            .await
+            .wrap_err("Unable to run \"remove_command_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_ids: Vec<Id<ChannelMarker>>,
+    ) -> Result<Vec<Id<ChannelMarker>>> {
+        let channel_ids_param = channel_ids
+            .into_iter()
+            .map(|id| (id.get() as i64).to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        let statements = [
+            format!(
+                "
+                DELETE FROM
+                    category_channel
+                WHERE
+                    guild_id = ?1
+                    AND id NOT IN ({channel_ids_param})
+                RETURNING
+                    id;
+            "
+            ),
+            format!(
+                "
+                DELETE FROM
+                    join_channel
+                WHERE
+                    guild_id = ?1
+                    AND id NOT IN ({channel_ids_param})
+                RETURNING
+                    id;
+            "
+            ),
+            format!(
+                "
+                DELETE FROM
+                    voice_channel
+                WHERE
+                    guild_id = ?1
+                    AND id NOT IN ({channel_ids_param})
+                RETURNING
+                    id;
+            "
+            ),
+        ];
+
+        self.run(move |connection| {
+            let transaction = connection.transaction()?;
+            let mut removed_channel_ids = Vec::new();
+
+            for statement in &statements {
+                let ids = transaction
+                    .prepare(statement)?
+                    .query_map(params![guild_id.get() as i64], |row| {
+                        row.get::<_, i64>("id")
+                    })?
+                    .collect::<Result<Vec<i64>, SqliteError>>()?;
+
+                removed_channel_ids.extend(ids.into_iter().map(|id| Id::new(id as u64)));
+            }
+
+            transaction.commit()?;
+
+            Ok(removed_channel_ids)
+        })
+        .await
+        .wrap_err("Unable to run \"remove_channels\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_channel_and_parent_ids: Vec<(Id<ChannelMarker>, Id<ChannelMarker>)>,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                voice_channel
+            SET
+                parent_id = ?3
+            WHERE
+                guild_id = ?1
+                AND id = ?2;
+        ";
+
+        self.run(move |connection| {
+            let transaction = connection.transaction()?;
+
+            for (id, parent_id) in voice_channel_and_parent_ids {
+                transaction.execute(
+                    statement,
+                    params![
+                        guild_id.get() as i64,
+                        id.get() as i64,
+                        parent_id.get() as i64
+                    ],
+                )?;
+            }
+
+            transaction.commit()
+        })
+        .await
+        .wrap_err("Unable to run \"update_channels\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_panel_message(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        panel_message_id: Option<Id<MessageMarker>>,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                voice_channel
+            SET
+                panel_message_id = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    voice_channel_id.get() as i64,
+                    panel_message_id.map(|id| id.get() as i64)
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_panel_message\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_category_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseCategoryChannel>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                category_channel
+            WHERE
+                guild_id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map(params![guild_id.get() as i64], |row| {
+                    DatabaseCategoryChannel::try_from(row)
+                })?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"guild_category_channels\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_voice_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseVoiceChannel>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                voice_channel
+            WHERE
+                guild_id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map(params![guild_id.get() as i64], |row| {
+                    DatabaseVoiceChannel::try_from(row)
+                })?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"guild_voice_channels\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_voice_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+        parent_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                voice_channel (id, guild_id, parent_id, owner_id)
+            VALUES
+                (?1, ?2, ?3, ?4)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    id.get() as i64,
+                    guild_id.get() as i64,
+                    parent_id.get() as i64,
+                    owner_id.map(|id| id.get() as i64)
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"insert_voice_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_voice_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        let statement = "
+            DELETE FROM
+                voice_channel
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| connection.execute(statement, params![channel_id.get() as i64]))
+            .await
+            .wrap_err("Unable to run \"remove_voice_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_guild(&self, guild_id: Id<GuildMarker>) -> Result<()> {
+        let statement = "
+            DELETE FROM
+                guild
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| connection.execute(statement, params![guild_id.get() as i64]))
+            .await
+            .wrap_err("Unable to run \"remove_guild\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_category_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        let statement = "
+            DELETE FROM
+                category_channel
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| connection.execute(statement, params![channel_id.get() as i64]))
+            .await
+            .wrap_err("Unable to run \"remove_category_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_appearance(
+        &self,
+        guild_id: Id<GuildMarker>,
+        appearance: GuildAppearance,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                guild
+            SET
+                appearance_color = ?2,
+                appearance_description = ?3,
+                appearance_footer = ?4,
+                appearance_thumbnail_url = ?5,
+                appearance_title = ?6
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    guild_id.get() as i64,
+                    appearance.color,
+                    appearance.description,
+                    appearance.footer,
+                    appearance.thumbnail_url,
+                    appearance.title
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_appearance\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_locale(&self, guild_id: Id<GuildMarker>, locale: Option<String>) -> Result<()> {
+        let statement = "
+            UPDATE
+                guild
+            SET
+                locale = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(statement, params![guild_id.get() as i64, locale])
+        })
+        .await
+        .wrap_err("Unable to run \"update_locale\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_permanence(&self, guild_id: Id<GuildMarker>, permanence: bool) -> Result<()> {
+        let statement = "
+            UPDATE
+                guild
+            SET
+                permanence = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(statement, params![guild_id.get() as i64, permanence])
+        })
+        .await
+        .wrap_err("Unable to run \"update_permanence\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_privacy(&self, guild_id: Id<GuildMarker>, privacy: String) -> Result<()> {
+        let statement = "
+            UPDATE
+                guild
+            SET
+                privacy = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(statement, params![guild_id.get() as i64, privacy])
+        })
+        .await
+        .wrap_err("Unable to run \"update_privacy\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_voice_channel_owner(
+        &self,
+        voice_channel_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                voice_channel
+            SET
+                owner_id = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    voice_channel_id.get() as i64,
+                    owner_id.map(|id| id.get() as i64)
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_voice_channel_owner\" endpoint.")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_category_channel(
+        &self,
+        id: Id<ChannelMarker>,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                category_channel (id, guild_id)
+            VALUES
+                (?1, ?2)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(statement, params![id.get() as i64, guild_id.get() as i64])
+        })
+        .await
+        .wrap_err("Unable to run \"insert_category_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_join_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseJoinChannel>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                join_channel
+            WHERE
+                guild_id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map(params![guild_id.get() as i64], |row| {
+                    DatabaseJoinChannel::try_from(row)
+                })?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"guild_join_channels\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                join_channel (
+                    id,
+                    guild_id,
+                    category_channel_id,
+                    name_template,
+                    user_limit,
+                    privacy,
+                    bitrate,
+                    template_name
+                )
+            VALUES
+                (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    join_channel.id.get() as i64,
+                    join_channel.guild_id.get() as i64,
+                    join_channel.category_channel_id.get() as i64,
+                    join_channel.name_template,
+                    join_channel.user_limit,
+                    join_channel.privacy,
+                    join_channel.bitrate,
+                    join_channel.template_name
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"insert_join_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_join_channel(&self, channel_id: Id<ChannelMarker>) -> Result<()> {
+        let statement = "
+            DELETE FROM
+                join_channel
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| connection.execute(statement, params![channel_id.get() as i64]))
+            .await
+            .wrap_err("Unable to run \"remove_join_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_join_channel(&self, join_channel: DatabaseJoinChannel) -> Result<()> {
+        let statement = "
+            UPDATE
+                join_channel
+            SET
+                name_template = ?2,
+                user_limit = ?3,
+                privacy = ?4,
+                bitrate = ?5,
+                template_name = ?6
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    join_channel.id.get() as i64,
+                    join_channel.name_template,
+                    join_channel.user_limit,
+                    join_channel.privacy,
+                    join_channel.bitrate,
+                    join_channel.template_name
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_join_channel\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_join_channel_category(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        category_channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                join_channel
+            SET
+                category_channel_id = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![channel_id.get() as i64, category_channel_id.get() as i64],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_join_channel_category\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_join_channel_template(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        template_name: Option<String>,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                join_channel
+            SET
+                template_name = ?2
+            WHERE
+                id = ?1;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(statement, params![channel_id.get() as i64, template_name])
+        })
+        .await
+        .wrap_err("Unable to run \"update_join_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn channel_template(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: String,
+    ) -> Result<Option<DatabaseChannelTemplate>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                channel_template
+            WHERE
+                guild_id = ?1
+                AND name = ?2;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map(params![guild_id.get() as i64, name], |row| {
+                    DatabaseChannelTemplate::try_from(row)
+                })?
+                .next()
+                .transpose()
+        })
+        .await
+        .wrap_err("Unable to run \"channel_template\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn guild_channel_templates(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DatabaseChannelTemplate>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                channel_template
+            WHERE
+                guild_id = ?1
+            ORDER BY
+                name;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map(params![guild_id.get() as i64], |row| {
+                    DatabaseChannelTemplate::try_from(row)
+                })?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"guild_channel_templates\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                channel_template (
+                    guild_id,
+                    name,
+                    user_limit,
+                    privacy,
+                    bitrate,
+                    permission_overwrites
+                )
+            VALUES
+                (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT
+            DO NOTHING;
+        ";
+        let permission_overwrites = serde_json::to_string(&channel_template.permission_overwrites)?;
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    channel_template.guild_id.get() as i64,
+                    channel_template.name,
+                    channel_template.user_limit,
+                    channel_template.privacy,
+                    channel_template.bitrate,
+                    permission_overwrites
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"insert_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_channel_template(
+        &self,
+        channel_template: DatabaseChannelTemplate,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                channel_template
+            SET
+                user_limit = ?3,
+                privacy = ?4,
+                bitrate = ?5,
+                permission_overwrites = ?6
+            WHERE
+                guild_id = ?1
+                AND name = ?2;
+        ";
+        let permission_overwrites = serde_json::to_string(&channel_template.permission_overwrites)?;
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    channel_template.guild_id.get() as i64,
+                    channel_template.name,
+                    channel_template.user_limit,
+                    channel_template.privacy,
+                    channel_template.bitrate,
+                    permission_overwrites
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_channel_template(&self, guild_id: Id<GuildMarker>, name: String) -> Result<()> {
+        let statements = [
+            "
+            UPDATE
+                join_channel
+            SET
+                template_name = NULL
+            WHERE
+                guild_id = ?1
+                AND template_name = ?2;
+            ",
+            "
+            DELETE FROM
+                channel_template
+            WHERE
+                guild_id = ?1
+                AND name = ?2;
+            ",
+        ];
+
+        self.run(move |connection| {
+            let transaction = connection.transaction()?;
+
+            for statement in statements {
+                transaction.execute(statement, params![guild_id.get() as i64, name])?;
+            }
+
+            transaction.commit()
+        })
+        .await
+        .wrap_err("Unable to run \"remove_channel_template\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn insert_voice_channel_creation(
+        &self,
+        voice_channel_creation: DatabaseVoiceChannelCreation,
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                pending_voice_channel (guild_id, owner_id, name, parent_id, id)
+            VALUES
+                (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (guild_id, owner_id)
+            DO UPDATE SET
+                name = excluded.name,
+                parent_id = excluded.parent_id,
+                id = excluded.id,
+                started_at = unixepoch();
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    voice_channel_creation.guild_id.get() as i64,
+                    voice_channel_creation.owner_id.get() as i64,
+                    voice_channel_creation.name,
+                    voice_channel_creation.parent_id.get() as i64,
+                    voice_channel_creation.id.map(|id| id.get() as i64),
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"insert_voice_channel_creation\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn update_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+        id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let statement = "
+            UPDATE
+                pending_voice_channel
+            SET
+                id = ?3
+            WHERE
+                guild_id = ?1
+                AND owner_id = ?2;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![
+                    guild_id.get() as i64,
+                    owner_id.get() as i64,
+                    id.get() as i64
+                ],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"update_voice_channel_creation\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn remove_voice_channel_creation(
+        &self,
+        guild_id: Id<GuildMarker>,
+        owner_id: Id<UserMarker>,
+    ) -> Result<()> {
+        let statement = "
+            DELETE FROM
+                pending_voice_channel
+            WHERE
+                guild_id = ?1
+                AND owner_id = ?2;
+        ";
+
+        self.run(move |connection| {
+            connection.execute(
+                statement,
+                params![guild_id.get() as i64, owner_id.get() as i64],
+            )
+        })
+        .await
+        .wrap_err("Unable to run \"remove_voice_channel_creation\" endpoint")?;
+
+        Ok(())
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn voice_channel_creations(&self) -> Result<Vec<DatabaseVoiceChannelCreation>> {
+        let statement = "
+            SELECT
+                *
+            FROM
+                pending_voice_channel;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_map([], |row| DatabaseVoiceChannelCreation::try_from(row))?
+                .collect()
+        })
+        .await
+        .wrap_err("Unable to run \"voice_channel_creations\" endpoint")
+    }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn stats(&self) -> Result<DatabaseStats> {
+        let statement = "
+            SELECT
+                (SELECT COUNT(*) FROM category_channel) AS category_channels,
+                (SELECT COUNT(*) FROM channel_template) AS channel_templates,
+                (SELECT COUNT(*) FROM guild) AS guilds,
+                (SELECT COUNT(*) FROM join_channel) AS join_channels,
+                (SELECT COUNT(*) FROM shard_session) AS shard_sessions,
+                (SELECT COUNT(*) FROM pending_voice_channel) AS voice_channel_creations,
+                (SELECT COUNT(*) FROM voice_channel) AS voice_channels;
+        ";
+
+        self.run(move |connection| {
+            connection
+                .prepare_cached(statement)?
+                .query_row([], |row| DatabaseStats::try_from(row))
+        })
+        .await
+        .wrap_err("Unable to run \"stats\" endpoint")
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseCategoryChannel {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            guild_id: Id::new(row.get::<_, i64>("guild_id")? as u64),
+            id: Id::new(row.get::<_, i64>("id")? as u64),
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseChannelTemplate {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            bitrate: row.get::<_, Option<u32>>("bitrate")?,
+            guild_id: Id::new(row.get::<_, i64>("guild_id")? as u64),
+            name: row.get::<_, String>("name")?,
+            permission_overwrites: serde_json::from_str(
+                &row.get::<_, String>("permission_overwrites")?,
+            )
+            .unwrap_or_default(),
+            privacy: row.get::<_, Option<String>>("privacy")?,
+            user_limit: row.get::<_, Option<u16>>("user_limit")?,
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseGuild {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            appearance: GuildAppearance {
+                color: row.get::<_, Option<u32>>("appearance_color")?,
+                description: row.get::<_, Option<String>>("appearance_description")?,
+                footer: row.get::<_, Option<String>>("appearance_footer")?,
+                thumbnail_url: row.get::<_, Option<String>>("appearance_thumbnail_url")?,
+                title: row.get::<_, Option<String>>("appearance_title")?,
+            },
+            id: Id::new(row.get::<_, i64>("id")? as u64),
+            locale: row.get::<_, Option<String>>("locale")?,
+            permanence: row.get::<_, bool>("permanence")?,
+            privacy: row.get::<_, String>("privacy")?,
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseJoinChannel {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            bitrate: row.get::<_, Option<u32>>("bitrate")?,
+            category_channel_id: Id::new(row.get::<_, i64>("category_channel_id")? as u64),
+            guild_id: Id::new(row.get::<_, i64>("guild_id")? as u64),
+            id: Id::new(row.get::<_, i64>("id")? as u64),
+            name_template: row.get::<_, Option<String>>("name_template")?,
+            privacy: row.get::<_, Option<String>>("privacy")?,
+            template_name: row.get::<_, Option<String>>("template_name")?,
+            user_limit: row.get::<_, Option<u16>>("user_limit")?,
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseShardSession {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            cache: row.get::<_, String>("cache")?,
+            id: row.get::<_, u32>("id")?,
+            resume_url: row.get::<_, Option<String>>("resume_url")?,
+            sequence: row.get::<_, i64>("sequence")? as u64,
+            session_id: row.get::<_, String>("session_id")?,
+            total: row.get::<_, u32>("total")?,
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseStats {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            category_channels: row.get::<_, i64>("category_channels")? as u64,
+            channel_templates: row.get::<_, i64>("channel_templates")? as u64,
+            guilds: row.get::<_, i64>("guilds")? as u64,
+            join_channels: row.get::<_, i64>("join_channels")? as u64,
+            shard_sessions: row.get::<_, i64>("shard_sessions")? as u64,
+            voice_channel_creations: row.get::<_, i64>("voice_channel_creations")? as u64,
+            voice_channels: row.get::<_, i64>("voice_channels")? as u64,
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseVoiceChannel {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Id::new(row.get::<_, i64>("id")? as u64),
+            guild_id: Id::new(row.get::<_, i64>("guild_id")? as u64),
+            parent_id: Id::new(row.get::<_, i64>("parent_id")? as u64),
+            owner_id: row
+                .get::<_, Option<i64>>("owner_id")?
+                .map(|id| Id::new(id as u64)),
+            panel_message_id: row
+                .get::<_, Option<i64>>("panel_message_id")?
+                .map(|id| Id::new(id as u64)),
+        })
+    }
+}
+
+impl TryFrom<&Row<'_>> for DatabaseVoiceChannelCreation {
+    type Error = SqliteError;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            guild_id: Id::new(row.get::<_, i64>("guild_id")? as u64),
+            id: row
+                .get::<_, Option<i64>>("id")?
+                .map(|id| Id::new(id as u64)),
+            name: row.get("name")?,
+            owner_id: Id::new(row.get::<_, i64>("owner_id")? as u64),
+            parent_id: Id::new(row.get::<_, i64>("parent_id")? as u64),
+        })
+    }
+}
@@ -0,0 +1,163 @@
+use std::{path::Path, sync::Arc};
+
+use eyre::{Context, Result};
+use rustls::{
+    client::{
+        danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+        WebPkiServerVerifier,
+    },
+    crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider},
+    pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer, ServerName, UnixTime},
+    CertificateError, ClientConfig, DigitallySignedStruct, Error as TlsError, RootCertStore,
+    SignatureScheme,
+};
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+use super::postgres::{ConnectOptions, SslMode};
+
+#[derive(Debug)]
+struct SkipHostnameVerifier(Arc<WebPkiServerVerifier>);
+
+#[derive(Debug)]
+struct SkipVerifier(Arc<CryptoProvider>);
+
+pub fn connector(options: &ConnectOptions) -> Result<MakeRustlsConnect> {
+    let provider = Arc::new(ring::default_provider());
+    let mut root_certificates = RootCertStore::empty();
+
+    match &options.ssl_root_cert {
+        Some(path) => {
+            for certificate in read_certificates(path)? {
+                root_certificates
+                    .add(certificate)
+                    .wrap_err_with(|| format!("Unable to use {} as a CA.", path.display()))?;
+            }
+        }
+        None => root_certificates.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned()),
+    }
+
+    let webpki_verifier = WebPkiServerVerifier::builder_with_provider(
+        Arc::new(root_certificates),
+        Arc::clone(&provider),
+    )
+    .build()?;
+
+    // Like libpq, "require" only verifies the server when a CA is provided.
+    let verifier: Arc<dyn ServerCertVerifier> = match options.ssl_mode {
+        SslMode::VerifyFull => webpki_verifier,
+        SslMode::VerifyCa => Arc::new(SkipHostnameVerifier(webpki_verifier)),
+        _ if options.ssl_root_cert.is_some() => Arc::new(SkipHostnameVerifier(webpki_verifier)),
+        _ => Arc::new(SkipVerifier(Arc::clone(&provider))),
+    };
+    let builder = ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()?
+        .dangerous()
+        .with_custom_certificate_verifier(verifier);
+    let config = match (&options.ssl_cert, &options.ssl_key) {
+        (Some(certificate_path), Some(key_path)) => {
+            let key = PrivateKeyDer::from_pem_file(key_path)
+                .wrap_err_with(|| format!("Unable to read {}.", key_path.display()))?;
+
+            builder
+                .with_client_auth_cert(read_certificates(certificate_path)?, key)
+                .wrap_err("Unable to use the client certificate.")?
+        }
+        _ => builder.with_no_client_auth(),
+    };
+
+    Ok(MakeRustlsConnect::new(config))
+}
+
+fn read_certificates(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    CertificateDer::pem_file_iter(path)
+        .and_then(|certificates| certificates.collect())
+        .wrap_err_with(|| format!("Unable to read {}.", path.display()))
+}
+
+impl ServerCertVerifier for SkipHostnameVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, TlsError> {
+        match self
+            .0
+            .verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)
+        {
+            Err(TlsError::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) => Ok(ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.0.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.0.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.supported_verify_schemes()
+    }
+}
+
+impl ServerCertVerifier for SkipVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, TlsError> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+#[cfg(feature = "postgres")]
+use deadpool_postgres::PoolError;
+use eyre::Report;
+use twilight_http::{response::DeserializeBodyError, Error as HttpError};
+
+use super::{database::ConstraintViolation, locale::Locale};
+
+#[derive(Debug)]
+pub enum Error {
+    Database(Report),
+    Http(Report),
+    MissingData(&'static str),
+    Panic(String),
+    Unknown(Report),
+}
+
+impl Error {
+    pub fn user_message(&self, locale: Locale) -> &'static str {
+        match self {
+            Self::Database(_) => locale.text("errors.database"),
+            Self::Http(_) => locale.text("errors.http"),
+            Self::MissingData(_) => locale.text("errors.missing_data"),
+            Self::Panic(_) | Self::Unknown(_) => locale.text("errors.unknown"),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Database(report) => write!(f, "database error: {report:#}"),
+            Self::Http(report) => write!(f, "http error: {report:#}"),
+            Self::MissingData(name) => write!(f, "missing data: {name}"),
+            Self::Panic(message) => write!(f, "panic: {message}"),
+            Self::Unknown(report) => write!(f, "{report:#}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Report> for Error {
+    fn from(report: Report) -> Self {
+        let is_database_error = report.chain().any(is_database_error);
+        let is_http_error = report
+            .chain()
+            .any(|cause| cause.is::<HttpError>() || cause.is::<DeserializeBodyError>());
+
+        if is_database_error {
+            Self::Database(report)
+        } else if is_http_error {
+            Self::Http(report)
+        } else {
+            report.downcast::<Error>().unwrap_or_else(Self::Unknown)
+        }
+    }
+}
+
+fn is_database_error(cause: &(dyn std::error::Error + 'static)) -> bool {
+    #[cfg(feature = "postgres")]
+    if cause.is::<PoolError>() || cause.is::<tokio_postgres::Error>() {
+        return true;
+    }
+
+    #[cfg(feature = "sqlite")]
+    if cause.is::<rusqlite::Error>() {
+        return true;
+    }
+
+    cause.is::<ConstraintViolation>()
+}
@@ -0,0 +1,147 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use parking_lot::Mutex;
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    time::timeout,
+};
+use tracing::{debug, info_span, Instrument};
+use twilight_gateway::Event;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use super::context::Context;
+use crate::events::handle_event;
+
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+pub struct EventQueue {
+    closed: AtomicBool,
+    guild_queues: Mutex<HashMap<Id<GuildMarker>, Arc<GuildQueue>>>,
+}
+
+struct GuildQueue {
+    depth: Arc<AtomicUsize>,
+    sender: UnboundedSender<Job>,
+}
+
+pub struct EventQueueDepth {
+    pub guild_count: usize,
+    pub max_depth: usize,
+    pub total_depth: usize,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        Self {
+            closed: AtomicBool::new(false),
+            guild_queues: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+        self.guild_queues.lock().clear();
+    }
+
+    pub fn depth(&self) -> EventQueueDepth {
+        let guild_queues = self.guild_queues.lock();
+        let depths = guild_queues
+            .values()
+            .map(|guild_queue| guild_queue.depth.load(Ordering::Relaxed));
+
+        EventQueueDepth {
+            guild_count: guild_queues.len(),
+            max_depth: depths.clone().max().unwrap_or_default(),
+            total_depth: depths.sum(),
+        }
+    }
+
+    pub fn push(&self, context: Arc<Context>, event: Event) {
+        let guild_id = event.guild_id();
+        let job = handle_event(Arc::clone(&context), event);
+
+        self.push_job(context, guild_id, job);
+    }
+
+    // Runs `job` after everything already queued for the guild, so it never interleaves with
+    // that guild's events. Jobs without a guild run right away.
+    pub fn push_job(
+        &self,
+        context: Arc<Context>,
+        guild_id: Option<Id<GuildMarker>>,
+        job: impl Future<Output = ()> + Send + 'static,
+    ) {
+        if self.closed.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let Some(guild_id) = guild_id else {
+            context.tasks.spawn(job);
+
+            return;
+        };
+        let mut guild_queues = self.guild_queues.lock();
+        let guild_queue = guild_queues.entry(guild_id).or_insert_with(|| {
+            let (sender, receiver) = unbounded_channel();
+            let guild_queue = Arc::new(GuildQueue {
+                depth: Arc::new(AtomicUsize::new(0)),
+                sender,
+            });
+
+            let span = info_span!("guild", id = guild_id.get());
+
+            context.tasks.spawn(
+                run_guild_queue(
+                    Arc::clone(&context),
+                    guild_id,
+                    Arc::clone(&guild_queue.depth),
+                    receiver,
+                )
+                .instrument(span),
+            );
+
+            guild_queue
+        });
+
+        guild_queue.depth.fetch_add(1, Ordering::Relaxed);
+        _ = guild_queue.sender.send(Box::pin(job));
+    }
+}
+
+async fn run_guild_queue(
+    context: Arc<Context>,
+    guild_id: Id<GuildMarker>,
+    depth: Arc<AtomicUsize>,
+    mut receiver: UnboundedReceiver<Job>,
+) {
+    loop {
+        match timeout(IDLE_TIMEOUT, receiver.recv()).await {
+            Ok(Some(job)) => {
+                job.await;
+                depth.fetch_sub(1, Ordering::Relaxed);
+            }
+            Ok(None) => break,
+            Err(_elapsed) => {
+                let mut guild_queues = context.event_queue.guild_queues.lock();
+
+                if receiver.is_empty() {
+                    guild_queues.remove(&guild_id);
+                    debug!("Closed idle event queue");
+
+                    break;
+                }
+            }
+        }
+    }
+}
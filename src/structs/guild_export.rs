@@ -0,0 +1,325 @@
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+use twilight_model::{
+    channel::permission_overwrite::PermissionOverwrite as ChannelPermissionOverwrite,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use super::{
+    cache::GuildAppearance,
+    config::{
+        LimitsConfig, MAX_CATEGORY_CHANNELS, MAX_CHANNEL_TEMPLATES, MAX_JOIN_CHANNELS,
+        PRIVACY_OPTIONS,
+    },
+    locale::Locale,
+};
+
+pub const GUILD_EXPORT_VERSION: u32 = 1;
+
+const PENDING_IMPORT_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct GuildExport {
+    pub version: u32,
+    pub permanence: bool,
+    pub privacy: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default_appearance")]
+    pub appearance: GuildAppearance,
+    #[serde(default)]
+    pub categories: Vec<ExportedCategoryChannel>,
+    #[serde(default)]
+    pub templates: Vec<ExportedChannelTemplate>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ExportedCategoryChannel {
+    pub id: Id<ChannelMarker>,
+    pub name: String,
+    #[serde(default)]
+    pub join_channels: Vec<ExportedJoinChannel>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ExportedJoinChannel {
+    pub id: Id<ChannelMarker>,
+    pub name: String,
+    #[serde(default)]
+    pub bitrate: Option<u32>,
+    #[serde(default)]
+    pub name_template: Option<String>,
+    #[serde(default)]
+    pub privacy: Option<String>,
+    #[serde(default)]
+    pub template_name: Option<String>,
+    #[serde(default)]
+    pub user_limit: Option<u16>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ExportedChannelTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub bitrate: Option<u32>,
+    #[serde(default)]
+    pub permission_overwrites: Vec<ChannelPermissionOverwrite>,
+    #[serde(default)]
+    pub privacy: Option<String>,
+    #[serde(default)]
+    pub user_limit: Option<u16>,
+}
+
+pub struct PendingImport {
+    created_at: Instant,
+    pub guild_export: GuildExport,
+    pub guild_id: Id<GuildMarker>,
+}
+
+impl PendingImport {
+    pub fn new(guild_id: Id<GuildMarker>, guild_export: GuildExport) -> Self {
+        Self {
+            created_at: Instant::now(),
+            guild_export,
+            guild_id,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created_at.elapsed() > PENDING_IMPORT_LIFETIME
+    }
+}
+
+impl GuildExport {
+    pub fn parse(bytes: &[u8], limits: &LimitsConfig, locale: Locale) -> Result<Self> {
+        let version = serde_json::from_slice::<serde_json::Value>(bytes)
+            .map_err(|source| {
+                eyre!(locale.format("settings.import.errors.invalid_json", &[("error", &source)]))
+            })?
+            .get("version")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| eyre!(locale.text("settings.import.errors.missing_version")))?;
+
+        if version != u64::from(GUILD_EXPORT_VERSION) {
+            return Err(eyre!(locale.format(
+                "settings.import.errors.unsupported_version",
+                &[
+                    ("supported_version", &GUILD_EXPORT_VERSION),
+                    ("version", &version),
+                ],
+            )));
+        }
+
+        let guild_export = serde_json::from_slice::<Self>(bytes).map_err(|source| {
+            eyre!(locale.format(
+                "settings.import.errors.invalid_format",
+                &[("error", &source)]
+            ))
+        })?;
+
+        guild_export.validate(limits, locale)?;
+
+        Ok(guild_export)
+    }
+
+    pub fn validate(&self, limits: &LimitsConfig, locale: Locale) -> Result<()> {
+        validate_privacy(&self.privacy, locale)?;
+
+        if let Some(code) = &self.locale {
+            if Locale::new(code).is_none() {
+                return Err(eyre!(locale.format(
+                    "settings.import.errors.invalid_locale",
+                    &[("locale", code)],
+                )));
+            }
+        }
+
+        validate_appearance(&self.appearance, locale)?;
+
+        if self.templates.len() > MAX_CHANNEL_TEMPLATES {
+            return Err(eyre!(
+                locale.text("settings.import.errors.too_many_templates")
+            ));
+        }
+
+        let mut template_names = HashSet::new();
+
+        for template in &self.templates {
+            if template.name.is_empty() || template.name.chars().count() > 50 {
+                return Err(eyre!(
+                    locale.text("settings.import.errors.template_name_length")
+                ));
+            }
+
+            if !template_names.insert(template.name.as_str()) {
+                return Err(eyre!(locale.format(
+                    "settings.import.errors.duplicate_template",
+                    &[("name", &template.name)],
+                )));
+            }
+
+            validate_preset(
+                template.bitrate,
+                template.privacy.as_deref(),
+                template.user_limit,
+                limits,
+                locale,
+            )?;
+        }
+
+        if self.categories.len() > MAX_CATEGORY_CHANNELS {
+            return Err(eyre!(
+                locale.text("settings.import.errors.too_many_categories")
+            ));
+        }
+
+        let mut channel_ids = HashSet::new();
+
+        for category in &self.categories {
+            validate_channel_name(&category.name, locale)?;
+
+            if !channel_ids.insert(category.id) {
+                return Err(eyre!(locale.format(
+                    "settings.import.errors.duplicate_channel",
+                    &[("channel_id", &category.id)],
+                )));
+            }
+
+            if category.join_channels.len() > MAX_JOIN_CHANNELS {
+                return Err(eyre!(locale.format(
+                    "settings.import.errors.too_many_join_channels",
+                    &[("name", &category.name)],
+                )));
+            }
+
+            for join_channel in &category.join_channels {
+                validate_channel_name(&join_channel.name, locale)?;
+
+                if !channel_ids.insert(join_channel.id) {
+                    return Err(eyre!(locale.format(
+                        "settings.import.errors.duplicate_channel",
+                        &[("channel_id", &join_channel.id)],
+                    )));
+                }
+
+                if join_channel
+                    .name_template
+                    .as_ref()
+                    .is_some_and(|name_template| name_template.chars().count() > 100)
+                {
+                    return Err(eyre!(
+                        locale.text("settings.import.errors.name_template_length")
+                    ));
+                }
+
+                validate_preset(
+                    join_channel.bitrate,
+                    join_channel.privacy.as_deref(),
+                    join_channel.user_limit,
+                    limits,
+                    locale,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_default_appearance(appearance: &GuildAppearance) -> bool {
+    *appearance == GuildAppearance::default()
+}
+
+fn validate_appearance(appearance: &GuildAppearance, locale: Locale) -> Result<()> {
+    if appearance.color.is_some_and(|color| color > 0xFFFFFF) {
+        return Err(eyre!(locale.text("settings.import.errors.invalid_color")));
+    }
+
+    if appearance
+        .thumbnail_url
+        .as_ref()
+        .is_some_and(|thumbnail_url| {
+            !thumbnail_url.starts_with("https://") && !thumbnail_url.starts_with("http://")
+        })
+    {
+        return Err(eyre!(
+            locale.text("settings.import.errors.invalid_thumbnail")
+        ));
+    }
+
+    let is_too_long = |text: &Option<String>, max_length: usize| {
+        text.as_ref()
+            .is_some_and(|text| text.chars().count() > max_length)
+    };
+
+    if is_too_long(&appearance.title, 256)
+        || is_too_long(&appearance.description, 4096)
+        || is_too_long(&appearance.footer, 2048)
+    {
+        return Err(eyre!(
+            locale.text("settings.import.errors.appearance_length")
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_channel_name(name: &str, locale: Locale) -> Result<()> {
+    if name.is_empty() || name.chars().count() > 100 {
+        return Err(eyre!(
+            locale.text("settings.import.errors.channel_name_length")
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_privacy(privacy: &str, locale: Locale) -> Result<()> {
+    if !PRIVACY_OPTIONS.contains(&privacy) {
+        return Err(eyre!(locale.format(
+            "settings.import.errors.invalid_privacy",
+            &[("privacy", &privacy)],
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_preset(
+    bitrate: Option<u32>,
+    privacy: Option<&str>,
+    user_limit: Option<u16>,
+    limits: &LimitsConfig,
+    locale: Locale,
+) -> Result<()> {
+    // Presets store bits per second, while the limits are in kbps.
+    let min_bitrate = limits.min_bitrate * 1000;
+    let max_bitrate = limits.max_bitrate * 1000;
+
+    if bitrate.is_some_and(|bitrate| !(min_bitrate..=max_bitrate).contains(&bitrate)) {
+        return Err(eyre!(locale.format(
+            "settings.import.errors.invalid_bitrate",
+            &[("min_bitrate", &min_bitrate), ("max_bitrate", &max_bitrate)],
+        )));
+    }
+
+    if user_limit.is_some_and(|user_limit| !(1..=99).contains(&user_limit)) {
+        return Err(eyre!(
+            locale.text("settings.import.errors.invalid_user_limit")
+        ));
+    }
+
+    if let Some(privacy) = privacy {
+        validate_privacy(privacy, locale)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use parking_lot::RwLock;
+use twilight_gateway::ShardState;
+
+pub struct Health {
+    last_event_at: RwLock<Option<Instant>>,
+    shard_states: RwLock<BTreeMap<u32, ShardState>>,
+}
+
+impl Health {
+    pub fn last_event_age(&self) -> Option<Duration> {
+        self.last_event_at
+            .read()
+            .map(|last_event_at| last_event_at.elapsed())
+    }
+
+    pub fn new() -> Self {
+        Self {
+            last_event_at: RwLock::new(None),
+            shard_states: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn record_event(&self) {
+        *self.last_event_at.write() = Some(Instant::now());
+    }
+
+    pub fn shard_states(&self) -> BTreeMap<u32, ShardState> {
+        self.shard_states.read().clone()
+    }
+
+    pub fn update_shard_state(&self, shard_id: u32, state: ShardState) {
+        self.shard_states.write().insert(shard_id, state);
+    }
+}
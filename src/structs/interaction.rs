@@ -11,27 +11,45 @@ use twilight_model::{
     },
 };
 
-use super::cache::{CachedGuild, CachedVoiceChannel};
+use super::{
+    cache::{CachedGuild, CachedVoiceChannel},
+    locale::Locale,
+};
 
 pub struct ApplicationCommandInteraction {
+    pub color: u32,
     pub data: Box<CommandData>,
     pub guild: Arc<CachedGuild>,
     pub id: Id<InteractionMarker>,
+    pub locale: Locale,
+    pub token: String,
+}
+
+pub struct GuildMessageComponentInteraction {
+    pub color: u32,
+    pub data: Box<MessageComponentInteractionData>,
+    pub guild: Arc<CachedGuild>,
+    pub id: Id<InteractionMarker>,
+    pub locale: Locale,
     pub token: String,
 }
 
 #[derive(Clone)]
 pub struct MessageComponentInteraction {
+    pub color: u32,
     pub data: Box<MessageComponentInteractionData>,
     pub id: Id<InteractionMarker>,
+    pub locale: Locale,
     pub token: String,
     pub user_id: Id<UserMarker>,
     pub voice_channel: Arc<CachedVoiceChannel>,
 }
 
 pub struct ModalSubmitInteraction {
+    pub color: u32,
     pub data: ModalInteractionData,
     pub id: Id<InteractionMarker>,
+    pub locale: Locale,
     pub token: String,
     pub voice_channel: Arc<CachedVoiceChannel>,
 }
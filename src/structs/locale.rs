@@ -0,0 +1,186 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+    sync::LazyLock,
+};
+
+use toml::{Table, Value};
+use twilight_model::application::command::{Command, CommandOption, CommandOptionChoiceValue};
+
+const LOCALE_FILES: [(&str, &str); 2] = [
+    ("de", include_str!("../../locales/de.toml")),
+    ("en-US", include_str!("../../locales/en-US.toml")),
+];
+
+// Every catalog flattened into dotted keys, such as `settings.privacy.locked`.
+static CATALOGS: LazyLock<HashMap<&'static str, HashMap<String, String>>> = LazyLock::new(|| {
+    LOCALE_FILES
+        .into_iter()
+        .map(|(code, file)| {
+            let table = file
+                .parse::<Table>()
+                .unwrap_or_else(|source| panic!("locales/{code}.toml is invalid: {source}"));
+            let mut messages = HashMap::new();
+
+            flatten(&mut messages, "", table);
+
+            (code, messages)
+        })
+        .collect()
+});
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Locale(&'static str);
+
+impl Locale {
+    pub const DEFAULT: Self = Self("en-US");
+
+    // Discord locales match a file exactly or by language, so `en-GB` uses `en-US`.
+    pub fn new(code: &str) -> Option<Self> {
+        let language = code.split('-').next()?;
+
+        LOCALE_FILES
+            .iter()
+            .find(|(file_code, _)| *file_code == code)
+            .or_else(|| {
+                LOCALE_FILES
+                    .iter()
+                    .find(|(file_code, _)| file_code.split('-').next() == Some(language))
+            })
+            .map(|(file_code, _)| Self(file_code))
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        LOCALE_FILES.into_iter().map(|(code, _)| Self(code))
+    }
+
+    pub fn code(self) -> &'static str {
+        self.0
+    }
+
+    pub fn name(self) -> &'static str {
+        self.text("language")
+    }
+
+    pub fn get(self, key: &str) -> Option<&'static str> {
+        CATALOGS
+            .get(self.0)
+            .and_then(|messages| messages.get(key))
+            .map(String::as_str)
+    }
+
+    #[cfg(test)]
+    pub fn keys(self) -> impl Iterator<Item = &'static str> {
+        CATALOGS
+            .get(self.0)
+            .into_iter()
+            .flat_map(|messages| messages.keys().map(String::as_str))
+    }
+
+    pub fn text(self, key: &'static str) -> &'static str {
+        self.get(key)
+            .or_else(|| Self::DEFAULT.get(key))
+            .unwrap_or(key)
+    }
+
+    // Fills `{name}` placeholders in a single pass, so arguments containing braces stay as is.
+    pub fn format(self, key: &str, arguments: &[(&str, &dyn Display)]) -> String {
+        let mut rest = self
+            .get(key)
+            .or_else(|| Self::DEFAULT.get(key))
+            .unwrap_or(key);
+        let mut message = String::with_capacity(rest.len());
+
+        while let Some(start) = rest.find('{') {
+            message.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let argument = rest.find('}').and_then(|end| {
+                arguments
+                    .iter()
+                    .find(|(name, _)| *name == &rest[1..end])
+                    .map(|(_, value)| (end, value))
+            });
+
+            if let Some((end, value)) = argument {
+                let _ = write!(message, "{value}");
+
+                rest = &rest[end + 1..];
+            } else {
+                message.push('{');
+
+                rest = &rest[1..];
+            }
+        }
+
+        message.push_str(rest);
+
+        message
+    }
+}
+
+// Fills in the names and descriptions other locales give under `commands`, keyed by the path of
+// each command and option, such as `commands.settings.options.privacy.options.state`.
+pub fn localize_commands(commands: &mut [Command]) {
+    for command in commands {
+        let path = format!("commands.{}", command.name);
+
+        command.name_localizations = localizations(&format!("{path}.name"));
+        command.description_localizations = localizations(&format!("{path}.description"));
+        localize_options(&mut command.options, &path);
+    }
+}
+
+fn localize_options(options: &mut [CommandOption], parent_path: &str) {
+    for option in options {
+        let path = format!("{parent_path}.options.{}", option.name);
+
+        option.name_localizations = localizations(&format!("{path}.name"));
+        option.description_localizations = localizations(&format!("{path}.description"));
+
+        for choice in option.choices.iter_mut().flatten() {
+            let value = match &choice.value {
+                CommandOptionChoiceValue::Integer(value) => value.to_string(),
+                CommandOptionChoiceValue::Number(value) => value.to_string(),
+                CommandOptionChoiceValue::String(value) => value.clone(),
+            };
+
+            choice.name_localizations = localizations(&format!("{path}.choices.{value}"));
+        }
+
+        if let Some(options) = &mut option.options {
+            localize_options(options, &path);
+        }
+    }
+}
+
+fn localizations(key: &str) -> Option<HashMap<String, String>> {
+    let localizations = Locale::all()
+        .filter(|locale| *locale != Locale::DEFAULT)
+        .filter_map(|locale| {
+            locale
+                .get(key)
+                .map(|text| (locale.code().to_owned(), text.to_owned()))
+        })
+        .collect::<HashMap<String, String>>();
+
+    (!localizations.is_empty()).then_some(localizations)
+}
+
+fn flatten(messages: &mut HashMap<String, String>, prefix: &str, table: Table) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}.{key}")
+        };
+
+        match value {
+            Value::String(text) => {
+                messages.insert(key, text);
+            }
+            Value::Table(table) => flatten(messages, &key, table),
+            _ => {}
+        }
+    }
+}
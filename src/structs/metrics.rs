@@ -0,0 +1,111 @@
+use eyre::Result;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+
+pub struct Metrics {
+    pub cache_entries: IntGaugeVec,
+    pub cache_inconsistencies: IntCounterVec,
+    pub database_connections: IntGaugeVec,
+    pub event_queue_depth: IntGaugeVec,
+    pub gateway_events: IntCounterVec,
+    pub interaction_duration: HistogramVec,
+    pub panics: IntCounter,
+    registry: Registry,
+    pub rest_errors: IntCounterVec,
+    pub voice_channel_creation_duration: Histogram,
+    pub voice_channels_created: IntCounter,
+    pub voice_channels_deleted: IntCounter,
+}
+
+impl Metrics {
+    pub fn encode(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new_custom(Some("meloetta".to_owned()), None)?;
+        let cache_inconsistencies = IntCounterVec::new(
+            Opts::new(
+                "cache_inconsistencies_total",
+                "Cache inconsistencies found by kind",
+            ),
+            &["kind"],
+        )?;
+        let cache_entries = IntGaugeVec::new(
+            Opts::new("cache_entries", "Number of entries in each cache map"),
+            &["map"],
+        )?;
+        let database_connections = IntGaugeVec::new(
+            Opts::new("database_connections", "Database connection pool usage"),
+            &["state"],
+        )?;
+        let event_queue_depth = IntGaugeVec::new(
+            Opts::new(
+                "event_queue_depth",
+                "Number of events waiting in guild queues",
+            ),
+            &["aggregate"],
+        )?;
+        let gateway_events = IntCounterVec::new(
+            Opts::new("gateway_events_total", "Gateway events received by type"),
+            &["event_type"],
+        )?;
+        let interaction_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "interaction_duration_seconds",
+                "Time taken to handle interactions by custom ID",
+            ),
+            &["custom_id"],
+        )?;
+        let panics = IntCounter::new("panics_total", "Number of panics since startup")?;
+        let rest_errors = IntCounterVec::new(
+            Opts::new("rest_errors_total", "Failed Discord REST requests by route"),
+            &["route"],
+        )?;
+        let voice_channel_creation_duration = Histogram::with_opts(HistogramOpts::new(
+            "voice_channel_creation_duration_seconds",
+            "Time taken to create a voice channel for a member",
+        ))?;
+        let voice_channels_created = IntCounter::new(
+            "voice_channels_created_total",
+            "Number of voice channels created",
+        )?;
+        let voice_channels_deleted = IntCounter::new(
+            "voice_channels_deleted_total",
+            "Number of voice channels deleted",
+        )?;
+
+        registry.register(Box::new(cache_entries.clone()))?;
+        registry.register(Box::new(cache_inconsistencies.clone()))?;
+        registry.register(Box::new(database_connections.clone()))?;
+        registry.register(Box::new(event_queue_depth.clone()))?;
+        registry.register(Box::new(gateway_events.clone()))?;
+        registry.register(Box::new(interaction_duration.clone()))?;
+        registry.register(Box::new(panics.clone()))?;
+        registry.register(Box::new(rest_errors.clone()))?;
+        registry.register(Box::new(voice_channel_creation_duration.clone()))?;
+        registry.register(Box::new(voice_channels_created.clone()))?;
+        registry.register(Box::new(voice_channels_deleted.clone()))?;
+
+        Ok(Self {
+            cache_entries,
+            cache_inconsistencies,
+            database_connections,
+            event_queue_depth,
+            gateway_events,
+            interaction_duration,
+            panics,
+            registry,
+            rest_errors,
+            voice_channel_creation_duration,
+            voice_channels_created,
+            voice_channels_deleted,
+        })
+    }
+}
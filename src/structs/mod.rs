@@ -1,4 +1,12 @@
 pub(super) mod cache;
+pub(super) mod config;
 pub(super) mod context;
 pub(super) mod database;
+pub(super) mod error;
+pub(super) mod event_queue;
+pub(super) mod guild_export;
+pub(super) mod health;
 pub(super) mod interaction;
+pub(super) mod locale;
+pub(super) mod metrics;
+pub(super) mod saga;
@@ -0,0 +1,37 @@
+use std::future::Future;
+
+use eyre::Result;
+use futures::future::BoxFuture;
+use tracing::warn;
+
+pub struct Saga {
+    compensations: Vec<(&'static str, BoxFuture<'static, Result<()>>)>,
+}
+
+impl Saga {
+    pub fn new() -> Self {
+        Self {
+            compensations: Vec::new(),
+        }
+    }
+
+    pub fn compensate(
+        &mut self,
+        step: &'static str,
+        compensation: impl Future<Output = Result<()>> + Send + 'static,
+    ) {
+        self.compensations.push((step, Box::pin(compensation)));
+    }
+
+    // Compensations run newest first and stop at the first failure, so that
+    // earlier steps stay in place for recovery to find.
+    pub async fn roll_back(self) {
+        for (step, compensation) in self.compensations.into_iter().rev() {
+            if let Err(source) = compensation.await {
+                warn!(step, ?source, "Unable to compensate saga step");
+
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use axum::http::Method;
+use serde_json::json;
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::harness::{application_command, next_id, voice_state_update, Harness};
+
+#[tokio::test]
+async fn adopting_category_manages_its_voice_channels() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let category_channel_id: Id<ChannelMarker> = next_id();
+    let occupied_channel_id: Id<ChannelMarker> = next_id();
+    let empty_channel_id: Id<ChannelMarker> = next_id();
+    let occupant_id: Id<UserMarker> = next_id();
+    let chosen_owner_id: Id<UserMarker> = next_id();
+
+    harness.discord.insert_channel(
+        guild_id,
+        category_channel_id,
+        ChannelType::GuildCategory,
+        None,
+    );
+
+    for channel_id in [occupied_channel_id, empty_channel_id] {
+        harness.discord.insert_channel(
+            guild_id,
+            channel_id,
+            ChannelType::GuildVoice,
+            Some(category_channel_id),
+        );
+    }
+
+    harness
+        .dispatch([
+            voice_state_update(guild_id, Some(occupied_channel_id), occupant_id, "Alex"),
+            application_command(
+                guild_id,
+                next_id(),
+                "create",
+                json!([{
+                    "name": "adopt",
+                    "options": [
+                        { "name": "category", "type": 7, "value": category_channel_id },
+                        { "name": "owner", "type": 6, "value": chosen_owner_id },
+                    ],
+                    "type": 1,
+                }]),
+            ),
+        ])
+        .await;
+
+    let cache = &harness.context.cache;
+    let category_channel = cache.category_channel(category_channel_id).unwrap();
+
+    assert_eq!(category_channel.join_channel_ids.read().len(), 1);
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::POST, &format!("guilds/{guild_id}/channels"))
+            .len(),
+        1
+    );
+    assert_eq!(
+        *cache
+            .voice_channel(occupied_channel_id)
+            .unwrap()
+            .owner_id
+            .read(),
+        Some(occupant_id)
+    );
+    assert_eq!(
+        *cache
+            .voice_channel(empty_channel_id)
+            .unwrap()
+            .owner_id
+            .read(),
+        Some(chosen_owner_id)
+    );
+    assert!(cache
+        .voice_channel(occupied_channel_id)
+        .unwrap()
+        .connected_user_ids
+        .read()
+        .contains(&occupant_id));
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_voice_channels.len(), 2);
+    assert!(database_voice_channels
+        .iter()
+        .all(|voice_channel| voice_channel.panel_message_id.is_some()));
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::POST, "channels/*/messages")
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn adopting_category_with_join_channel_reuses_it() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let category_channel_id: Id<ChannelMarker> = next_id();
+    let join_channel_id: Id<ChannelMarker> = next_id();
+
+    harness.discord.insert_channel(
+        guild_id,
+        category_channel_id,
+        ChannelType::GuildCategory,
+        None,
+    );
+    harness.discord.insert_channel(
+        guild_id,
+        join_channel_id,
+        ChannelType::GuildVoice,
+        Some(category_channel_id),
+    );
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "create",
+            json!([{
+                "name": "adopt",
+                "options": [
+                    { "name": "category", "type": 7, "value": category_channel_id },
+                    { "name": "join-channel", "type": 7, "value": join_channel_id },
+                ],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    assert_eq!(
+        *harness
+            .context
+            .cache
+            .category_channel(category_channel_id)
+            .unwrap()
+            .join_channel_ids
+            .read(),
+        [join_channel_id].into()
+    );
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(join_channel_id)
+        .is_none());
+    assert!(harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"))
+        .is_empty());
+}
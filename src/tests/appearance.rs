@@ -0,0 +1,143 @@
+use axum::http::Method;
+use serde_json::{json, Value};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use super::harness::{application_command, next_id, voice_state_update, Harness};
+
+async fn settings_appearance(
+    harness: &Harness,
+    guild_id: Id<GuildMarker>,
+    options: Value,
+) -> Value {
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "settings",
+            json!([{ "name": "appearance", "options": options, "type": 1 }]),
+        )])
+        .await;
+
+    harness
+        .discord
+        .requests(Method::PATCH, "webhooks/*/*/messages/@original")
+        .pop()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn appearance_command_stores_branding_and_previews_panel() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let response = settings_appearance(
+        &harness,
+        guild_id,
+        json!([
+            { "name": "color", "type": 3, "value": "#5865F2" },
+            { "name": "title", "type": 3, "value": "Lounge" },
+            { "name": "thumbnail", "type": 3, "value": "https://example.com/logo.png" },
+            { "name": "footer", "type": 3, "value": "Be nice" },
+        ]),
+    )
+    .await;
+
+    assert_eq!(response["embeds"][0]["color"], 0x5865F2);
+    assert_eq!(
+        response["embeds"][0]["description"],
+        "I've updated the appearance! New panel messages will look like this:"
+    );
+    assert_eq!(response["embeds"][1]["color"], 0x5865F2);
+    assert_eq!(response["embeds"][1]["title"], "Lounge");
+    assert_eq!(
+        response["embeds"][1]["description"],
+        "Make the channel your own!"
+    );
+    assert_eq!(
+        response["embeds"][1]["thumbnail"]["url"],
+        "https://example.com/logo.png"
+    );
+    assert_eq!(response["embeds"][1]["footer"]["text"], "Be nice");
+
+    let appearance = harness
+        .context
+        .database
+        .guild(guild_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .appearance;
+
+    assert_eq!(appearance.color, Some(0x5865F2));
+    assert_eq!(appearance.title.as_deref(), Some("Lounge"));
+
+    let response = settings_appearance(
+        &harness,
+        guild_id,
+        json!([{ "name": "color", "type": 3, "value": "blue" }]),
+    )
+    .await;
+
+    assert_eq!(response["embeds"][0]["color"], 0x5865F2);
+    assert_eq!(
+        response["embeds"][0]["description"],
+        "\"blue\" is not a color. Use a hex code such as `#5865F2`."
+    );
+
+    let response = settings_appearance(
+        &harness,
+        guild_id,
+        json!([{ "name": "reset", "type": 5, "value": true }]),
+    )
+    .await;
+
+    assert_eq!(response["embeds"][0]["color"], 0xF8F8FF);
+    assert_eq!(response["embeds"][1]["title"], Value::Null);
+    assert_eq!(
+        harness
+            .context
+            .database
+            .guild(guild_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .appearance
+            .color,
+        None
+    );
+}
+
+#[tokio::test]
+async fn new_panel_messages_use_guild_branding() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    settings_appearance(
+        &harness,
+        guild_id,
+        json!([
+            { "name": "color", "type": 3, "value": "00ff7f" },
+            { "name": "description", "type": 3, "value": "Welcome to your channel." },
+        ]),
+    )
+    .await;
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            next_id(),
+            "Alex",
+        )])
+        .await;
+
+    let panel_messages = harness
+        .discord
+        .requests(Method::POST, "channels/*/messages");
+
+    assert_eq!(panel_messages.len(), 1);
+    assert_eq!(panel_messages[0]["embeds"][0]["color"], 0x00FF7F);
+    assert_eq!(
+        panel_messages[0]["embeds"][0]["description"],
+        "Welcome to your channel."
+    );
+}
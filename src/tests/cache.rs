@@ -0,0 +1,196 @@
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
+    Id,
+};
+
+use super::harness::next_id;
+use crate::structs::cache::Cache;
+
+fn cache_with_category_channel() -> (Cache, Id<GuildMarker>, Id<ChannelMarker>) {
+    let cache = Cache::new();
+    let guild_id = next_id();
+    let category_channel_id = next_id();
+
+    cache.insert_guild(
+        guild_id,
+        next_id::<RoleMarker>(),
+        false,
+        "unlocked".to_owned(),
+    );
+    cache.insert_category_channel(guild_id, category_channel_id, Vec::new(), []);
+
+    (cache, guild_id, category_channel_id)
+}
+
+#[test]
+fn removing_guild_removes_its_channels_and_indexes() {
+    let (cache, guild_id, category_channel_id) = cache_with_category_channel();
+    let owner_id: Id<UserMarker> = next_id();
+    let voice_channel_ids = [next_id(), next_id()];
+
+    for voice_channel_id in voice_channel_ids {
+        cache.insert_voice_channel(
+            [],
+            guild_id,
+            voice_channel_id,
+            Some(owner_id),
+            None,
+            category_channel_id,
+            Vec::new(),
+        );
+        cache.insert_voice_state(guild_id, voice_channel_id, next_id());
+    }
+
+    cache.remove_guild(guild_id);
+
+    let stats = cache.stats();
+
+    assert_eq!(stats.category_channels, 0);
+    assert_eq!(stats.voice_channels, 0);
+    assert_eq!(stats.voice_channel_owners, 0);
+    assert_eq!(stats.voice_states, 0);
+}
+
+#[test]
+fn removing_voice_channel_keeps_states_of_members_who_moved() {
+    let (cache, guild_id, category_channel_id) = cache_with_category_channel();
+    let user_id = next_id();
+    let voice_channel_ids: [Id<ChannelMarker>; 2] = [next_id(), next_id()];
+
+    for voice_channel_id in voice_channel_ids {
+        cache.insert_voice_channel(
+            [],
+            guild_id,
+            voice_channel_id,
+            None,
+            None,
+            category_channel_id,
+            Vec::new(),
+        );
+    }
+
+    cache.insert_voice_state(guild_id, voice_channel_ids[0], user_id);
+    cache.insert_voice_state(guild_id, voice_channel_ids[1], user_id);
+    cache.remove_voice_channels([voice_channel_ids[0]]);
+
+    assert_eq!(
+        cache
+            .voice_state(guild_id, user_id)
+            .map(|channel_id| *channel_id),
+        Some(voice_channel_ids[1])
+    );
+    assert_eq!(
+        *cache
+            .category_channel(category_channel_id)
+            .unwrap()
+            .voice_channel_ids
+            .read(),
+        [voice_channel_ids[1]].into()
+    );
+}
+
+#[test]
+fn guild_lookups_only_return_that_guilds_entries() {
+    let (cache, guild_id, category_channel_id) = cache_with_category_channel();
+    let (other_guild_id, other_category_channel_id) = (next_id(), next_id());
+    let owner_id = next_id();
+    let voice_channel_id = next_id();
+    let other_voice_channel_id = next_id();
+
+    cache.insert_guild(
+        other_guild_id,
+        next_id::<RoleMarker>(),
+        false,
+        "unlocked".to_owned(),
+    );
+    cache.insert_category_channel(other_guild_id, other_category_channel_id, Vec::new(), []);
+
+    for (guild_id, category_channel_id, voice_channel_id) in [
+        (guild_id, category_channel_id, voice_channel_id),
+        (
+            other_guild_id,
+            other_category_channel_id,
+            other_voice_channel_id,
+        ),
+    ] {
+        cache.insert_voice_channel(
+            [],
+            guild_id,
+            voice_channel_id,
+            Some(owner_id),
+            None,
+            category_channel_id,
+            Vec::new(),
+        );
+        cache.insert_voice_state(guild_id, voice_channel_id, owner_id);
+    }
+
+    assert_eq!(
+        cache
+            .guild_category_channels(guild_id)
+            .iter()
+            .map(|category_channel| category_channel.id)
+            .collect::<Vec<_>>(),
+        [category_channel_id]
+    );
+    assert_eq!(
+        cache
+            .guild_voice_channels(guild_id)
+            .iter()
+            .map(|voice_channel| voice_channel.id)
+            .collect::<Vec<_>>(),
+        [voice_channel_id]
+    );
+    assert_eq!(
+        cache.guild_voice_channel_owners(guild_id),
+        [(owner_id, voice_channel_id)]
+    );
+    assert_eq!(
+        cache.guild_voice_states(guild_id),
+        [(owner_id, voice_channel_id)]
+    );
+
+    cache.clear_guild_channels(guild_id);
+    cache.clear_guild_voice_states(guild_id);
+
+    assert!(cache.guild_voice_channels(guild_id).is_empty());
+    assert!(cache.guild_voice_states(guild_id).is_empty());
+    assert_eq!(cache.guild_voice_channels(other_guild_id).len(), 1);
+    assert_eq!(cache.guild_voice_states(other_guild_id).len(), 1);
+}
+
+#[test]
+fn reinserting_guild_keeps_its_indexes() {
+    let (cache, guild_id, category_channel_id) = cache_with_category_channel();
+    let user_id = next_id();
+    let voice_channel_id = next_id();
+
+    cache.insert_voice_channel(
+        [],
+        guild_id,
+        voice_channel_id,
+        Some(user_id),
+        None,
+        category_channel_id,
+        Vec::new(),
+    );
+    cache.insert_voice_state(guild_id, next_id(), user_id);
+    cache.insert_guild(
+        guild_id,
+        next_id::<RoleMarker>(),
+        false,
+        "unlocked".to_owned(),
+    );
+
+    assert_eq!(cache.guild_category_channels(guild_id).len(), 1);
+    assert_eq!(cache.guild_voice_channels(guild_id).len(), 1);
+    assert_eq!(cache.guild_voice_channel_owners(guild_id).len(), 1);
+    assert_eq!(cache.guild_voice_states(guild_id).len(), 1);
+
+    cache.remove_guild(guild_id);
+
+    let stats = cache.stats();
+
+    assert_eq!(stats.voice_channel_owners, 0);
+    assert_eq!(stats.voice_states, 0);
+}
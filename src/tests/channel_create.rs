@@ -0,0 +1,166 @@
+use axum::http::{Method, StatusCode};
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use super::harness::{channel_create, next_id, voice_state_update, Harness};
+
+#[tokio::test]
+async fn voice_channel_created_in_managed_category_is_managed_without_owner() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let voice_channel_id = next_id();
+
+    harness
+        .dispatch([channel_create(
+            guild_id,
+            voice_channel_id,
+            Some(category_channel_id),
+        )])
+        .await;
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+    let voice_channel = harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .unwrap();
+
+    assert_eq!(database_voice_channels.len(), 1);
+    assert_eq!(database_voice_channels[0].id, voice_channel_id);
+    assert_eq!(database_voice_channels[0].owner_id, None);
+    assert!(database_voice_channels[0].panel_message_id.is_some());
+    assert_eq!(*voice_channel.parent_id.read(), category_channel_id);
+    assert!(harness
+        .context
+        .cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .voice_channel_ids
+        .read()
+        .contains(&voice_channel_id));
+    assert_eq!(
+        harness
+            .discord
+            .requests(
+                Method::POST,
+                &format!("channels/{voice_channel_id}/messages")
+            )
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn voice_channel_created_outside_managed_categories_is_ignored() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let voice_channel_id = next_id();
+
+    harness.seed_category_channel(guild_id).await;
+    harness
+        .dispatch([channel_create(guild_id, voice_channel_id, Some(next_id()))])
+        .await;
+
+    assert!(harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .is_none());
+}
+
+#[tokio::test]
+async fn voice_channel_created_by_bot_keeps_its_owner() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let user_id = next_id();
+
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let voice_channel_id: Id<ChannelMarker> = *harness
+        .context
+        .cache
+        .voice_channel_owner(guild_id, user_id)
+        .unwrap();
+
+    harness
+        .dispatch([channel_create(
+            guild_id,
+            voice_channel_id,
+            Some(category_channel_id),
+        )])
+        .await;
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_voice_channels.len(), 1);
+    assert_eq!(database_voice_channels[0].owner_id, Some(user_id));
+    assert_eq!(
+        harness
+            .discord
+            .requests(
+                Method::POST,
+                &format!("channels/{voice_channel_id}/messages")
+            )
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn voice_channel_deleted_before_its_panel_is_ignored() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let voice_channel_id = next_id();
+
+    harness.discord.fail_next(
+        Method::POST,
+        &format!("channels/{voice_channel_id}/messages"),
+        StatusCode::NOT_FOUND,
+    );
+    harness
+        .dispatch([channel_create(
+            guild_id,
+            voice_channel_id,
+            Some(category_channel_id),
+        )])
+        .await;
+
+    assert!(harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .is_none());
+}
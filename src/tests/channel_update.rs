@@ -0,0 +1,247 @@
+use super::harness::{channel_update, next_id, Harness};
+
+#[tokio::test]
+async fn moving_voice_channel_between_categories_updates_its_parent() {
+    let harness = Harness::new().await;
+    let cache = &harness.context.cache;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, voice_channel_id) =
+        harness.seed_voice_channel(guild_id, next_id()).await;
+    let (other_category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .dispatch([channel_update(
+            guild_id,
+            voice_channel_id,
+            Some(other_category_channel_id),
+            3,
+        )])
+        .await;
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        database_voice_channels[0].parent_id,
+        other_category_channel_id
+    );
+    assert_eq!(
+        *cache
+            .voice_channel(voice_channel_id)
+            .unwrap()
+            .parent_id
+            .read(),
+        other_category_channel_id
+    );
+    assert!(cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .voice_channel_ids
+        .read()
+        .is_empty());
+    assert!(cache
+        .category_channel(other_category_channel_id)
+        .unwrap()
+        .voice_channel_ids
+        .read()
+        .contains(&voice_channel_id));
+}
+
+#[tokio::test]
+async fn reordering_voice_channel_keeps_it_managed() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, voice_channel_id) =
+        harness.seed_voice_channel(guild_id, next_id()).await;
+
+    harness
+        .dispatch([channel_update(
+            guild_id,
+            voice_channel_id,
+            Some(category_channel_id),
+            7,
+        )])
+        .await;
+
+    assert_eq!(
+        *harness
+            .context
+            .cache
+            .voice_channel(voice_channel_id)
+            .unwrap()
+            .parent_id
+            .read(),
+        category_channel_id
+    );
+}
+
+#[tokio::test]
+async fn reordering_join_channel_changes_nothing() {
+    let harness = Harness::new().await;
+    let cache = &harness.context.cache;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let request_count = harness.discord.request_count();
+
+    harness
+        .dispatch([channel_update(
+            guild_id,
+            join_channel_id,
+            Some(category_channel_id),
+            4,
+        )])
+        .await;
+
+    let database_join_channels = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_join_channels.len(), 1);
+    assert_eq!(
+        database_join_channels[0].category_channel_id,
+        category_channel_id
+    );
+    assert_eq!(
+        cache
+            .join_channel(join_channel_id)
+            .unwrap()
+            .category_channel_id,
+        category_channel_id
+    );
+    assert!(cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .join_channel_ids
+        .read()
+        .contains(&join_channel_id));
+    assert_eq!(harness.discord.request_count(), request_count);
+}
+
+#[tokio::test]
+async fn moving_voice_channel_out_of_managed_categories_stops_managing_it() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, voice_channel_id) =
+        harness.seed_voice_channel(guild_id, next_id()).await;
+
+    harness
+        .dispatch([channel_update(
+            guild_id,
+            voice_channel_id,
+            Some(next_id()),
+            0,
+        )])
+        .await;
+
+    assert!(harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .is_none());
+    assert!(harness
+        .context
+        .cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .voice_channel_ids
+        .read()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn moving_join_channel_between_categories_updates_its_category() {
+    let harness = Harness::new().await;
+    let cache = &harness.context.cache;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let (other_category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .dispatch([channel_update(
+            guild_id,
+            join_channel_id,
+            Some(other_category_channel_id),
+            1,
+        )])
+        .await;
+
+    let database_join_channel = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|join_channel| join_channel.id == join_channel_id)
+        .unwrap();
+
+    assert_eq!(
+        database_join_channel.category_channel_id,
+        other_category_channel_id
+    );
+    assert_eq!(
+        cache
+            .join_channel(join_channel_id)
+            .unwrap()
+            .category_channel_id,
+        other_category_channel_id
+    );
+    assert!(!cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .join_channel_ids
+        .read()
+        .contains(&join_channel_id));
+    assert!(cache
+        .category_channel(other_category_channel_id)
+        .unwrap()
+        .join_channel_ids
+        .read()
+        .contains(&join_channel_id));
+}
+
+#[tokio::test]
+async fn moving_join_channel_out_of_managed_categories_stops_managing_it() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .dispatch([channel_update(guild_id, join_channel_id, None, 0)])
+        .await;
+
+    assert!(harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .context
+        .cache
+        .join_channel(join_channel_id)
+        .is_none());
+    assert!(harness
+        .context
+        .cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .join_channel_ids
+        .read()
+        .is_empty());
+}
@@ -0,0 +1,84 @@
+use eyre::Result;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use super::harness::{next_id, Harness};
+use crate::{
+    cli::{prune::prune, Command},
+    structs::database::DatabaseJoinChannel,
+};
+
+fn parse(args: &[&str]) -> Result<Option<Command>> {
+    Command::parse(args.iter().map(|arg| (*arg).to_owned()))
+}
+
+#[test]
+fn parsing_arguments_selects_command() {
+    assert_eq!(parse(&[]).unwrap(), None);
+    assert_eq!(parse(&["migrate"]).unwrap(), Some(Command::Migrate));
+    assert_eq!(
+        parse(&["register-commands", "--guild", "1234"]).unwrap(),
+        Some(Command::RegisterCommands {
+            guild_id: Some(Id::new(1234))
+        })
+    );
+    assert_eq!(
+        parse(&["export", "--guild", "1234"]).unwrap(),
+        Some(Command::Export {
+            guild_id: Id::new(1234),
+            output: None,
+        })
+    );
+    assert!(parse(&["export"]).is_err());
+    assert!(parse(&["stats", "--guild", "1234"]).is_err());
+    assert!(parse(&["prune", "--guild", "server"]).is_err());
+}
+
+#[tokio::test]
+async fn pruning_removes_deleted_channels_and_left_servers() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let deleted_join_channel_id = next_id();
+    let left_guild_id: Id<GuildMarker> = next_id();
+
+    harness
+        .seed_join_channel(DatabaseJoinChannel::new(
+            deleted_join_channel_id,
+            guild_id,
+            category_channel_id,
+        ))
+        .await;
+    harness.discord.remove_channel(deleted_join_channel_id);
+    harness
+        .context
+        .database
+        .insert_guild(left_guild_id, false, "unlocked")
+        .await
+        .unwrap();
+
+    let summary = prune(&harness.context, Some(guild_id)).await.unwrap();
+
+    assert_eq!(summary.channel_ids, vec![deleted_join_channel_id]);
+    assert!(summary.guild_ids.is_empty());
+
+    let database_join_channels = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_join_channels.len(), 1);
+    assert_eq!(database_join_channels[0].id, join_channel_id);
+
+    let summary = prune(&harness.context, Some(left_guild_id)).await.unwrap();
+
+    assert_eq!(summary.guild_ids, vec![left_guild_id]);
+    assert!(harness
+        .context
+        .database
+        .guild(left_guild_id)
+        .await
+        .unwrap()
+        .is_none());
+}
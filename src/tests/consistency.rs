@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use super::harness::{next_id, voice_state_update, Harness};
+use crate::utilities::consistency::{check_guild, run_check, Inconsistency};
+
+#[tokio::test]
+async fn consistent_guild_has_no_inconsistencies() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, voice_channel_id) = harness.seed_voice_channel(guild_id, next_id()).await;
+
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(voice_channel_id),
+            next_id(),
+            "Sam",
+        )])
+        .await;
+
+    assert_eq!(
+        check_guild(&harness.context, guild_id).await.unwrap(),
+        Vec::new()
+    );
+}
+
+#[tokio::test]
+async fn guild_create_restores_voice_states_consistently() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let owner_id = next_id();
+    let member_id = next_id();
+    let (_, voice_channel_id) = harness.seed_voice_channel(guild_id, owner_id).await;
+
+    harness.context.cache.clear_guild_voice_states(guild_id);
+    harness
+        .dispatch([harness.discord.guild_create(
+            guild_id,
+            &[(owner_id, voice_channel_id), (member_id, voice_channel_id)],
+        )])
+        .await;
+
+    assert_eq!(
+        *harness
+            .context
+            .cache
+            .voice_channel(voice_channel_id)
+            .unwrap()
+            .connected_user_ids
+            .read(),
+        HashSet::from([owner_id, member_id])
+    );
+    assert_eq!(
+        check_guild(&harness.context, guild_id).await.unwrap(),
+        Vec::new()
+    );
+
+    run_check(&harness.context, guild_id, true).await.unwrap();
+
+    assert_eq!(
+        harness
+            .context
+            .cache
+            .voice_channel(voice_channel_id)
+            .unwrap()
+            .connected_user_ids
+            .read()
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn guild_create_moves_join_channels_to_their_new_category() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let (other_category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .discord
+        .set_parent(join_channel_id, other_category_channel_id);
+    harness
+        .dispatch([harness.discord.guild_create(guild_id, &[])])
+        .await;
+
+    let database_join_channel = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|join_channel| join_channel.id == join_channel_id)
+        .unwrap();
+
+    assert_eq!(
+        database_join_channel.category_channel_id,
+        other_category_channel_id
+    );
+    assert_eq!(
+        harness
+            .context
+            .cache
+            .join_channel(join_channel_id)
+            .unwrap()
+            .category_channel_id,
+        other_category_channel_id
+    );
+    assert!(!harness
+        .context
+        .cache
+        .category_channel(category_channel_id)
+        .unwrap()
+        .join_channel_ids
+        .read()
+        .contains(&join_channel_id));
+    assert_eq!(
+        check_guild(&harness.context, guild_id).await.unwrap(),
+        Vec::new()
+    );
+}
+
+#[tokio::test]
+async fn stale_owner_is_found_and_repaired() {
+    let harness = Harness::new().await;
+    let cache = &harness.context.cache;
+    let guild_id = harness.seed_guild().await;
+    let user_id = next_id();
+    let (_, voice_channel_id) = harness.seed_voice_channel(guild_id, user_id).await;
+
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(voice_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let voice_channel = cache.voice_channel(voice_channel_id).unwrap();
+
+    cache.insert_voice_channel(
+        [],
+        guild_id,
+        voice_channel_id,
+        None,
+        *voice_channel.panel_message_id.read(),
+        *voice_channel.parent_id.read(),
+        Vec::new(),
+    );
+
+    assert_eq!(
+        run_check(&harness.context, guild_id, true).await.unwrap(),
+        vec![
+            Inconsistency::MismatchedChannel(voice_channel_id, "owner"),
+            Inconsistency::MissingConnectedUser(voice_channel_id, user_id),
+            Inconsistency::StaleVoiceChannelOwner(voice_channel_id, user_id),
+        ]
+    );
+    assert_eq!(
+        *cache
+            .voice_channel(voice_channel_id)
+            .unwrap()
+            .owner_id
+            .read(),
+        Some(user_id)
+    );
+    assert_eq!(
+        cache
+            .voice_channel(voice_channel_id)
+            .unwrap()
+            .connected_user_ids
+            .read()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        vec![user_id]
+    );
+    assert!(check_guild(&harness.context, guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn deleted_channel_is_found_and_repaired() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let user_id = next_id();
+    let (_, voice_channel_id) = harness.seed_voice_channel(guild_id, user_id).await;
+
+    harness.discord.remove_channel(voice_channel_id);
+
+    assert_eq!(
+        run_check(&harness.context, guild_id, false).await.unwrap(),
+        vec![Inconsistency::DeletedChannel(voice_channel_id)]
+    );
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .is_some());
+
+    run_check(&harness.context, guild_id, true).await.unwrap();
+
+    assert!(harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .is_none());
+    assert!(harness
+        .context
+        .cache
+        .voice_channel_owner(guild_id, user_id)
+        .is_none());
+}
@@ -0,0 +1,98 @@
+use twilight_model::id::marker::ChannelMarker;
+
+use super::harness::{next_id, Harness};
+
+#[tokio::test]
+async fn removing_category_channel_removes_its_voice_channels() {
+    let harness = Harness::new().await;
+    let database = &harness.context.database;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+
+    database
+        .insert_voice_channel(next_id(), guild_id, category_channel_id, Some(next_id()))
+        .await
+        .unwrap();
+    database
+        .remove_category_channel(category_channel_id)
+        .await
+        .unwrap();
+
+    assert!(database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn removing_guild_removes_its_channels() {
+    let harness = Harness::new().await;
+    let database = &harness.context.database;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+
+    database
+        .insert_voice_channel(next_id(), guild_id, category_channel_id, Some(next_id()))
+        .await
+        .unwrap();
+    database.remove_guild(guild_id).await.unwrap();
+
+    assert!(database.guild(guild_id).await.unwrap().is_none());
+    assert!(database
+        .guild_category_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn inserting_voice_channel_requires_category_channel() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+
+    assert!(harness
+        .context
+        .database
+        .insert_voice_channel(next_id(), guild_id, next_id(), Some(next_id()))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn shard_sessions_keep_their_resume_url() {
+    let harness = Harness::new().await;
+    let database = &harness.context.database;
+    let shard_id = (next_id::<ChannelMarker>().get() % 1_000_000) as u32;
+
+    database
+        .insert_shard_session(
+            shard_id,
+            1,
+            "session",
+            42,
+            Some("wss://resume.discord.gg"),
+            "{}",
+        )
+        .await
+        .unwrap();
+
+    let shard_session = database
+        .take_shard_sessions()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|shard_session| shard_session.id == shard_id)
+        .unwrap();
+
+    assert_eq!(shard_session.sequence, 42);
+    assert_eq!(
+        shard_session.resume_url.as_deref(),
+        Some("wss://resume.discord.gg")
+    );
+}
@@ -0,0 +1,879 @@
+use std::{
+    collections::HashMap,
+    env,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, LazyLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{Method, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    Json, Router,
+};
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use twilight_gateway::Event;
+use twilight_http::Client;
+use twilight_model::{
+    application::interaction::Interaction,
+    channel::{Channel, ChannelType},
+    gateway::payload::incoming::{
+        ChannelCreate, ChannelDelete, ChannelUpdate, GuildCreate, InteractionCreate,
+        VoiceStateUpdate,
+    },
+    guild::Guild,
+    id::{
+        marker::{
+            ChannelMarker, CommandMarker, CommandVersionMarker, GuildMarker, RoleMarker, UserMarker,
+        },
+        Id,
+    },
+    voice::VoiceState,
+};
+
+use crate::{
+    events::handle_event,
+    structs::{
+        config::{Config, DatabaseConfig, DiscordConfig},
+        context::Context,
+        database::DatabaseJoinChannel,
+    },
+};
+
+const TIMESTAMP: &str = "2024-01-01T00:00:00.000000+00:00";
+
+static NEXT_ID: LazyLock<AtomicU64> = LazyLock::new(|| {
+    let micros = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_micros() as u64;
+
+    AtomicU64::new(micros << 8)
+});
+
+pub fn next_id<T>() -> Id<T> {
+    Id::new(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+}
+
+pub struct InjectedFailure {
+    pub handled: bool,
+    pub method: Method,
+    pub path: String,
+    pub status: StatusCode,
+}
+
+pub struct RecordedRequest {
+    pub body: Value,
+    pub method: Method,
+    pub path: String,
+}
+
+#[derive(Clone)]
+pub struct FakeDiscord {
+    address: SocketAddr,
+    attachments: Arc<Mutex<Vec<Vec<u8>>>>,
+    bot_user_id: Id<UserMarker>,
+    channels: Arc<Mutex<Vec<Value>>>,
+    commands: Arc<Mutex<HashMap<String, Value>>>,
+    failures: Arc<Mutex<Vec<InjectedFailure>>>,
+    guild_ids: Arc<Mutex<Vec<Id<GuildMarker>>>>,
+    messages: Arc<Mutex<Vec<Value>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl FakeDiscord {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let discord = Self {
+            address: listener.local_addr().unwrap(),
+            attachments: Arc::new(Mutex::new(Vec::new())),
+            bot_user_id: next_id(),
+            channels: Arc::new(Mutex::new(Vec::new())),
+            commands: Arc::new(Mutex::new(HashMap::new())),
+            failures: Arc::new(Mutex::new(Vec::new())),
+            guild_ids: Arc::new(Mutex::new(Vec::new())),
+            messages: Arc::new(Mutex::new(Vec::new())),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        let router = Router::new()
+            .fallback(handle_request)
+            .with_state(discord.clone());
+
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        discord
+    }
+
+    pub fn insert_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        kind: ChannelType,
+        parent_id: Option<Id<ChannelMarker>>,
+    ) {
+        self.channels.lock().push(json!({
+            "guild_id": guild_id,
+            "id": channel_id,
+            "parent_id": parent_id,
+            "type": kind,
+        }));
+    }
+
+    pub fn insert_guild(&self, guild_id: Id<GuildMarker>) {
+        self.guild_ids.lock().push(guild_id);
+    }
+
+    // Serves `file` like Discord's CDN and returns its URL.
+    pub fn insert_attachment(&self, file: Vec<u8>) -> String {
+        let mut attachments = self.attachments.lock();
+
+        attachments.push(file);
+
+        format!(
+            "http://{}/api/v10/attachments/{}",
+            self.address,
+            attachments.len() - 1
+        )
+    }
+
+    pub fn set_permission_overwrites(&self, channel_id: Id<ChannelMarker>, overwrites: Value) {
+        if let Some(channel) = self
+            .channels
+            .lock()
+            .iter_mut()
+            .find(|channel| channel["id"] == json!(channel_id))
+        {
+            channel["permission_overwrites"] = overwrites;
+        }
+    }
+
+    pub fn set_name(&self, channel_id: Id<ChannelMarker>, name: &str) {
+        if let Some(channel) = self
+            .channels
+            .lock()
+            .iter_mut()
+            .find(|channel| channel["id"] == json!(channel_id))
+        {
+            channel["name"] = json!(name);
+        }
+    }
+
+    pub fn set_parent(&self, channel_id: Id<ChannelMarker>, parent_id: Id<ChannelMarker>) {
+        if let Some(channel) = self
+            .channels
+            .lock()
+            .iter_mut()
+            .find(|channel| channel["id"] == json!(channel_id))
+        {
+            channel["parent_id"] = json!(parent_id);
+        }
+    }
+
+    // Replaces the commands registered at `path`, such as `applications/{id}/commands`.
+    pub fn set_commands(&self, path: &str, commands: &[Value]) {
+        let application_id = path.split('/').nth(1).unwrap_or_default();
+        let commands = commands
+            .iter()
+            .map(|command| registered_command(application_id, command))
+            .collect::<Vec<_>>();
+
+        self.commands
+            .lock()
+            .insert(path.to_owned(), json!(commands));
+    }
+
+    pub fn remove_channel(&self, channel_id: Id<ChannelMarker>) {
+        self.channels
+            .lock()
+            .retain(|channel| channel["id"] != json!(channel_id));
+    }
+
+    // Fails the next request matching `path`, where `*` matches any segment.
+    pub fn fail_next(&self, method: Method, path: &str, status: StatusCode) {
+        self.failures.lock().push(InjectedFailure {
+            handled: false,
+            method,
+            path: path.to_owned(),
+            status,
+        });
+    }
+
+    // Handles the next request matching `path` but answers with `status`, as if the response was lost.
+    pub fn fail_next_after_handling(&self, method: Method, path: &str, status: StatusCode) {
+        self.failures.lock().push(InjectedFailure {
+            handled: true,
+            method,
+            path: path.to_owned(),
+            status,
+        });
+    }
+
+    pub fn requests(&self, method: Method, path: &str) -> Vec<Value> {
+        self.requests
+            .lock()
+            .iter()
+            .filter(|request| {
+                request.method == method
+                    && path_matches(path, &request.path.split('/').collect::<Vec<_>>())
+            })
+            .map(|request| request.body.clone())
+            .collect()
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.requests.lock().len()
+    }
+}
+
+async fn handle_request(
+    State(discord): State<FakeDiscord>,
+    method: Method,
+    uri: Uri,
+    body: Bytes,
+) -> Response {
+    let path = uri
+        .path()
+        .strip_prefix("/api/v10/")
+        .unwrap_or(uri.path())
+        .to_owned();
+    let body = serde_json::from_slice::<Value>(&body).unwrap_or(Value::Null);
+    let segments = path.split('/').collect::<Vec<_>>();
+    let failure = {
+        let mut failures = discord.failures.lock();
+
+        failures
+            .iter()
+            .position(|failure| failure.method == method && path_matches(&failure.path, &segments))
+            .map(|index| failures.remove(index))
+    };
+
+    if let Some(failure) = failure.as_ref().filter(|failure| !failure.handled) {
+        discord
+            .requests
+            .lock()
+            .push(RecordedRequest { body, method, path });
+
+        return (
+            failure.status,
+            Json(json!({ "code": 0, "message": "Injected failure" })),
+        )
+            .into_response();
+    }
+
+    let mut response = match (&method, segments.as_slice()) {
+        (&Method::GET, ["guilds", guild_id, "channels"]) => {
+            let channels = discord
+                .channels
+                .lock()
+                .iter()
+                .filter(|channel| channel["guild_id"] == json!(guild_id))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            Json(channels).into_response()
+        }
+        (&Method::GET, ["users", "@me", "guilds"]) => {
+            let guilds = discord
+                .guild_ids
+                .lock()
+                .iter()
+                .map(|guild_id| {
+                    json!({
+                        "features": [],
+                        "icon": null,
+                        "id": guild_id,
+                        "name": "Server",
+                        "owner": false,
+                        "permissions": "8",
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            Json(guilds).into_response()
+        }
+        (
+            &Method::GET,
+            ["applications", _, "commands"] | ["applications", _, "guilds", _, "commands"],
+        ) => {
+            let commands = discord
+                .commands
+                .lock()
+                .get(&path)
+                .cloned()
+                .unwrap_or_else(|| json!([]));
+
+            Json(commands).into_response()
+        }
+        (
+            &Method::PUT,
+            ["applications", _, "commands"] | ["applications", _, "guilds", _, "commands"],
+        ) => {
+            discord.set_commands(&path, body.as_array().map_or(&[], Vec::as_slice));
+
+            Json(discord.commands.lock()[&path].clone()).into_response()
+        }
+        (&Method::GET, ["attachments", index]) => {
+            match index
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| discord.attachments.lock().get(index).cloned())
+            {
+                Some(file) => file.into_response(),
+                None => StatusCode::NOT_FOUND.into_response(),
+            }
+        }
+        (&Method::GET, ["channels", channel_id]) => {
+            let channel = discord
+                .channels
+                .lock()
+                .iter()
+                .find(|channel| channel["id"] == json!(channel_id))
+                .cloned();
+
+            match channel {
+                Some(channel) => Json(channel).into_response(),
+                None => (
+                    StatusCode::NOT_FOUND,
+                    Json(json!({ "code": 10003, "message": "Unknown Channel" })),
+                )
+                    .into_response(),
+            }
+        }
+        (&Method::POST, ["guilds", guild_id, "channels"]) => {
+            let channel = json!({
+                "guild_id": guild_id,
+                "id": next_id::<ChannelMarker>(),
+                "name": body["name"],
+                "parent_id": body["parent_id"],
+                "permission_overwrites": body["permission_overwrites"],
+                "type": body["type"],
+            });
+
+            discord.channels.lock().push(channel.clone());
+
+            Json(channel).into_response()
+        }
+        (&Method::GET, ["channels", channel_id, "messages"]) => {
+            let messages = discord
+                .messages
+                .lock()
+                .iter()
+                .rev()
+                .filter(|message| message["channel_id"] == json!(channel_id))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            Json(messages).into_response()
+        }
+        (&Method::POST, ["channels", channel_id, "messages"]) => {
+            let message = message(discord.bot_user_id, channel_id, &body);
+
+            discord.messages.lock().push(message.clone());
+
+            Json(message).into_response()
+        }
+        (&Method::PATCH, ["webhooks", _, _, "messages", _]) => Json(message(
+            discord.bot_user_id,
+            &next_id::<ChannelMarker>().to_string(),
+            &body,
+        ))
+        .into_response(),
+        (&Method::DELETE, ["channels", channel_id]) => {
+            discord
+                .channels
+                .lock()
+                .retain(|channel| channel["id"] != json!(channel_id));
+
+            Json(json!({
+                "id": channel_id,
+                "type": 2,
+            }))
+            .into_response()
+        }
+        (&Method::PATCH, ["channels", channel_id]) => Json(json!({
+            "id": channel_id,
+            "type": 2,
+        }))
+        .into_response(),
+        (&Method::POST, ["interactions", _, _, "callback"])
+        | (&Method::PATCH, ["guilds", _, "members", _])
+        | (&Method::PUT | &Method::DELETE, ["channels", _, "permissions", _])
+        | (&Method::DELETE, ["channels", _, "messages", _])
+        | (&Method::DELETE, ["users", "@me", "guilds", _]) => {
+            StatusCode::NO_CONTENT.into_response()
+        }
+        _ => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "code": 0, "message": "404: Not Found" })),
+        )
+            .into_response(),
+    };
+
+    if let Some(failure) = failure {
+        response = (
+            failure.status,
+            Json(json!({ "code": 0, "message": "Injected failure" })),
+        )
+            .into_response();
+    }
+
+    discord
+        .requests
+        .lock()
+        .push(RecordedRequest { body, method, path });
+
+    response
+}
+
+fn path_matches(pattern: &str, segments: &[&str]) -> bool {
+    let pattern = pattern.split('/').collect::<Vec<_>>();
+
+    pattern.len() == segments.len()
+        && pattern
+            .iter()
+            .zip(segments)
+            .all(|(expected, segment)| *expected == "*" || expected == segment)
+}
+
+// Fills in the fields Discord adds to registered commands.
+fn registered_command(application_id: &str, command: &Value) -> Value {
+    let mut command = command.clone();
+
+    command["application_id"] = json!(application_id);
+    command["contexts"] = Value::Null;
+    command["dm_permission"] = json!(true);
+    command["id"] = json!(next_id::<CommandMarker>());
+    command["integration_types"] = json!([0]);
+    command["nsfw"] = json!(false);
+    command["version"] = json!(next_id::<CommandVersionMarker>());
+
+    command
+}
+
+fn message(author_id: Id<UserMarker>, channel_id: &str, body: &Value) -> Value {
+    json!({
+        "attachments": [],
+        "author": user(author_id, "meloetta"),
+        "channel_id": channel_id,
+        "components": body.get("components").cloned().unwrap_or(json!([])),
+        "content": body.get("content").cloned().unwrap_or(json!("")),
+        "edited_timestamp": null,
+        "embeds": body.get("embeds").cloned().unwrap_or(json!([])),
+        "id": next_id::<ChannelMarker>(),
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": TIMESTAMP,
+        "tts": false,
+        "type": 0,
+    })
+}
+
+fn user(user_id: Id<UserMarker>, name: &str) -> Value {
+    json!({
+        "accent_color": null,
+        "avatar": null,
+        "avatar_decoration": null,
+        "banner": null,
+        "discriminator": "0",
+        "id": user_id,
+        "username": name,
+    })
+}
+
+pub fn channel_create(
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    parent_id: Option<Id<ChannelMarker>>,
+) -> Event {
+    let channel = serde_json::from_value::<Channel>(json!({
+        "guild_id": guild_id,
+        "id": channel_id,
+        "parent_id": parent_id,
+        "permission_overwrites": [],
+        "position": 0,
+        "type": 2,
+    }))
+    .unwrap();
+
+    Event::ChannelCreate(Box::new(ChannelCreate(channel)))
+}
+
+pub fn channel_delete(guild_id: Id<GuildMarker>, channel_id: Id<ChannelMarker>) -> Event {
+    let channel = serde_json::from_value::<Channel>(json!({
+        "guild_id": guild_id,
+        "id": channel_id,
+        "type": 2,
+    }))
+    .unwrap();
+
+    Event::ChannelDelete(Box::new(ChannelDelete(channel)))
+}
+
+pub fn application_command(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    name: &str,
+    options: Value,
+) -> Event {
+    application_command_with_resolved(guild_id, user_id, name, options, Value::Null)
+}
+
+pub fn application_command_with_resolved(
+    guild_id: Id<GuildMarker>,
+    user_id: Id<UserMarker>,
+    name: &str,
+    options: Value,
+    resolved: Value,
+) -> Event {
+    let interaction = serde_json::from_value::<Interaction>(json!({
+        "application_id": next_id::<ChannelMarker>(),
+        "authorizing_integration_owners": {},
+        "data": {
+            "id": next_id::<ChannelMarker>(),
+            "name": name,
+            "options": options,
+            "resolved": resolved,
+            "type": 1,
+        },
+        "guild_id": guild_id,
+        "id": next_id::<ChannelMarker>(),
+        "locale": "en-US",
+        "member": {
+            "deaf": false,
+            "flags": 0,
+            "joined_at": TIMESTAMP,
+            "mute": false,
+            "permissions": "8",
+            "roles": [],
+            "user": user(user_id, "Admin"),
+        },
+        "token": "token",
+        "type": 2,
+        "version": 1,
+    }))
+    .unwrap();
+
+    Event::InteractionCreate(Box::new(InteractionCreate(interaction)))
+}
+
+pub fn with_locale(mut event: Event, locale: &str) -> Event {
+    if let Event::InteractionCreate(payload) = &mut event {
+        payload.0.locale = Some(locale.to_owned());
+    }
+
+    event
+}
+
+pub fn message_component(
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    user_id: Id<UserMarker>,
+    custom_id: &str,
+    values: &[&str],
+) -> Event {
+    let interaction = serde_json::from_value::<Interaction>(json!({
+        "application_id": next_id::<ChannelMarker>(),
+        "authorizing_integration_owners": {},
+        "channel": {
+            "id": channel_id,
+            "type": 2,
+        },
+        "data": {
+            "component_type": if values.is_empty() { 2 } else { 3 },
+            "custom_id": custom_id,
+            "values": values,
+        },
+        "guild_id": guild_id,
+        "id": next_id::<ChannelMarker>(),
+        "locale": "en-US",
+        "member": {
+            "deaf": false,
+            "flags": 0,
+            "joined_at": TIMESTAMP,
+            "mute": false,
+            "permissions": "8",
+            "roles": [],
+            "user": user(user_id, "Member"),
+        },
+        "token": "token",
+        "type": 3,
+        "version": 1,
+    }))
+    .unwrap();
+
+    Event::InteractionCreate(Box::new(InteractionCreate(interaction)))
+}
+
+pub fn channel_update(
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+    parent_id: Option<Id<ChannelMarker>>,
+    position: i32,
+) -> Event {
+    let channel = serde_json::from_value::<Channel>(json!({
+        "guild_id": guild_id,
+        "id": channel_id,
+        "parent_id": parent_id,
+        "permission_overwrites": [],
+        "position": position,
+        "type": 2,
+    }))
+    .unwrap();
+
+    Event::ChannelUpdate(Box::new(ChannelUpdate(channel)))
+}
+
+pub fn voice_state_update(
+    guild_id: Id<GuildMarker>,
+    channel_id: Option<Id<ChannelMarker>>,
+    user_id: Id<UserMarker>,
+    name: &str,
+) -> Event {
+    let voice_state = serde_json::from_value::<VoiceState>(json!({
+        "channel_id": channel_id,
+        "deaf": false,
+        "guild_id": guild_id,
+        "member": {
+            "deaf": false,
+            "flags": 0,
+            "joined_at": TIMESTAMP,
+            "mute": false,
+            "roles": [],
+            "user": user(user_id, name),
+        },
+        "mute": false,
+        "self_deaf": false,
+        "self_mute": false,
+        "self_stream": false,
+        "self_video": false,
+        "session_id": "session",
+        "suppress": false,
+        "user_id": user_id,
+    }))
+    .unwrap();
+
+    Event::VoiceStateUpdate(Box::new(VoiceStateUpdate(voice_state)))
+}
+
+impl FakeDiscord {
+    // Builds the GUILD_CREATE Discord sends for a guild, with its channels and `voice_states`.
+    pub fn guild_create(
+        &self,
+        guild_id: Id<GuildMarker>,
+        voice_states: &[(Id<UserMarker>, Id<ChannelMarker>)],
+    ) -> Event {
+        let channels = self
+            .channels
+            .lock()
+            .iter()
+            .filter(|channel| channel["guild_id"] == json!(guild_id))
+            .map(|channel| {
+                let mut channel = channel.clone();
+
+                channel["position"] = json!(0);
+
+                channel
+            })
+            .collect::<Vec<_>>();
+        let voice_states = voice_states
+            .iter()
+            .map(|(user_id, channel_id)| {
+                json!({
+                    "channel_id": channel_id,
+                    "deaf": false,
+                    "guild_id": guild_id,
+                    "mute": false,
+                    "self_deaf": false,
+                    "self_mute": false,
+                    "self_stream": false,
+                    "self_video": false,
+                    "session_id": "session",
+                    "suppress": false,
+                    "user_id": user_id,
+                })
+            })
+            .collect::<Vec<_>>();
+        let guild = serde_json::from_value::<Guild>(json!({
+            "afk_timeout": 300,
+            "channels": channels,
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "id": guild_id,
+            "mfa_level": 0,
+            "name": "Server",
+            "nsfw_level": 0,
+            "owner_id": next_id::<UserMarker>(),
+            "preferred_locale": "en-US",
+            "premium_progress_bar_enabled": false,
+            "premium_tier": 0,
+            "roles": [{
+                "color": 0,
+                "flags": 0,
+                "hoist": false,
+                "id": next_id::<RoleMarker>(),
+                "managed": true,
+                "mentionable": false,
+                "name": "meloetta",
+                "permissions": "8",
+                "position": 1,
+                "tags": { "bot_id": self.bot_user_id },
+            }],
+            "system_channel_flags": 0,
+            "verification_level": 0,
+            "voice_states": voice_states,
+        }))
+        .unwrap();
+
+        Event::GuildCreate(Box::new(GuildCreate::Available(guild)))
+    }
+}
+
+pub struct Harness {
+    pub context: Arc<Context>,
+    pub discord: FakeDiscord,
+}
+
+impl Harness {
+    pub async fn new() -> Self {
+        Self::with_config(|_| {}).await
+    }
+
+    pub async fn with_config(configure: impl FnOnce(&mut Config)) -> Self {
+        let url = env::var("TEST_DATABASE_URL").unwrap_or_else(|_| "memory:".to_owned());
+        let discord = FakeDiscord::start().await;
+        let client = Client::builder()
+            .proxy(discord.address.to_string(), true)
+            .ratelimiter(None)
+            .token("test".to_owned())
+            .build();
+        let mut config = Config {
+            database: DatabaseConfig {
+                url,
+                ..DatabaseConfig::default()
+            },
+            discord: DiscordConfig {
+                token: "test".to_owned(),
+                ..DiscordConfig::default()
+            },
+            ..Config::default()
+        };
+
+        configure(&mut config);
+
+        let context = Arc::new(Context::new(client, discord.bot_user_id.cast(), config).unwrap());
+
+        context.database.create_tables().await.unwrap();
+
+        Self { context, discord }
+    }
+
+    pub async fn dispatch(&self, events: impl IntoIterator<Item = Event>) {
+        for event in events {
+            handle_event(Arc::clone(&self.context), event).await;
+        }
+    }
+
+    pub async fn seed_guild(&self) -> Id<GuildMarker> {
+        let guild_id = next_id();
+        let bot_role_id: Id<RoleMarker> = next_id();
+        let defaults = &self.context.config.defaults;
+
+        self.discord.insert_guild(guild_id);
+        self.context
+            .database
+            .insert_guild(guild_id, defaults.permanence, &defaults.privacy)
+            .await
+            .unwrap();
+        self.context.cache.insert_guild(
+            guild_id,
+            bot_role_id,
+            defaults.permanence,
+            defaults.privacy.clone(),
+        );
+
+        guild_id
+    }
+
+    pub async fn seed_category_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> (Id<ChannelMarker>, Id<ChannelMarker>) {
+        let category_channel_id = next_id();
+        let join_channel_id = next_id();
+
+        self.context
+            .database
+            .insert_category_channel(category_channel_id, guild_id)
+            .await
+            .unwrap();
+        self.context
+            .cache
+            .insert_category_channel(guild_id, category_channel_id, Vec::new(), []);
+        self.discord.insert_channel(
+            guild_id,
+            category_channel_id,
+            ChannelType::GuildCategory,
+            None,
+        );
+        self.seed_join_channel(DatabaseJoinChannel::new(
+            join_channel_id,
+            guild_id,
+            category_channel_id,
+        ))
+        .await;
+
+        (category_channel_id, join_channel_id)
+    }
+
+    pub async fn seed_join_channel(&self, join_channel: DatabaseJoinChannel) {
+        self.discord.insert_channel(
+            join_channel.guild_id,
+            join_channel.id,
+            ChannelType::GuildVoice,
+            Some(join_channel.category_channel_id),
+        );
+        self.context
+            .database
+            .insert_join_channel(join_channel.clone())
+            .await
+            .unwrap();
+        self.context.cache.insert_join_channel(join_channel.into());
+    }
+
+    pub async fn seed_voice_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> (Id<ChannelMarker>, Id<ChannelMarker>) {
+        let (category_channel_id, join_channel_id) = self.seed_category_channel(guild_id).await;
+
+        self.dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+        let voice_channel_id = self
+            .context
+            .cache
+            .voice_channel_owner(guild_id, user_id)
+            .map(|channel_id| *channel_id)
+            .unwrap();
+
+        (category_channel_id, voice_channel_id)
+    }
+}
@@ -0,0 +1,211 @@
+use axum::http::Method;
+use serde_json::json;
+use twilight_model::{
+    channel::permission_overwrite::PermissionOverwriteType as ChannelPermissionOverwriteType,
+    guild::Permissions,
+};
+
+use super::harness::{application_command, channel_delete, next_id, voice_state_update, Harness};
+use crate::structs::database::DatabaseJoinChannel;
+
+#[tokio::test]
+async fn joining_join_channel_applies_its_preset() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let join_channel_id = next_id();
+    let user_id = next_id();
+
+    harness
+        .seed_join_channel(DatabaseJoinChannel {
+            bitrate: Some(96_000),
+            name_template: Some("{user}'s duo".to_owned()),
+            privacy: Some("locked".to_owned()),
+            user_limit: Some(2),
+            ..DatabaseJoinChannel::new(join_channel_id, guild_id, category_channel_id)
+        })
+        .await;
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let created_channels = harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"));
+
+    assert_eq!(created_channels.len(), 1);
+    assert_eq!(created_channels[0]["name"], "Alex's duo");
+    assert_eq!(created_channels[0]["parent_id"], json!(category_channel_id));
+    assert_eq!(created_channels[0]["user_limit"], 2);
+    assert_eq!(created_channels[0]["bitrate"], 96_000);
+
+    let voice_channel_id = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()[0]
+        .id;
+    let voice_channel = harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .unwrap();
+    let everyone_deny = voice_channel
+        .permission_overwrites
+        .read()
+        .iter()
+        .find(|permission_overwrite| {
+            permission_overwrite.id == guild_id.cast()
+                && permission_overwrite.kind == ChannelPermissionOverwriteType::Role
+        })
+        .map(|permission_overwrite| permission_overwrite.deny);
+
+    assert_eq!(everyone_deny, Some(Permissions::CONNECT));
+}
+
+#[tokio::test]
+async fn creating_join_channel_adds_another_to_category() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "create",
+            json!([{
+                "name": "join-channel",
+                "options": [
+                    { "name": "category", "type": 7, "value": category_channel_id },
+                    { "name": "name", "type": 3, "value": "Join for squad" },
+                    { "name": "user-limit", "type": 4, "value": 5 },
+                ],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    let created_channels = harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"));
+
+    assert_eq!(created_channels.len(), 1);
+    assert_eq!(created_channels[0]["name"], "Join for squad");
+
+    let mut database_join_channels = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap();
+
+    database_join_channels.sort_by_key(|join_channel| join_channel.user_limit);
+
+    assert_eq!(database_join_channels.len(), 2);
+    assert_eq!(database_join_channels[0].id, join_channel_id);
+    assert_eq!(database_join_channels[1].user_limit, Some(5));
+    assert_eq!(database_join_channels[1].name_template, None);
+    assert_eq!(
+        harness
+            .context
+            .cache
+            .category_channel(category_channel_id)
+            .unwrap()
+            .join_channel_ids
+            .read()
+            .len(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn creating_join_channel_rejects_bitrate_above_limit() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "create",
+            json!([{
+                "name": "join-channel",
+                "options": [
+                    { "name": "category", "type": 7, "value": category_channel_id },
+                    { "name": "bitrate", "type": 4, "value": 384 },
+                ],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    assert!(harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"))
+        .is_empty());
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::PATCH, "webhooks/*/*/messages/@original")
+            .pop()
+            .unwrap()["embeds"][0]["description"],
+        "The bitrate must be between 8 and 96, inclusive."
+    );
+    assert_eq!(
+        harness
+            .context
+            .database
+            .guild_join_channels(guild_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn deleting_join_channel_keeps_the_others() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let other_join_channel_id = next_id();
+
+    harness
+        .seed_join_channel(DatabaseJoinChannel::new(
+            other_join_channel_id,
+            guild_id,
+            category_channel_id,
+        ))
+        .await;
+    harness
+        .dispatch([channel_delete(guild_id, join_channel_id)])
+        .await;
+
+    let database_join_channels = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_join_channels.len(), 1);
+    assert_eq!(database_join_channels[0].id, other_join_channel_id);
+    assert!(harness
+        .context
+        .cache
+        .join_channel(join_channel_id)
+        .is_none());
+    assert!(harness
+        .context
+        .cache
+        .join_channel(other_join_channel_id)
+        .is_some());
+}
@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+
+use axum::http::Method;
+use serde_json::{json, Value};
+use twilight_model::{
+    application::command::{CommandOption, CommandOptionChoiceValue},
+    id::{marker::GuildMarker, Id},
+};
+
+use super::harness::{application_command, next_id, with_locale, Harness};
+use crate::{structs::locale::Locale, utilities::constants::COMMANDS};
+
+fn placeholders(message: &str) -> BTreeSet<&str> {
+    message
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+fn command_paths(paths: &mut BTreeSet<String>, options: &[CommandOption], parent_path: &str) {
+    for option in options {
+        let path = format!("{parent_path}.options.{}", option.name);
+
+        paths.insert(format!("{path}.name"));
+        paths.insert(format!("{path}.description"));
+
+        for choice in option.choices.iter().flatten() {
+            if let CommandOptionChoiceValue::String(value) = &choice.value {
+                paths.insert(format!("{path}.choices.{value}"));
+            }
+        }
+
+        if let Some(options) = &option.options {
+            command_paths(paths, options, &path);
+        }
+    }
+}
+
+async fn settings_language(
+    harness: &Harness,
+    guild_id: Id<GuildMarker>,
+    locale: &str,
+    language: &str,
+) -> Value {
+    harness
+        .dispatch([with_locale(
+            application_command(
+                guild_id,
+                next_id(),
+                "settings",
+                json!([{
+                    "name": "language",
+                    "options": [{ "name": "language", "type": 3, "value": language }],
+                    "type": 1,
+                }]),
+            ),
+            locale,
+        )])
+        .await;
+
+    harness
+        .discord
+        .requests(Method::PATCH, "webhooks/*/*/messages/@original")
+        .pop()
+        .unwrap()["embeds"][0]["description"]
+        .clone()
+}
+
+#[test]
+fn locales_have_the_same_messages_and_placeholders() {
+    for locale in Locale::all() {
+        let keys = locale
+            .keys()
+            .filter(|key| !key.starts_with("commands."))
+            .collect::<BTreeSet<&str>>();
+        let default_keys = Locale::DEFAULT.keys().collect::<BTreeSet<&str>>();
+
+        assert_eq!(
+            keys,
+            default_keys,
+            "{} has different messages",
+            locale.code()
+        );
+
+        for key in keys {
+            assert_eq!(
+                placeholders(locale.get(key).unwrap()),
+                placeholders(Locale::DEFAULT.get(key).unwrap()),
+                "{} has different placeholders in {key}",
+                locale.code()
+            );
+        }
+    }
+}
+
+#[test]
+fn command_localizations_match_commands() {
+    let mut paths = BTreeSet::new();
+
+    for command in COMMANDS.iter() {
+        let path = format!("commands.{}", command.name);
+
+        paths.insert(format!("{path}.name"));
+        paths.insert(format!("{path}.description"));
+        command_paths(&mut paths, &command.options, &path);
+    }
+
+    for locale in Locale::all() {
+        for key in locale.keys().filter(|key| key.starts_with("commands.")) {
+            assert!(paths.contains(key), "{} has unknown {key}", locale.code());
+        }
+    }
+}
+
+#[test]
+fn locales_resolve_by_language() {
+    assert_eq!(Locale::new("en-US"), Some(Locale::DEFAULT));
+    assert_eq!(Locale::new("en-GB"), Some(Locale::DEFAULT));
+    assert_eq!(Locale::new("de").map(Locale::code), Some("de"));
+    assert_eq!(Locale::new("fr"), None);
+}
+
+#[test]
+fn format_leaves_arguments_and_unknown_placeholders_as_is() {
+    assert_eq!(
+        Locale::DEFAULT.format("interaction.unknown_command", &[("name", &"{name}")]),
+        "I don't have a command with the name \"{name}\"!"
+    );
+    assert_eq!(
+        Locale::DEFAULT.format("interaction.unknown_command", &[]),
+        "I don't have a command with the name \"{name}\"!"
+    );
+}
+
+#[tokio::test]
+async fn replies_follow_interaction_locale() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+
+    assert_eq!(
+        settings_language(&harness, guild_id, "de", "automatic").await,
+        "Es wurde nichts geändert."
+    );
+    assert_eq!(
+        settings_language(&harness, guild_id, "en-GB", "automatic").await,
+        "No change has been applied."
+    );
+}
+
+#[tokio::test]
+async fn guild_language_overrides_interaction_locale() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+
+    assert_eq!(
+        settings_language(&harness, guild_id, "en-US", "de").await,
+        "Ich antworte in diesem Server jetzt auf **Deutsch**."
+    );
+    assert_eq!(
+        harness
+            .context
+            .database
+            .guild(guild_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .locale
+            .as_deref(),
+        Some("de")
+    );
+    assert_eq!(
+        settings_language(&harness, guild_id, "en-US", "de").await,
+        "Es wurde nichts geändert."
+    );
+    assert_eq!(
+        settings_language(&harness, guild_id, "en-US", "automatic").await,
+        "Ich antworte jetzt in der Sprache jedes Mitglieds."
+    );
+    assert_eq!(
+        settings_language(&harness, guild_id, "en-US", "automatic").await,
+        "No change has been applied."
+    );
+}
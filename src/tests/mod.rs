@@ -0,0 +1,16 @@
+mod adopt;
+mod appearance;
+mod cache;
+mod channel_create;
+mod channel_update;
+mod cli;
+mod consistency;
+mod database;
+mod harness;
+mod join_channel;
+mod locale;
+mod registration;
+mod settings;
+mod template;
+mod voice_channel_creation;
+mod voice_state_update;
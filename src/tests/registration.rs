@@ -0,0 +1,152 @@
+use axum::http::Method;
+use serde_json::{json, Value};
+
+use super::harness::{next_id, Harness};
+use crate::utilities::{
+    constants::COMMANDS,
+    registration::{clear_commands, register_commands, sync_commands},
+};
+
+fn command_values() -> Vec<Value> {
+    COMMANDS
+        .iter()
+        .map(|command| serde_json::to_value(command).unwrap())
+        .collect()
+}
+
+fn command_names() -> Vec<String> {
+    COMMANDS
+        .iter()
+        .map(|command| command.name.clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn registering_unchanged_commands_sends_nothing() {
+    let harness = Harness::new().await;
+    let guild_id = next_id();
+    let path = format!(
+        "applications/{}/guilds/{guild_id}/commands",
+        harness.context.application_id
+    );
+    let changes = register_commands(&harness.context, Some(guild_id))
+        .await
+        .unwrap();
+
+    assert_eq!(changes.created, command_names());
+    assert_eq!(harness.discord.requests(Method::PUT, &path).len(), 1);
+
+    let changes = register_commands(&harness.context, Some(guild_id))
+        .await
+        .unwrap();
+
+    assert!(changes.is_empty());
+    assert_eq!(harness.discord.requests(Method::PUT, &path).len(), 1);
+    assert!(harness
+        .discord
+        .requests(Method::PUT, "applications/*/commands")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn registering_commands_removes_stale_commands() {
+    let harness = Harness::new().await;
+    let path = format!("applications/{}/commands", harness.context.application_id);
+    let mut registered_commands = command_values();
+
+    registered_commands.push(json!({
+        "description": "Removed in an earlier version",
+        "name": "stale",
+        "type": 1,
+    }));
+    harness.discord.set_commands(&path, &registered_commands);
+
+    let changes = register_commands(&harness.context, None).await.unwrap();
+
+    assert!(changes.created.is_empty());
+    assert!(changes.updated.is_empty());
+    assert_eq!(changes.removed, vec!["stale".to_owned()]);
+
+    let sent_commands = harness.discord.requests(Method::PUT, &path);
+
+    assert_eq!(sent_commands.len(), 1);
+    assert!(!sent_commands[0]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|command| command["name"] == "stale"));
+}
+
+#[tokio::test]
+async fn development_mode_clears_global_and_unconfigured_guild_commands() {
+    let guild_id = next_id();
+    let removed_guild_id = next_id();
+    let harness =
+        Harness::with_config(|config| config.development.guild_ids = vec![guild_id]).await;
+    let application_id = harness.context.application_id;
+    let global_path = format!("applications/{application_id}/commands");
+    let guild_path = format!("applications/{application_id}/guilds/{guild_id}/commands");
+    let removed_guild_path =
+        format!("applications/{application_id}/guilds/{removed_guild_id}/commands");
+
+    harness
+        .discord
+        .set_commands(&global_path, &command_values());
+    harness
+        .discord
+        .set_commands(&removed_guild_path, &command_values());
+    harness
+        .context
+        .database
+        .insert_command_guild(removed_guild_id)
+        .await
+        .unwrap();
+
+    sync_commands(&harness.context).await.unwrap();
+
+    assert_eq!(
+        harness.discord.requests(Method::PUT, &global_path),
+        vec![json!([])]
+    );
+    assert_eq!(
+        harness.discord.requests(Method::PUT, &removed_guild_path),
+        vec![json!([])]
+    );
+    assert_eq!(harness.discord.requests(Method::PUT, &guild_path).len(), 1);
+
+    let command_guild_ids = harness.context.database.command_guild_ids().await.unwrap();
+
+    assert!(command_guild_ids.contains(&guild_id));
+    assert!(!command_guild_ids.contains(&removed_guild_id));
+}
+
+#[tokio::test]
+async fn clearing_commands_without_registered_commands_sends_nothing() {
+    let harness = Harness::new().await;
+    let guild_id = next_id();
+    let path = format!(
+        "applications/{}/guilds/{guild_id}/commands",
+        harness.context.application_id
+    );
+
+    harness
+        .context
+        .database
+        .insert_command_guild(guild_id)
+        .await
+        .unwrap();
+
+    let changes = clear_commands(&harness.context, Some(guild_id))
+        .await
+        .unwrap();
+
+    assert!(changes.is_empty());
+    assert!(harness.discord.requests(Method::PUT, &path).is_empty());
+    assert!(!harness
+        .context
+        .database
+        .command_guild_ids()
+        .await
+        .unwrap()
+        .contains(&guild_id));
+}
@@ -0,0 +1,358 @@
+use axum::http::Method;
+use serde_json::{json, Value};
+use twilight_model::id::{
+    marker::{AttachmentMarker, GuildMarker},
+    Id,
+};
+
+use super::harness::{application_command_with_resolved, message_component, next_id, Harness};
+use crate::{
+    structs::{
+        cache::GuildAppearance,
+        database::{DatabaseChannelTemplate, DatabaseJoinChannel},
+    },
+    utilities::guild_export::export_guild,
+};
+
+async fn import_file(harness: &Harness, guild_id: Id<GuildMarker>, file: Vec<u8>) -> Value {
+    let attachment_id = next_id::<AttachmentMarker>();
+    let size = file.len();
+    let url = harness.discord.insert_attachment(file);
+
+    harness
+        .dispatch([application_command_with_resolved(
+            guild_id,
+            next_id(),
+            "settings",
+            json!([{
+                "name": "import",
+                "options": [{ "name": "file", "type": 11, "value": attachment_id }],
+                "type": 1,
+            }]),
+            json!({
+                "attachments": {
+                    attachment_id.to_string(): {
+                        "filename": "meloetta.json",
+                        "id": attachment_id,
+                        "proxy_url": url,
+                        "size": size,
+                        "url": url,
+                    },
+                },
+            }),
+        )])
+        .await;
+
+    harness
+        .discord
+        .requests(Method::PATCH, "webhooks/*/*/messages/@original")
+        .pop()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn importing_export_recreates_settings_in_another_guild() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let other_guild_id = harness.seed_guild().await;
+
+    harness
+        .context
+        .database
+        .update_privacy(guild_id, "locked".to_owned())
+        .await
+        .unwrap();
+    harness
+        .context
+        .database
+        .update_appearance(
+            guild_id,
+            GuildAppearance {
+                color: Some(0x5865F2),
+                ..GuildAppearance::default()
+            },
+        )
+        .await
+        .unwrap();
+    harness
+        .context
+        .database
+        .insert_channel_template(DatabaseChannelTemplate {
+            user_limit: Some(2),
+            ..DatabaseChannelTemplate::new(guild_id, "Duo".to_owned())
+        })
+        .await
+        .unwrap();
+    harness
+        .context
+        .database
+        .update_join_channel(DatabaseJoinChannel {
+            bitrate: Some(64_000),
+            template_name: Some("Duo".to_owned()),
+            ..DatabaseJoinChannel::new(join_channel_id, guild_id, category_channel_id)
+        })
+        .await
+        .unwrap();
+
+    let guild_export = export_guild(&harness.context, guild_id).await.unwrap();
+
+    assert_eq!(guild_export.privacy, "locked");
+    assert_eq!(guild_export.categories.len(), 1);
+    assert_eq!(
+        guild_export.categories[0].join_channels[0].bitrate,
+        Some(64_000)
+    );
+
+    let response = import_file(
+        &harness,
+        other_guild_id,
+        serde_json::to_vec(&guild_export).unwrap(),
+    )
+    .await;
+    let description = response["embeds"][0]["description"].as_str().unwrap();
+
+    assert!(description.contains("Change privacy from **unlocked** to **locked**"));
+    assert!(description.contains("Change the color of my replies and the look of panel messages"));
+    assert!(description.contains("Create the **Duo** template"));
+    assert!(description.contains("Create the **Voice channels** category with **Join to create**"));
+    let apply_custom_id = response["components"][0]["components"][0]["custom_id"]
+        .as_str()
+        .unwrap();
+
+    assert!(apply_custom_id.starts_with("import-apply-button:"));
+
+    harness
+        .dispatch([message_component(
+            other_guild_id,
+            next_id(),
+            next_id(),
+            apply_custom_id,
+            &[],
+        )])
+        .await;
+
+    let created_channels = harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{other_guild_id}/channels"));
+
+    assert_eq!(created_channels.len(), 2);
+    assert_eq!(created_channels[0]["name"], "Voice channels");
+    assert_eq!(created_channels[1]["name"], "Join to create");
+
+    let database_join_channels = harness
+        .context
+        .database
+        .guild_join_channels(other_guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_join_channels.len(), 1);
+    assert_eq!(database_join_channels[0].bitrate, Some(64_000));
+    assert_eq!(
+        database_join_channels[0].template_name.as_deref(),
+        Some("Duo")
+    );
+    assert_eq!(
+        harness
+            .context
+            .database
+            .guild_channel_templates(other_guild_id)
+            .await
+            .unwrap()[0]
+            .user_limit,
+        Some(2)
+    );
+    assert_eq!(
+        *harness
+            .context
+            .cache
+            .guild(other_guild_id)
+            .unwrap()
+            .privacy
+            .read(),
+        "locked"
+    );
+    assert_eq!(
+        harness
+            .context
+            .cache
+            .guild(other_guild_id)
+            .unwrap()
+            .appearance
+            .read()
+            .color,
+        Some(0x5865F2)
+    );
+    assert!(harness.context.pending_imports.is_empty());
+}
+
+#[tokio::test]
+async fn later_import_does_not_replace_pending_one() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let locked_response = import_file(
+        &harness,
+        guild_id,
+        serde_json::to_vec(&json!({ "version": 1, "permanence": true, "privacy": "locked" }))
+            .unwrap(),
+    )
+    .await;
+
+    import_file(
+        &harness,
+        guild_id,
+        serde_json::to_vec(&json!({ "version": 1, "permanence": true, "privacy": "invisible" }))
+            .unwrap(),
+    )
+    .await;
+    harness
+        .dispatch([message_component(
+            guild_id,
+            next_id(),
+            next_id(),
+            locked_response["components"][0]["components"][0]["custom_id"]
+                .as_str()
+                .unwrap(),
+            &[],
+        )])
+        .await;
+
+    assert_eq!(
+        *harness
+            .context
+            .cache
+            .guild(guild_id)
+            .unwrap()
+            .privacy
+            .read(),
+        "locked"
+    );
+    assert_eq!(harness.context.pending_imports.len(), 1);
+}
+
+#[tokio::test]
+async fn importing_past_category_limit_is_rejected() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+
+    for _ in 0..3 {
+        harness.seed_category_channel(guild_id).await;
+    }
+
+    let response = import_file(
+        &harness,
+        guild_id,
+        serde_json::to_vec(&json!({
+            "version": 1,
+            "permanence": true,
+            "privacy": "unlocked",
+            "categories": [{ "id": next_id::<GuildMarker>(), "name": "More voice channels" }],
+        }))
+        .unwrap(),
+    )
+    .await;
+
+    assert_eq!(
+        response["embeds"][0]["description"],
+        "I can't import this file. This server would have more than three voice channel categories after importing."
+    );
+    assert!(harness.context.pending_imports.is_empty());
+}
+
+#[tokio::test]
+async fn applying_import_past_category_limit_is_rejected() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+
+    for _ in 0..2 {
+        harness.seed_category_channel(guild_id).await;
+    }
+
+    let response = import_file(
+        &harness,
+        guild_id,
+        serde_json::to_vec(&json!({
+            "version": 1,
+            "permanence": true,
+            "privacy": "unlocked",
+            "categories": [{ "id": next_id::<GuildMarker>(), "name": "More voice channels" }],
+        }))
+        .unwrap(),
+    )
+    .await;
+    let apply_custom_id = response["components"][0]["components"][0]["custom_id"]
+        .as_str()
+        .unwrap();
+
+    harness.seed_category_channel(guild_id).await;
+    harness
+        .dispatch([message_component(
+            guild_id,
+            next_id(),
+            next_id(),
+            apply_custom_id,
+            &[],
+        )])
+        .await;
+
+    let response = harness
+        .discord
+        .requests(Method::PATCH, "webhooks/*/*/messages/@original")
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        response["embeds"][0]["description"],
+        "I can't import this file. This server would have more than three voice channel categories after importing."
+    );
+    assert!(harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"))
+        .is_empty());
+    assert!(harness.context.pending_imports.is_empty());
+}
+
+#[tokio::test]
+async fn importing_bitrate_above_limit_is_rejected() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let response = import_file(
+        &harness,
+        guild_id,
+        serde_json::to_vec(&json!({
+            "version": 1,
+            "permanence": true,
+            "privacy": "unlocked",
+            "templates": [{ "name": "Studio", "bitrate": 128_000 }],
+        }))
+        .unwrap(),
+    )
+    .await;
+
+    assert_eq!(
+        response["embeds"][0]["description"],
+        "I can't import this file. Bitrates must be between 8000 and 96000."
+    );
+    assert!(harness.context.pending_imports.is_empty());
+}
+
+#[tokio::test]
+async fn importing_unsupported_version_is_rejected() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let response = import_file(
+        &harness,
+        guild_id,
+        serde_json::to_vec(&json!({ "version": 2, "permanence": true, "privacy": "locked" }))
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(
+        response["embeds"][0]["description"],
+        "I can't import this file. I can only import version 1 files, but this file is version 2."
+    );
+    assert_eq!(response["components"], json!([]));
+    assert!(harness.context.pending_imports.is_empty());
+}
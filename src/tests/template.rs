@@ -0,0 +1,329 @@
+use axum::http::Method;
+use serde_json::json;
+use twilight_model::{
+    channel::{
+        permission_overwrite::{
+            PermissionOverwrite as ChannelPermissionOverwrite,
+            PermissionOverwriteType as ChannelPermissionOverwriteType,
+        },
+        ChannelType,
+    },
+    guild::Permissions,
+    id::{marker::GenericMarker, Id},
+};
+
+use super::harness::{
+    application_command, message_component, next_id, voice_state_update, Harness,
+};
+use crate::structs::database::{DatabaseChannelTemplate, DatabaseJoinChannel};
+
+fn role_overwrite(role_id: Id<GenericMarker>) -> ChannelPermissionOverwrite {
+    ChannelPermissionOverwrite {
+        allow: Permissions::CONNECT | Permissions::SPEAK,
+        deny: Permissions::empty(),
+        id: role_id,
+        kind: ChannelPermissionOverwriteType::Role,
+    }
+}
+
+#[tokio::test]
+async fn creating_template_copies_role_overwrites() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let voice_channel_id = next_id();
+    let role_id = next_id();
+
+    harness.discord.insert_channel(
+        guild_id,
+        voice_channel_id,
+        ChannelType::GuildVoice,
+        Some(category_channel_id),
+    );
+    harness.discord.set_permission_overwrites(
+        voice_channel_id,
+        json!([
+            { "allow": "3145728", "deny": "0", "id": role_id, "type": 0 },
+            { "allow": "0", "deny": "1048576", "id": guild_id, "type": 0 },
+            { "allow": "1048576", "deny": "0", "id": next_id::<GenericMarker>(), "type": 1 },
+        ]),
+    );
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "template",
+            json!([{
+                "name": "create",
+                "options": [
+                    { "name": "name", "type": 3, "value": "Duo" },
+                    { "name": "user-limit", "type": 4, "value": 2 },
+                    { "name": "privacy", "type": 3, "value": "locked" },
+                    { "name": "permissions-from", "type": 7, "value": voice_channel_id },
+                ],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    let channel_template = harness
+        .context
+        .database
+        .channel_template(guild_id, "Duo".to_owned())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(channel_template.user_limit, Some(2));
+    assert_eq!(channel_template.privacy.as_deref(), Some("locked"));
+    assert_eq!(channel_template.bitrate, None);
+    assert_eq!(
+        channel_template.permission_overwrites,
+        vec![role_overwrite(role_id)]
+    );
+}
+
+#[tokio::test]
+async fn editing_template_rejects_bitrate_above_limit() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+
+    harness
+        .context
+        .database
+        .insert_channel_template(DatabaseChannelTemplate::new(guild_id, "Duo".to_owned()))
+        .await
+        .unwrap();
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "template",
+            json!([{
+                "name": "edit",
+                "options": [
+                    { "name": "name", "type": 3, "value": "Duo" },
+                    { "name": "bitrate", "type": 4, "value": 256 },
+                ],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::PATCH, "webhooks/*/*/messages/@original")
+            .pop()
+            .unwrap()["embeds"][0]["description"],
+        "The bitrate must be between 8 and 96, inclusive."
+    );
+    assert_eq!(
+        harness
+            .context
+            .database
+            .channel_template(guild_id, "Duo".to_owned())
+            .await
+            .unwrap()
+            .unwrap()
+            .bitrate,
+        None
+    );
+}
+
+#[tokio::test]
+async fn joining_join_channel_applies_its_template() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let join_channel_id = next_id();
+    let role_id = next_id();
+
+    harness
+        .context
+        .database
+        .insert_channel_template(DatabaseChannelTemplate {
+            bitrate: Some(64_000),
+            permission_overwrites: vec![role_overwrite(role_id)],
+            user_limit: Some(2),
+            ..DatabaseChannelTemplate::new(guild_id, "Duo".to_owned())
+        })
+        .await
+        .unwrap();
+    harness
+        .seed_join_channel(DatabaseJoinChannel {
+            template_name: Some("Duo".to_owned()),
+            user_limit: Some(3),
+            ..DatabaseJoinChannel::new(join_channel_id, guild_id, category_channel_id)
+        })
+        .await;
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            next_id(),
+            "Alex",
+        )])
+        .await;
+
+    let created_channels = harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"));
+
+    assert_eq!(created_channels.len(), 1);
+    assert_eq!(created_channels[0]["user_limit"], 3);
+    assert_eq!(created_channels[0]["bitrate"], 64_000);
+    assert!(created_channels[0]["permission_overwrites"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|permission_overwrite| permission_overwrite["id"] == json!(role_id)));
+}
+
+#[tokio::test]
+async fn applying_template_from_panel_updates_voice_channel() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let user_id = next_id();
+    let role_id = next_id();
+
+    harness
+        .context
+        .database
+        .insert_channel_template(DatabaseChannelTemplate {
+            permission_overwrites: vec![role_overwrite(role_id)],
+            privacy: Some("invisible".to_owned()),
+            user_limit: Some(5),
+            ..DatabaseChannelTemplate::new(guild_id, "Squad".to_owned())
+        })
+        .await
+        .unwrap();
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let voice_channel_id = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()[0]
+        .id;
+
+    harness
+        .dispatch([message_component(
+            guild_id,
+            voice_channel_id,
+            user_id,
+            "apply-template-select",
+            &["Squad"],
+        )])
+        .await;
+
+    let updated_channels = harness
+        .discord
+        .requests(Method::PATCH, &format!("channels/{voice_channel_id}"));
+
+    assert_eq!(updated_channels.len(), 1);
+    assert_eq!(updated_channels[0]["user_limit"], 5);
+
+    let permission_overwrites = updated_channels[0]["permission_overwrites"]
+        .as_array()
+        .unwrap();
+    let everyone_deny = permission_overwrites
+        .iter()
+        .find(|permission_overwrite| permission_overwrite["id"] == json!(guild_id))
+        .map(|permission_overwrite| permission_overwrite["deny"].clone());
+
+    assert!(permission_overwrites
+        .iter()
+        .any(|permission_overwrite| permission_overwrite["id"] == json!(role_id)));
+    assert_eq!(
+        everyone_deny,
+        Some(json!(Permissions::VIEW_CHANNEL.bits().to_string()))
+    );
+}
+
+#[tokio::test]
+async fn deleting_template_unassigns_join_channels() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    harness
+        .context
+        .database
+        .insert_channel_template(DatabaseChannelTemplate::new(guild_id, "Duo".to_owned()))
+        .await
+        .unwrap();
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "template",
+            json!([{
+                "name": "assign",
+                "options": [
+                    { "name": "join-channel", "type": 7, "value": join_channel_id },
+                    { "name": "name", "type": 3, "value": "Duo" },
+                ],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    assert_eq!(
+        harness
+            .context
+            .cache
+            .join_channel(join_channel_id)
+            .unwrap()
+            .template_name
+            .as_deref(),
+        Some("Duo")
+    );
+
+    harness
+        .dispatch([application_command(
+            guild_id,
+            next_id(),
+            "template",
+            json!([{
+                "name": "delete",
+                "options": [{ "name": "name", "type": 3, "value": "Duo" }],
+                "type": 1,
+            }]),
+        )])
+        .await;
+
+    let database_join_channels = harness
+        .context
+        .database
+        .guild_join_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert!(harness
+        .context
+        .database
+        .guild_channel_templates(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(database_join_channels[0].template_name, None);
+    assert_eq!(
+        harness
+            .context
+            .cache
+            .join_channel(join_channel_id)
+            .unwrap()
+            .template_name,
+        None
+    );
+}
@@ -0,0 +1,341 @@
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+use twilight_model::{
+    channel::ChannelType,
+    id::{marker::UserMarker, Id},
+};
+
+use super::harness::{next_id, voice_state_update, Harness};
+use crate::{
+    events::recover_voice_channel_creations, structs::database::DatabaseVoiceChannelCreation,
+};
+
+#[tokio::test]
+async fn failed_member_move_deletes_voice_channel() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let user_id = next_id();
+
+    harness.discord.fail_next(
+        Method::PATCH,
+        &format!("guilds/{guild_id}/members/{user_id}"),
+        StatusCode::BAD_REQUEST,
+    );
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let deleted_channels = harness.discord.requests(Method::DELETE, "channels/*").len();
+
+    assert_eq!(deleted_channels, 1);
+    assert!(harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!harness
+        .context
+        .database
+        .voice_channel_creations()
+        .await
+        .unwrap()
+        .iter()
+        .any(|voice_channel_creation| voice_channel_creation.guild_id == guild_id));
+    assert!(harness
+        .context
+        .cache
+        .voice_channel_owner(guild_id, user_id)
+        .is_none());
+}
+
+#[tokio::test]
+async fn transient_panel_failure_is_retried() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    harness.discord.fail_next(
+        Method::POST,
+        "channels/*/messages",
+        StatusCode::INTERNAL_SERVER_ERROR,
+    );
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            next_id(),
+            "Sam",
+        )])
+        .await;
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_voice_channels.len(), 1);
+    assert!(database_voice_channels[0].panel_message_id.is_some());
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::POST, "channels/*/messages")
+            .len(),
+        2
+    );
+    assert!(harness
+        .discord
+        .requests(Method::DELETE, "channels/*")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn lost_voice_channel_creation_is_not_repeated() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    harness.discord.fail_next_after_handling(
+        Method::POST,
+        "guilds/*/channels",
+        StatusCode::INTERNAL_SERVER_ERROR,
+    );
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            next_id(),
+            "Sam",
+        )])
+        .await;
+
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::POST, "guilds/*/channels")
+            .len(),
+        1
+    );
+    assert_eq!(
+        harness
+            .context
+            .database
+            .guild_voice_channels(guild_id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(harness
+        .discord
+        .requests(Method::DELETE, "channels/*")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn lost_panel_message_is_not_sent_again() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+
+    harness.discord.fail_next_after_handling(
+        Method::POST,
+        "channels/*/messages",
+        StatusCode::GATEWAY_TIMEOUT,
+    );
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            next_id(),
+            "Sam",
+        )])
+        .await;
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_voice_channels.len(), 1);
+    assert!(database_voice_channels[0].panel_message_id.is_some());
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::POST, "channels/*/messages")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn lost_voice_channel_creation_ignores_channels_without_owner_overwrite() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let user_id = next_id();
+    let other_channel_id = next_id();
+
+    harness.discord.insert_channel(
+        guild_id,
+        other_channel_id,
+        ChannelType::GuildVoice,
+        Some(category_channel_id),
+    );
+    harness.discord.set_name(other_channel_id, "Sam's Channel");
+    harness
+        .discord
+        .set_permission_overwrites(other_channel_id, json!([]));
+    harness.discord.fail_next_after_handling(
+        Method::POST,
+        "guilds/*/channels",
+        StatusCode::INTERNAL_SERVER_ERROR,
+    );
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Sam",
+        )])
+        .await;
+
+    let voice_channel_id = harness
+        .context
+        .cache
+        .voice_channel_owner(guild_id, user_id)
+        .map(|channel_id| *channel_id)
+        .unwrap();
+
+    assert_ne!(voice_channel_id, other_channel_id);
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::POST, "guilds/*/channels")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn recovery_removes_incomplete_voice_channels() {
+    let harness = Harness::new().await;
+    let database = &harness.context.database;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let owner_id = next_id();
+    let voice_channel_id = next_id();
+
+    database
+        .insert_voice_channel_creation(DatabaseVoiceChannelCreation {
+            guild_id,
+            id: Some(voice_channel_id),
+            name: "Alex's Channel".to_owned(),
+            owner_id,
+            parent_id: category_channel_id,
+        })
+        .await
+        .unwrap();
+    database
+        .insert_voice_channel(
+            voice_channel_id,
+            guild_id,
+            category_channel_id,
+            Some(owner_id),
+        )
+        .await
+        .unwrap();
+    recover_voice_channel_creations(&harness.context)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::DELETE, &format!("channels/{voice_channel_id}"))
+            .len(),
+        1
+    );
+    assert!(database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(!database
+        .voice_channel_creations()
+        .await
+        .unwrap()
+        .iter()
+        .any(|voice_channel_creation| voice_channel_creation.guild_id == guild_id));
+}
+
+#[tokio::test]
+async fn recovery_finds_voice_channels_created_without_a_response() {
+    let harness = Harness::new().await;
+    let database = &harness.context.database;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, _) = harness.seed_category_channel(guild_id).await;
+    let owner_id: Id<UserMarker> = next_id();
+    let voice_channel_id = next_id();
+    let other_channel_id = next_id();
+
+    for channel_id in [voice_channel_id, other_channel_id] {
+        harness.discord.insert_channel(
+            guild_id,
+            channel_id,
+            ChannelType::GuildVoice,
+            Some(category_channel_id),
+        );
+        harness.discord.set_name(channel_id, "Alex's Channel");
+    }
+
+    harness.discord.set_permission_overwrites(
+        voice_channel_id,
+        json!([{ "allow": "1048576", "deny": "0", "id": owner_id, "type": 1 }]),
+    );
+    harness
+        .discord
+        .set_permission_overwrites(other_channel_id, json!([]));
+    database
+        .insert_voice_channel_creation(DatabaseVoiceChannelCreation {
+            guild_id,
+            id: None,
+            name: "Alex's Channel".to_owned(),
+            owner_id,
+            parent_id: category_channel_id,
+        })
+        .await
+        .unwrap();
+    recover_voice_channel_creations(&harness.context)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        harness.discord.requests(Method::DELETE, "channels/*").len(),
+        1
+    );
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::DELETE, &format!("channels/{voice_channel_id}"))
+            .len(),
+        1
+    );
+    assert!(!database
+        .voice_channel_creations()
+        .await
+        .unwrap()
+        .iter()
+        .any(|voice_channel_creation| voice_channel_creation.guild_id == guild_id));
+}
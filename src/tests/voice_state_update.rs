@@ -0,0 +1,146 @@
+use axum::http::Method;
+use serde_json::json;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use super::harness::{channel_delete, next_id, voice_state_update, Harness};
+
+#[tokio::test]
+async fn joining_join_channel_creates_voice_channel() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (category_channel_id, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let user_id = next_id();
+
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let created_channels = harness
+        .discord
+        .requests(Method::POST, &format!("guilds/{guild_id}/channels"));
+
+    assert_eq!(created_channels.len(), 1);
+    assert_eq!(created_channels[0]["name"], "Alex's voice");
+    assert_eq!(created_channels[0]["parent_id"], json!(category_channel_id));
+
+    let database_voice_channels = harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap();
+
+    assert_eq!(database_voice_channels.len(), 1);
+
+    let voice_channel_id = database_voice_channels[0].id;
+
+    assert_eq!(database_voice_channels[0].owner_id, Some(user_id));
+    assert_eq!(database_voice_channels[0].parent_id, category_channel_id);
+    assert!(database_voice_channels[0].panel_message_id.is_some());
+    assert_eq!(
+        harness
+            .discord
+            .requests(
+                Method::POST,
+                &format!("channels/{voice_channel_id}/messages")
+            )
+            .len(),
+        1
+    );
+    assert_eq!(
+        harness.discord.requests(
+            Method::PATCH,
+            &format!("guilds/{guild_id}/members/{user_id}")
+        ),
+        vec![json!({ "channel_id": voice_channel_id })]
+    );
+
+    let voice_channel = harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .unwrap();
+
+    assert_eq!(*voice_channel.owner_id.read(), Some(user_id));
+    assert!(voice_channel.panel_message_id.read().is_some());
+}
+
+#[tokio::test]
+async fn leaving_voice_channel_deletes_it() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let (_, join_channel_id) = harness.seed_category_channel(guild_id).await;
+    let user_id = next_id();
+
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(join_channel_id),
+            user_id,
+            "Alex",
+        )])
+        .await;
+
+    let voice_channel_id = harness
+        .context
+        .cache
+        .voice_channel_owner(guild_id, user_id)
+        .map(|channel_id| *channel_id)
+        .unwrap();
+
+    harness
+        .dispatch([
+            voice_state_update(guild_id, Some(voice_channel_id), user_id, "Alex"),
+            voice_state_update(guild_id, None, user_id, "Alex"),
+        ])
+        .await;
+
+    assert_eq!(
+        harness
+            .discord
+            .requests(Method::DELETE, &format!("channels/{voice_channel_id}"))
+            .len(),
+        1
+    );
+
+    harness
+        .dispatch([channel_delete(guild_id, voice_channel_id)])
+        .await;
+
+    assert!(harness
+        .context
+        .database
+        .guild_voice_channels(guild_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(harness
+        .context
+        .cache
+        .voice_channel(voice_channel_id)
+        .is_none());
+}
+
+#[tokio::test]
+async fn joining_other_channel_does_not_create_voice_channel() {
+    let harness = Harness::new().await;
+    let guild_id = harness.seed_guild().await;
+    let _ = harness.seed_category_channel(guild_id).await;
+    let other_channel_id: Id<ChannelMarker> = next_id();
+
+    harness
+        .dispatch([voice_state_update(
+            guild_id,
+            Some(other_channel_id),
+            next_id(),
+            "Alex",
+        )])
+        .await;
+
+    assert_eq!(harness.discord.request_count(), 0);
+}
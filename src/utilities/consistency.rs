@@ -0,0 +1,572 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::Duration,
+};
+
+use eyre::Result;
+use tokio::{
+    sync::oneshot,
+    time::{interval, MissedTickBehavior},
+};
+use tracing::{info, warn};
+use twilight_model::{
+    channel::{
+        permission_overwrite::PermissionOverwrite as ChannelPermissionOverwrite, Channel,
+        ChannelType,
+    },
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    structs::{cache::Cache, context::Context, locale::Locale},
+    utilities::request::ObserveRequest,
+};
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Inconsistency {
+    DanglingCategoryChannel(Id<ChannelMarker>),
+    DanglingJoinChannel(Id<ChannelMarker>),
+    DanglingVoiceChannel(Id<ChannelMarker>),
+    DeletedChannel(Id<ChannelMarker>),
+    DeletedJoinChannel(Id<ChannelMarker>),
+    MismatchedChannel(Id<ChannelMarker>, &'static str),
+    MissingConnectedUser(Id<ChannelMarker>, Id<UserMarker>),
+    MissingVoiceChannelOwner(Id<ChannelMarker>, Id<UserMarker>),
+    MovedVoiceChannel(Id<ChannelMarker>),
+    StaleConnectedUser(Id<ChannelMarker>, Id<UserMarker>),
+    StaleVoiceChannelOwner(Id<ChannelMarker>, Id<UserMarker>),
+    UncachedChannel(Id<ChannelMarker>),
+    UnlistedCategoryChannel(Id<ChannelMarker>),
+    UnlistedJoinChannel(Id<ChannelMarker>),
+    UnlistedVoiceChannel(Id<ChannelMarker>),
+    UnstoredChannel(Id<ChannelMarker>),
+}
+
+impl Inconsistency {
+    pub fn description(&self, locale: Locale) -> String {
+        let key = format!("admin.check.inconsistencies.{}", self.kind());
+
+        match self {
+            Self::MismatchedChannel(channel_id, field) => {
+                locale.format(&key, &[("channel_id", channel_id), ("field", field)])
+            }
+            Self::MissingConnectedUser(channel_id, user_id)
+            | Self::MissingVoiceChannelOwner(channel_id, user_id)
+            | Self::StaleConnectedUser(channel_id, user_id)
+            | Self::StaleVoiceChannelOwner(channel_id, user_id) => {
+                locale.format(&key, &[("channel_id", channel_id), ("user_id", user_id)])
+            }
+            Self::DanglingCategoryChannel(channel_id)
+            | Self::DanglingJoinChannel(channel_id)
+            | Self::DanglingVoiceChannel(channel_id)
+            | Self::DeletedChannel(channel_id)
+            | Self::DeletedJoinChannel(channel_id)
+            | Self::MovedVoiceChannel(channel_id)
+            | Self::UncachedChannel(channel_id)
+            | Self::UnlistedCategoryChannel(channel_id)
+            | Self::UnlistedJoinChannel(channel_id)
+            | Self::UnlistedVoiceChannel(channel_id)
+            | Self::UnstoredChannel(channel_id) => {
+                locale.format(&key, &[("channel_id", channel_id)])
+            }
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::DanglingCategoryChannel(_) => "dangling_category_channel",
+            Self::DanglingJoinChannel(_) => "dangling_join_channel",
+            Self::DanglingVoiceChannel(_) => "dangling_voice_channel",
+            Self::DeletedChannel(_) => "deleted_channel",
+            Self::DeletedJoinChannel(_) => "deleted_join_channel",
+            Self::MismatchedChannel(..) => "mismatched_channel",
+            Self::MissingConnectedUser(..) => "missing_connected_user",
+            Self::MissingVoiceChannelOwner(..) => "missing_voice_channel_owner",
+            Self::MovedVoiceChannel(_) => "moved_voice_channel",
+            Self::StaleConnectedUser(..) => "stale_connected_user",
+            Self::StaleVoiceChannelOwner(..) => "stale_voice_channel_owner",
+            Self::UncachedChannel(_) => "uncached_channel",
+            Self::UnlistedCategoryChannel(_) => "unlisted_category_channel",
+            Self::UnlistedJoinChannel(_) => "unlisted_join_channel",
+            Self::UnlistedVoiceChannel(_) => "unlisted_voice_channel",
+            Self::UnstoredChannel(_) => "unstored_channel",
+        }
+    }
+}
+
+impl Display for Inconsistency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.description(Locale::DEFAULT))
+    }
+}
+
+pub fn check_cache(cache: &Cache, guild_id: Id<GuildMarker>) -> Vec<Inconsistency> {
+    let mut inconsistencies = Vec::new();
+    let Some(guild) = cache.guild(guild_id) else {
+        return inconsistencies;
+    };
+    let category_channel_ids = guild.category_channel_ids.read().clone();
+    let category_channels = cache
+        .guild_category_channels(guild_id)
+        .into_iter()
+        .map(|category_channel| (category_channel.id, category_channel))
+        .collect::<HashMap<_, _>>();
+    let join_channels = cache
+        .guild_join_channels(guild_id)
+        .into_iter()
+        .map(|join_channel| (join_channel.id, join_channel))
+        .collect::<HashMap<_, _>>();
+    let voice_channels = cache
+        .guild_voice_channels(guild_id)
+        .into_iter()
+        .map(|voice_channel| (voice_channel.id, voice_channel))
+        .collect::<HashMap<_, _>>();
+    let voice_channel_owners = cache
+        .guild_voice_channel_owners(guild_id)
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    let voice_states = cache
+        .guild_voice_states(guild_id)
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+    for category_channel_id in &category_channel_ids {
+        if !category_channels.contains_key(category_channel_id) {
+            inconsistencies.push(Inconsistency::DanglingCategoryChannel(*category_channel_id));
+        }
+    }
+
+    for (category_channel_id, category_channel) in &category_channels {
+        if !category_channel_ids.contains(category_channel_id) {
+            inconsistencies.push(Inconsistency::UnlistedCategoryChannel(*category_channel_id));
+        }
+
+        for join_channel_id in category_channel.join_channel_ids.read().iter() {
+            if !join_channels
+                .get(join_channel_id)
+                .is_some_and(|join_channel| {
+                    join_channel.category_channel_id == *category_channel_id
+                })
+            {
+                inconsistencies.push(Inconsistency::DanglingJoinChannel(*join_channel_id));
+            }
+        }
+
+        for voice_channel_id in category_channel.voice_channel_ids.read().iter() {
+            if !voice_channels
+                .get(voice_channel_id)
+                .is_some_and(|voice_channel| {
+                    *voice_channel.parent_id.read() == *category_channel_id
+                })
+            {
+                inconsistencies.push(Inconsistency::DanglingVoiceChannel(*voice_channel_id));
+            }
+        }
+    }
+
+    for (join_channel_id, join_channel) in &join_channels {
+        if !category_channels
+            .get(&join_channel.category_channel_id)
+            .is_some_and(|category_channel| {
+                category_channel
+                    .join_channel_ids
+                    .read()
+                    .contains(join_channel_id)
+            })
+        {
+            inconsistencies.push(Inconsistency::UnlistedJoinChannel(*join_channel_id));
+        }
+    }
+
+    for (voice_channel_id, voice_channel) in &voice_channels {
+        if !category_channels
+            .get(&*voice_channel.parent_id.read())
+            .is_some_and(|category_channel| {
+                category_channel
+                    .voice_channel_ids
+                    .read()
+                    .contains(voice_channel_id)
+            })
+        {
+            inconsistencies.push(Inconsistency::UnlistedVoiceChannel(*voice_channel_id));
+        }
+
+        if let Some(owner_id) = *voice_channel.owner_id.read() {
+            if voice_channel_owners.get(&owner_id) != Some(voice_channel_id) {
+                inconsistencies.push(Inconsistency::MissingVoiceChannelOwner(
+                    *voice_channel_id,
+                    owner_id,
+                ));
+            }
+        }
+
+        for user_id in voice_channel.connected_user_ids.read().iter() {
+            if voice_states.get(user_id) != Some(voice_channel_id) {
+                inconsistencies.push(Inconsistency::StaleConnectedUser(
+                    *voice_channel_id,
+                    *user_id,
+                ));
+            }
+        }
+    }
+
+    for (user_id, voice_channel_id) in voice_channel_owners {
+        if !voice_channels
+            .get(&voice_channel_id)
+            .is_some_and(|voice_channel| *voice_channel.owner_id.read() == Some(user_id))
+        {
+            inconsistencies.push(Inconsistency::StaleVoiceChannelOwner(
+                voice_channel_id,
+                user_id,
+            ));
+        }
+    }
+
+    for (user_id, channel_id) in voice_states {
+        if voice_channels
+            .get(&channel_id)
+            .is_some_and(|voice_channel| {
+                !voice_channel.connected_user_ids.read().contains(&user_id)
+            })
+        {
+            inconsistencies.push(Inconsistency::MissingConnectedUser(channel_id, user_id));
+        }
+    }
+
+    inconsistencies
+}
+
+pub async fn check_guild(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+) -> Result<Vec<Inconsistency>> {
+    if context.cache.guild(guild_id).is_none() {
+        return Ok(Vec::new());
+    }
+
+    let mut inconsistencies = check_cache(&context.cache, guild_id);
+    let channels = guild_channels(context, guild_id).await?;
+    let database_category_channels = context.database.guild_category_channels(guild_id).await?;
+    let database_join_channels = context.database.guild_join_channels(guild_id).await?;
+    let database_voice_channels = context.database.guild_voice_channels(guild_id).await?;
+    let category_channels = context.cache.guild_category_channels(guild_id);
+    let join_channels = context.cache.guild_join_channels(guild_id);
+    let voice_channels = context.cache.guild_voice_channels(guild_id);
+
+    for database_category_channel in &database_category_channels {
+        if context
+            .cache
+            .category_channel(database_category_channel.id)
+            .is_none()
+        {
+            inconsistencies.push(Inconsistency::UncachedChannel(database_category_channel.id));
+        }
+    }
+
+    for database_join_channel in &database_join_channels {
+        let Some(join_channel) = context.cache.join_channel(database_join_channel.id) else {
+            inconsistencies.push(Inconsistency::UncachedChannel(database_join_channel.id));
+
+            continue;
+        };
+
+        if join_channel.category_channel_id != database_join_channel.category_channel_id {
+            inconsistencies.push(Inconsistency::MismatchedChannel(
+                join_channel.id,
+                "category",
+            ));
+        }
+
+        if join_channel.bitrate != database_join_channel.bitrate
+            || join_channel.name_template != database_join_channel.name_template
+            || join_channel.privacy != database_join_channel.privacy
+            || join_channel.template_name != database_join_channel.template_name
+            || join_channel.user_limit != database_join_channel.user_limit
+        {
+            inconsistencies.push(Inconsistency::MismatchedChannel(join_channel.id, "preset"));
+        }
+    }
+
+    for database_voice_channel in &database_voice_channels {
+        let Some(voice_channel) = context.cache.voice_channel(database_voice_channel.id) else {
+            inconsistencies.push(Inconsistency::UncachedChannel(database_voice_channel.id));
+
+            continue;
+        };
+
+        if *voice_channel.owner_id.read() != database_voice_channel.owner_id {
+            inconsistencies.push(Inconsistency::MismatchedChannel(voice_channel.id, "owner"));
+        }
+
+        if *voice_channel.panel_message_id.read() != database_voice_channel.panel_message_id {
+            inconsistencies.push(Inconsistency::MismatchedChannel(
+                voice_channel.id,
+                "panel message",
+            ));
+        }
+
+        if *voice_channel.parent_id.read() != database_voice_channel.parent_id {
+            inconsistencies.push(Inconsistency::MismatchedChannel(
+                voice_channel.id,
+                "category",
+            ));
+        }
+    }
+
+    for category_channel in &category_channels {
+        if !database_category_channels
+            .iter()
+            .any(|database_category_channel| database_category_channel.id == category_channel.id)
+        {
+            inconsistencies.push(Inconsistency::UnstoredChannel(category_channel.id));
+        }
+
+        if !channels.contains_key(&category_channel.id) {
+            inconsistencies.push(Inconsistency::DeletedChannel(category_channel.id));
+        }
+    }
+
+    for join_channel in &join_channels {
+        if !database_join_channels
+            .iter()
+            .any(|database_join_channel| database_join_channel.id == join_channel.id)
+        {
+            inconsistencies.push(Inconsistency::UnstoredChannel(join_channel.id));
+        }
+
+        if !channels.contains_key(&join_channel.id) {
+            inconsistencies.push(Inconsistency::DeletedJoinChannel(join_channel.id));
+        }
+    }
+
+    for voice_channel in &voice_channels {
+        if !database_voice_channels
+            .iter()
+            .any(|database_voice_channel| database_voice_channel.id == voice_channel.id)
+        {
+            inconsistencies.push(Inconsistency::UnstoredChannel(voice_channel.id));
+        }
+
+        match channels.get(&voice_channel.id) {
+            None => inconsistencies.push(Inconsistency::DeletedChannel(voice_channel.id)),
+            Some(channel) if channel.parent_id != Some(*voice_channel.parent_id.read()) => {
+                inconsistencies.push(Inconsistency::MovedVoiceChannel(voice_channel.id));
+            }
+            Some(_) => {}
+        }
+    }
+
+    inconsistencies.sort();
+    inconsistencies.dedup();
+
+    Ok(inconsistencies)
+}
+
+// Prunes the database against Discord, then rebuilds the guild's cache from it.
+pub async fn repair_guild(context: &Context, guild_id: Id<GuildMarker>) -> Result<()> {
+    if context.cache.guild(guild_id).is_none() {
+        return Ok(());
+    }
+
+    let channels = guild_channels(context, guild_id).await?;
+
+    prune_guild_channels(context, guild_id, &channels).await?;
+
+    let database_category_channels = context.database.guild_category_channels(guild_id).await?;
+    let database_join_channels = context.database.guild_join_channels(guild_id).await?;
+    let database_voice_channels = context.database.guild_voice_channels(guild_id).await?;
+    // Discord only reports voice states over the gateway, so occupancy is rebuilt from the ones
+    // GUILD_CREATE and VOICE_STATE_UPDATE left in the cache.
+    let voice_states = context.cache.guild_voice_states(guild_id);
+
+    context.cache.clear_guild_channels(guild_id);
+
+    for database_category_channel in database_category_channels {
+        context.cache.insert_category_channel(
+            guild_id,
+            database_category_channel.id,
+            permission_overwrites(&channels, database_category_channel.id),
+            [],
+        );
+    }
+
+    for database_join_channel in database_join_channels {
+        context
+            .cache
+            .insert_join_channel(database_join_channel.into());
+    }
+
+    for database_voice_channel in database_voice_channels {
+        context.cache.insert_voice_channel(
+            [],
+            guild_id,
+            database_voice_channel.id,
+            database_voice_channel.owner_id,
+            database_voice_channel.panel_message_id,
+            database_voice_channel.parent_id,
+            permission_overwrites(&channels, database_voice_channel.id),
+        );
+    }
+
+    for (user_id, channel_id) in voice_states {
+        context
+            .cache
+            .insert_voice_state(guild_id, channel_id, user_id);
+    }
+
+    Ok(())
+}
+
+// Removes stored channels that no longer exist on Discord, moves the rest to their current
+// category, and returns the removed IDs.
+pub async fn prune_guild_channels(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    channels: &HashMap<Id<ChannelMarker>, Channel>,
+) -> Result<Vec<Id<ChannelMarker>>> {
+    let category_channel_ids = context
+        .database
+        .guild_category_channels(guild_id)
+        .await?
+        .into_iter()
+        .filter(|database_category_channel| channels.contains_key(&database_category_channel.id))
+        .map(|database_category_channel| database_category_channel.id)
+        .collect::<HashSet<_>>();
+    let mut voice_channel_and_parent_ids = Vec::new();
+    let channel_ids = channels
+        .values()
+        .filter_map(|channel| match (channel.kind, channel.parent_id) {
+            (ChannelType::GuildCategory, _) => Some(channel.id),
+            (ChannelType::GuildVoice, Some(parent_id))
+                if category_channel_ids.contains(&parent_id) =>
+            {
+                voice_channel_and_parent_ids.push((channel.id, parent_id));
+
+                Some(channel.id)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let removed_channel_ids = context
+        .database
+        .remove_channels(guild_id, channel_ids)
+        .await?;
+
+    for database_join_channel in context.database.guild_join_channels(guild_id).await? {
+        if let Some(&(_, parent_id)) = voice_channel_and_parent_ids
+            .iter()
+            .find(|(channel_id, _)| *channel_id == database_join_channel.id)
+            .filter(|(_, parent_id)| *parent_id != database_join_channel.category_channel_id)
+        {
+            context
+                .database
+                .update_join_channel_category(database_join_channel.id, parent_id)
+                .await?;
+        }
+    }
+
+    if !voice_channel_and_parent_ids.is_empty() {
+        context
+            .database
+            .update_channels(guild_id, voice_channel_and_parent_ids)
+            .await?;
+    }
+
+    Ok(removed_channel_ids)
+}
+
+pub async fn run_check(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    repair: bool,
+) -> Result<Vec<Inconsistency>> {
+    let inconsistencies = check_guild(context, guild_id).await?;
+
+    for inconsistency in &inconsistencies {
+        context
+            .metrics
+            .cache_inconsistencies
+            .with_label_values(&[inconsistency.kind()])
+            .inc();
+        warn!(guild_id = guild_id.get(), %inconsistency, "Found cache inconsistency");
+    }
+
+    if repair && !inconsistencies.is_empty() {
+        repair_guild(context, guild_id).await?;
+        info!(guild_id = guild_id.get(), "Repaired cache");
+    }
+
+    Ok(inconsistencies)
+}
+
+pub async fn run_periodically(context: Arc<Context>) {
+    let consistency = &context.config.consistency;
+    let mut interval = interval(Duration::from_secs(consistency.interval_seconds));
+
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        if context.tasks.is_closed() {
+            break;
+        }
+
+        // Each check runs on its guild's queue so repairs can't interleave with the guild's events,
+        // and guilds are still checked one at a time.
+        for guild_id in context.cache.guild_ids() {
+            let (checked_sender, checked_receiver) = oneshot::channel();
+            let job_context = Arc::clone(&context);
+            let repair = consistency.repair;
+
+            context
+                .event_queue
+                .push_job(Arc::clone(&context), Some(guild_id), async move {
+                    if let Err(source) = run_check(&job_context, guild_id, repair).await {
+                        warn!(
+                            guild_id = guild_id.get(),
+                            ?source,
+                            "Unable to check cache consistency"
+                        );
+                    }
+
+                    _ = checked_sender.send(());
+                });
+            _ = checked_receiver.await;
+        }
+    }
+}
+
+pub async fn guild_channels(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+) -> Result<HashMap<Id<ChannelMarker>, Channel>> {
+    let channels = context
+        .client
+        .guild_channels(guild_id)
+        .observe(context)
+        .await?
+        .models()
+        .await?;
+
+    Ok(channels
+        .into_iter()
+        .map(|channel| (channel.id, channel))
+        .collect())
+}
+
+fn permission_overwrites(
+    channels: &HashMap<Id<ChannelMarker>, Channel>,
+    channel_id: Id<ChannelMarker>,
+) -> Vec<ChannelPermissionOverwrite> {
+    channels
+        .get(&channel_id)
+        .and_then(|channel| channel.permission_overwrites.clone())
+        .unwrap_or_default()
+}
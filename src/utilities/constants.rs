@@ -1,33 +1,67 @@
 use std::{collections::HashSet, sync::LazyLock};
 
-use std::env;
-
-use eyre::WrapErr;
 use twilight_gateway::{EventTypeFlags, Intents};
 use twilight_model::{
     application::command::{Command, CommandType},
-    channel::{
-        message::{
-            component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
-            Component, Embed,
-        },
-        ChannelType,
-    },
+    channel::ChannelType,
 };
-use twilight_util::builder::{
-    command::{BooleanBuilder, ChannelBuilder, CommandBuilder, StringBuilder, SubCommandBuilder},
-    embed::EmbedBuilder,
+use twilight_util::builder::command::{
+    AttachmentBuilder, BooleanBuilder, ChannelBuilder, CommandBuilder, IntegerBuilder,
+    StringBuilder, SubCommandBuilder, UserBuilder,
 };
 
+use crate::structs::locale::{localize_commands, Locale};
+
 pub static COMMANDS: LazyLock<Vec<Command>> = LazyLock::new(|| {
-    vec![
+    let mut commands = vec![
+        CommandBuilder::new(
+            "admin",
+            "Maintain my internal state",
+            CommandType::ChatInput,
+        )
+        .option(
+            SubCommandBuilder::new("check", "Compare my cache with the database and Discord")
+                .option(
+                    BooleanBuilder::new("repair", "Should inconsistencies be repaired?").build(),
+                )
+                .build(),
+        )
+        .build(),
         CommandBuilder::new(
             "create",
             "Create (or recreate) missing things",
             CommandType::ChatInput,
         )
         .option(
-            SubCommandBuilder::new("join-channel", "Recreate a join channel")
+            SubCommandBuilder::new(
+                "adopt",
+                "Manage an existing category and its voice channels",
+            )
+            .option(
+                ChannelBuilder::new("category", "The category to manage")
+                    .channel_types(vec![ChannelType::GuildCategory])
+                    .required(true)
+                    .build(),
+            )
+            .option(
+                ChannelBuilder::new(
+                    "join-channel",
+                    "An existing voice channel in the category to use as its join channel",
+                )
+                .channel_types(vec![ChannelType::GuildVoice])
+                .build(),
+            )
+            .option(
+                UserBuilder::new(
+                    "owner",
+                    "The member owning voice channels that nobody else can own",
+                )
+                .build(),
+            )
+            .build(),
+        )
+        .option(
+            SubCommandBuilder::new("join-channel", "Create a join channel")
                 .option(
                     ChannelBuilder::new(
                         "category",
@@ -37,6 +71,36 @@ pub static COMMANDS: LazyLock<Vec<Command>> = LazyLock::new(|| {
                     .required(true)
                     .build(),
                 )
+                .option(StringBuilder::new("name", "The join channel name").build())
+                .option(
+                    StringBuilder::new(
+                        "name-template",
+                        "The name of created voice channels, where {user} is their owner",
+                    )
+                    .max_length(100)
+                    .build(),
+                )
+                .option(
+                    IntegerBuilder::new("user-limit", "The user limit of created voice channels")
+                        .min_value(1)
+                        .max_value(99)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("privacy", "The privacy state of created voice channels")
+                        .choices(vec![
+                            ("Invisible", "invisible"),
+                            ("Locked (and visible)", "locked"),
+                            ("Unlocked (and visible)", "unlocked"),
+                        ])
+                        .build(),
+                )
+                .option(
+                    IntegerBuilder::new("bitrate", "The bitrate of created voice channels in kbps")
+                        .min_value(8)
+                        .max_value(384)
+                        .build(),
+                )
                 .build(),
         )
         .option(
@@ -63,6 +127,72 @@ pub static COMMANDS: LazyLock<Vec<Command>> = LazyLock::new(|| {
         )
         .build(),
         CommandBuilder::new("settings", "Configure settings", CommandType::ChatInput)
+            .option(
+                SubCommandBuilder::new(
+                    "appearance",
+                    "Customize the color of my replies and the look of panel messages",
+                )
+                .option(
+                    StringBuilder::new("color", "The embed color as a hex code, such as #5865F2")
+                        .max_length(7)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("title", "The title of panel messages")
+                        .max_length(256)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("description", "The text of panel messages")
+                        .max_length(4096)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("thumbnail", "A link to an image shown in panel messages")
+                        .max_length(512)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("footer", "The footer of panel messages")
+                        .max_length(2048)
+                        .build(),
+                )
+                .option(
+                    BooleanBuilder::new(
+                        "reset",
+                        "Should the default appearance be restored before applying the other options?",
+                    )
+                    .build(),
+                )
+                .build(),
+            )
+            .option(SubCommandBuilder::new("export", "Download the settings as a file").build())
+            .option(
+                SubCommandBuilder::new("import", "Apply settings from an exported file")
+                    .option(
+                        AttachmentBuilder::new("file", "The file from /settings export")
+                            .required(true)
+                            .build(),
+                    )
+                    .build(),
+            )
+            .option(
+                SubCommandBuilder::new("language", "Set the language of my replies in this server")
+                    .option(
+                        StringBuilder::new(
+                            "language",
+                            "The language, or Automatic for each member's own language",
+                        )
+                        .choices(
+                            [("Automatic", "automatic")]
+                                .into_iter()
+                                .chain(Locale::all().map(|locale| (locale.name(), locale.code()))),
+                        )
+                        .required(true)
+                        .build(),
+                    )
+                    .build(),
+            )
             .option(
                 SubCommandBuilder::new(
                     "permanence",
@@ -99,81 +229,184 @@ pub static COMMANDS: LazyLock<Vec<Command>> = LazyLock::new(|| {
             )
             .option(SubCommandBuilder::new("show", "View current settings").build())
             .build(),
-    ]
-});
+        CommandBuilder::new(
+            "template",
+            "Manage reusable voice channel settings",
+            CommandType::ChatInput,
+        )
+        .option(
+            SubCommandBuilder::new("assign", "Assign a template to a join channel")
+                .option(
+                    ChannelBuilder::new(
+                        "join-channel",
+                        "The join channel to assign the template to",
+                    )
+                    .channel_types(vec![ChannelType::GuildVoice])
+                    .required(true)
+                    .build(),
+                )
+                .option(
+                    StringBuilder::new("name", "The template name, or nothing to unassign")
+                        .max_length(50)
+                        .build(),
+                )
+                .build(),
+        )
+        .option(
+            SubCommandBuilder::new("create", "Create a template")
+                .option(
+                    StringBuilder::new("name", "The template name")
+                        .max_length(50)
+                        .required(true)
+                        .build(),
+                )
+                .option(
+                    IntegerBuilder::new("user-limit", "The user limit of voice channels")
+                        .min_value(1)
+                        .max_value(99)
+                        .build(),
+                )
+                .option(
+                    IntegerBuilder::new("bitrate", "The bitrate of voice channels in kbps")
+                        .min_value(8)
+                        .max_value(384)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("privacy", "The privacy state of voice channels")
+                        .choices(vec![
+                            ("Invisible", "invisible"),
+                            ("Locked (and visible)", "locked"),
+                            ("Unlocked (and visible)", "unlocked"),
+                        ])
+                        .build(),
+                )
+                .option(
+                    ChannelBuilder::new(
+                        "permissions-from",
+                        "A voice channel to copy role permission overwrites from",
+                    )
+                    .channel_types(vec![ChannelType::GuildVoice])
+                    .build(),
+                )
+                .build(),
+        )
+        .option(
+            SubCommandBuilder::new("delete", "Delete a template")
+                .option(
+                    StringBuilder::new("name", "The template name")
+                        .max_length(50)
+                        .required(true)
+                        .build(),
+                )
+                .build(),
+        )
+        .option(
+            SubCommandBuilder::new("edit", "Change the settings of a template")
+                .option(
+                    StringBuilder::new("name", "The template name")
+                        .max_length(50)
+                        .required(true)
+                        .build(),
+                )
+                .option(
+                    IntegerBuilder::new("user-limit", "The user limit of voice channels")
+                        .min_value(1)
+                        .max_value(99)
+                        .build(),
+                )
+                .option(
+                    IntegerBuilder::new("bitrate", "The bitrate of voice channels in kbps")
+                        .min_value(8)
+                        .max_value(384)
+                        .build(),
+                )
+                .option(
+                    StringBuilder::new("privacy", "The privacy state of voice channels")
+                        .choices(vec![
+                            ("Invisible", "invisible"),
+                            ("Locked (and visible)", "locked"),
+                            ("Unlocked (and visible)", "unlocked"),
+                        ])
+                        .build(),
+                )
+                .option(
+                    ChannelBuilder::new(
+                        "permissions-from",
+                        "A voice channel to copy role permission overwrites from",
+                    )
+                    .channel_types(vec![ChannelType::GuildVoice])
+                    .build(),
+                )
+                .build(),
+        )
+        .option(SubCommandBuilder::new("list", "View templates").build())
+        .build(),
+    ];
 
-pub static DATABASE_URL: LazyLock<String> = LazyLock::new(|| {
-    env::var("DATABASE_URL")
-        .wrap_err("Environment variable \"DATABASE_URL\" is not set.")
-        .unwrap()
-});
+    localize_commands(&mut commands);
 
-pub static DISCORD_TOKEN: LazyLock<String> = LazyLock::new(|| {
-    env::var("DISCORD_TOKEN")
-        .wrap_err("Environment variable \"DISCORD_TOKEN\" is not set.")
-        .unwrap()
+    commands
 });
 
 pub static INTENTS: LazyLock<Intents> =
     LazyLock::new(|| Intents::GUILDS | Intents::GUILD_MEMBERS | Intents::GUILD_VOICE_STATES);
 
-pub static NON_VOICE_CHANNEL_OWNER_SELECT_OPTIONS: LazyLock<HashSet<String>> =
-    LazyLock::new(|| HashSet::from_iter(vec!["claim-select-option".to_owned()]));
-
-pub static PANEL_MESSAGE_COMPONENTS: LazyLock<Vec<Component>> = LazyLock::new(|| {
-    let options = [
-        ("Allow member (permissions)", "allow-member-select-option"),
-        ("Deny member (permissions)", "deny-member-select-option"),
-        ("Claim voice channel", "claim-select-option"),
-        ("Kick member", "kick-member-select-option"),
-        ("Modify bitrate", "modify-bitrate-select-option"),
-        ("Modify name", "modify-name-select-option"),
-        ("Modify privacy", "modify-privacy-select-option"),
-        ("Modify slowmode", "modify-slowmode-select-option"),
-        ("Modify user limit", "modify-user-limit-select-option"),
-        ("Modify video quality", "modify-video-quality-select-option"),
-        ("Remove channel", "remove-channel-select-option"),
-        ("Remove member (permissions)", "remove-member-select-option"),
-        ("Transfer voice channel", "transfer-select-option"),
-        ("View information", "view-information-select-option"),
-    ]
-    .into_iter()
-    .map(|(label, value)| SelectMenuOption {
-        default: false,
-        description: None,
-        emoji: None,
-        label: label.to_owned(),
-        value: value.to_owned(),
-    })
-    .collect::<Vec<SelectMenuOption>>();
-    let select_menu = Component::ActionRow(ActionRow {
-        components: vec![Component::SelectMenu(SelectMenu {
-            channel_types: None,
-            custom_id: "edit-channel-select".to_owned(),
-            default_values: None,
-            disabled: false,
-            kind: SelectMenuType::Text,
-            max_values: Some(1),
-            min_values: Some(1),
-            options: Some(options),
-            placeholder: Some("Edit channel".to_owned()),
-        })],
-    });
-
-    vec![select_menu]
+pub static GUILD_MESSAGE_COMPONENT_IDS: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    HashSet::from_iter(vec![
+        "import-apply-button".to_owned(),
+        "import-cancel-button".to_owned(),
+    ])
 });
 
-pub static PANEL_MESSAGE_EMBED: LazyLock<Embed> = LazyLock::new(|| {
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description("Make the channel your own!")
-        .build();
+pub static NON_VOICE_CHANNEL_OWNER_SELECT_OPTIONS: LazyLock<HashSet<String>> =
+    LazyLock::new(|| HashSet::from_iter(vec!["claim-select-option".to_owned()]));
 
-    embed
-});
+pub const PANEL_MESSAGE_SELECT_OPTIONS: [(&str, &str); 15] = [
+    ("panel.options.allow_member", "allow-member-select-option"),
+    (
+        "panel.options.apply_template",
+        "apply-template-select-option",
+    ),
+    ("panel.options.deny_member", "deny-member-select-option"),
+    ("panel.options.claim", "claim-select-option"),
+    ("panel.options.kick_member", "kick-member-select-option"),
+    (
+        "panel.options.modify_bitrate",
+        "modify-bitrate-select-option",
+    ),
+    ("panel.options.modify_name", "modify-name-select-option"),
+    (
+        "panel.options.modify_privacy",
+        "modify-privacy-select-option",
+    ),
+    (
+        "panel.options.modify_slowmode",
+        "modify-slowmode-select-option",
+    ),
+    (
+        "panel.options.modify_user_limit",
+        "modify-user-limit-select-option",
+    ),
+    (
+        "panel.options.modify_video_quality",
+        "modify-video-quality-select-option",
+    ),
+    (
+        "panel.options.remove_channel",
+        "remove-channel-select-option",
+    ),
+    ("panel.options.remove_member", "remove-member-select-option"),
+    ("panel.options.transfer", "transfer-select-option"),
+    (
+        "panel.options.view_information",
+        "view-information-select-option",
+    ),
+];
 
 pub static WANTED_EVENT_TYPES: LazyLock<EventTypeFlags> = LazyLock::new(|| {
-    EventTypeFlags::CHANNEL_DELETE
+    EventTypeFlags::CHANNEL_CREATE
+        | EventTypeFlags::CHANNEL_DELETE
         | EventTypeFlags::CHANNEL_UPDATE
         | EventTypeFlags::GUILD_CREATE
         | EventTypeFlags::GUILD_DELETE
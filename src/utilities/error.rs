@@ -0,0 +1,89 @@
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    panic,
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use tracing::error;
+use twilight_model::{
+    gateway::event::EventType,
+    id::{
+        marker::{GuildMarker, InteractionMarker},
+        Id,
+    },
+};
+
+use crate::{
+    structs::{context::Context, error::Error, locale::Locale},
+    utilities::{
+        interaction::{create_interaction_response_embed, create_response_embed, embed_color},
+        request::ObserveRequest,
+    },
+};
+
+pub static PANIC_COUNT: AtomicU64 = AtomicU64::new(0);
+
+pub struct ErrorSource {
+    pub event_type: EventType,
+    pub guild_id: Option<Id<GuildMarker>>,
+    pub interaction: Option<(Id<InteractionMarker>, String, Locale)>,
+}
+
+pub fn install_panic_hook() {
+    panic::set_hook(Box::new(|panic_info| {
+        PANIC_COUNT.fetch_add(1, Ordering::Relaxed);
+
+        let thread = thread::current();
+
+        error!(
+            thread = thread.name().unwrap_or("<unnamed>"),
+            backtrace = %Backtrace::capture(),
+            "Panicked: {panic_info}"
+        );
+    }));
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+pub async fn report_error(context: &Context, source: ErrorSource, error: Error) {
+    error!(%error, "Unable to handle event");
+
+    let Some((interaction_id, interaction_token, locale)) = source.interaction else {
+        return;
+    };
+    let color = embed_color(context, source.guild_id);
+    let interaction_response =
+        create_interaction_response_embed(color, error.user_message(locale).to_owned(), true);
+
+    if context
+        .interaction_client()
+        .create_response(interaction_id, &interaction_token, &interaction_response)
+        .observe(context)
+        .await
+        .is_ok()
+    {
+        return;
+    }
+
+    let embed = create_response_embed(color, error.user_message(locale));
+
+    if let Err(source) = context
+        .interaction_client()
+        .update_response(&interaction_token)
+        .embeds(Some(&[embed]))
+        .observe(context)
+        .await
+    {
+        error!(%interaction_id, %source, "Unable to report error to interaction");
+    }
+}
@@ -0,0 +1,498 @@
+use std::{collections::HashMap, sync::Arc};
+
+use eyre::{eyre, Result};
+use twilight_model::{
+    channel::{
+        permission_overwrite::{
+            PermissionOverwrite as ChannelPermissionOverwrite,
+            PermissionOverwriteType as ChannelPermissionOverwriteType,
+        },
+        Channel, ChannelType,
+    },
+    guild::Permissions,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+
+use super::{
+    consistency::guild_channels, permission::update_category_privacy, request::ObserveRequest,
+};
+use crate::structs::{
+    cache::{CachedGuild, CachedJoinChannel},
+    config::{MAX_CATEGORY_CHANNELS, MAX_CHANNEL_TEMPLATES, MAX_JOIN_CHANNELS},
+    context::Context,
+    database::{DatabaseChannelTemplate, DatabaseJoinChannel},
+    error::Error,
+    guild_export::{
+        ExportedCategoryChannel, ExportedChannelTemplate, ExportedJoinChannel, GuildExport,
+        GUILD_EXPORT_VERSION,
+    },
+    locale::Locale,
+};
+
+pub async fn export_guild(context: &Context, guild_id: Id<GuildMarker>) -> Result<GuildExport> {
+    let guild = context
+        .database
+        .guild(guild_id)
+        .await?
+        .ok_or(Error::MissingData("guild"))?;
+    let locale = guild
+        .locale
+        .as_deref()
+        .and_then(Locale::new)
+        .unwrap_or(Locale::DEFAULT);
+    let channels = guild_channels(context, guild_id).await?;
+    let mut database_category_channels = context.database.guild_category_channels(guild_id).await?;
+    let mut database_join_channels = context.database.guild_join_channels(guild_id).await?;
+
+    database_category_channels.sort_by_key(|category_channel| category_channel.id);
+    database_join_channels.sort_by_key(|join_channel| join_channel.id);
+
+    let categories = database_category_channels
+        .into_iter()
+        .map(|category_channel| ExportedCategoryChannel {
+            id: category_channel.id,
+            name: channel_name(
+                &channels,
+                category_channel.id,
+                locale.text("channels.category"),
+            ),
+            join_channels: database_join_channels
+                .iter()
+                .filter(|join_channel| join_channel.category_channel_id == category_channel.id)
+                .map(|join_channel| ExportedJoinChannel {
+                    id: join_channel.id,
+                    name: channel_name(
+                        &channels,
+                        join_channel.id,
+                        locale.text("channels.join_channel"),
+                    ),
+                    bitrate: join_channel.bitrate,
+                    name_template: join_channel.name_template.clone(),
+                    privacy: join_channel.privacy.clone(),
+                    template_name: join_channel.template_name.clone(),
+                    user_limit: join_channel.user_limit,
+                })
+                .collect(),
+        })
+        .collect();
+    let templates = context
+        .database
+        .guild_channel_templates(guild_id)
+        .await?
+        .into_iter()
+        .map(|channel_template| ExportedChannelTemplate {
+            name: channel_template.name,
+            bitrate: channel_template.bitrate,
+            permission_overwrites: channel_template.permission_overwrites,
+            privacy: channel_template.privacy,
+            user_limit: channel_template.user_limit,
+        })
+        .collect();
+
+    Ok(GuildExport {
+        version: GUILD_EXPORT_VERSION,
+        permanence: guild.permanence,
+        privacy: guild.privacy,
+        locale: guild.locale,
+        appearance: guild.appearance,
+        categories,
+        templates,
+    })
+}
+
+// Lists the changes importing `guild_export` would make, failing if the result would break a limit.
+pub fn diff_guild_export(
+    context: &Context,
+    guild: &CachedGuild,
+    channel_templates: &[DatabaseChannelTemplate],
+    guild_export: &GuildExport,
+    locale: Locale,
+) -> Result<Vec<String>> {
+    let mut changes = Vec::new();
+    let permanence = *guild.permanence.read();
+    let privacy = guild.privacy.read().clone();
+    let guild_locale = guild.locale.read().clone();
+
+    if permanence != guild_export.permanence {
+        changes.push(locale.format(
+            "settings.import.changes.permanence",
+            &[("old", &permanence), ("new", &guild_export.permanence)],
+        ));
+    }
+
+    if privacy != guild_export.privacy {
+        changes.push(locale.format(
+            "settings.import.changes.privacy",
+            &[("old", &privacy), ("new", &guild_export.privacy)],
+        ));
+    }
+
+    if guild_locale != guild_export.locale {
+        let language_name = |code: &Option<String>| {
+            code.as_deref()
+                .and_then(Locale::new)
+                .map_or(locale.text("settings.language.automatic"), Locale::name)
+        };
+
+        changes.push(locale.format(
+            "settings.import.changes.language",
+            &[
+                ("old", &language_name(&guild_locale)),
+                ("new", &language_name(&guild_export.locale)),
+            ],
+        ));
+    }
+
+    if *guild.appearance.read() != guild_export.appearance {
+        changes.push(locale.text("settings.import.changes.appearance").to_owned());
+    }
+
+    let mut channel_template_count = channel_templates.len();
+
+    for template in &guild_export.templates {
+        match channel_templates
+            .iter()
+            .find(|channel_template| channel_template.name == template.name)
+        {
+            Some(channel_template) if is_template_unchanged(channel_template, template) => {}
+            Some(_) => changes.push(locale.format(
+                "settings.import.changes.update_template",
+                &[("name", &template.name)],
+            )),
+            None => {
+                channel_template_count += 1;
+                changes.push(locale.format(
+                    "settings.import.changes.create_template",
+                    &[("name", &template.name)],
+                ));
+            }
+        }
+    }
+
+    if channel_template_count > MAX_CHANNEL_TEMPLATES {
+        return Err(eyre!(locale.text("settings.import.errors.template_limit")));
+    }
+
+    let mut category_channel_count = guild.category_channel_ids.read().len();
+
+    for category in &guild_export.categories {
+        for join_channel in &category.join_channels {
+            if let Some(template_name) = &join_channel.template_name {
+                if !guild_export
+                    .templates
+                    .iter()
+                    .any(|template| &template.name == template_name)
+                    && !channel_templates
+                        .iter()
+                        .any(|channel_template| &channel_template.name == template_name)
+                {
+                    return Err(eyre!(locale.format(
+                        "settings.import.errors.missing_template",
+                        &[
+                            ("join_channel", &join_channel.name),
+                            ("name", template_name)
+                        ],
+                    )));
+                }
+            }
+        }
+
+        let Some(category_channel) = context
+            .cache
+            .category_channel(category.id)
+            .filter(|category_channel| category_channel.guild_id == guild.id)
+        else {
+            let join_channel_names = category
+                .join_channels
+                .iter()
+                .map(|join_channel| format!("**{}**", join_channel.name))
+                .collect::<Vec<String>>();
+
+            category_channel_count += 1;
+
+            if join_channel_names.is_empty() {
+                changes.push(locale.format(
+                    "settings.import.changes.create_category",
+                    &[("name", &category.name)],
+                ));
+            } else {
+                changes.push(locale.format(
+                    "settings.import.changes.create_category_with_join_channels",
+                    &[
+                        ("name", &category.name),
+                        ("join_channels", &join_channel_names.join(", ")),
+                    ],
+                ));
+            }
+
+            continue;
+        };
+        let mut join_channel_count = category_channel.join_channel_ids.read().len();
+
+        for join_channel in &category.join_channels {
+            match cached_join_channel(context, guild.id, join_channel.id) {
+                Some(cached_join_channel)
+                    if is_join_channel_unchanged(&cached_join_channel, join_channel) => {}
+                Some(_) => changes.push(locale.format(
+                    "settings.import.changes.update_join_channel",
+                    &[("channel_id", &join_channel.id)],
+                )),
+                None => {
+                    join_channel_count += 1;
+                    changes.push(locale.format(
+                        "settings.import.changes.create_join_channel",
+                        &[("name", &join_channel.name), ("channel_id", &category.id)],
+                    ));
+                }
+            }
+        }
+
+        if join_channel_count > MAX_JOIN_CHANNELS {
+            return Err(eyre!(locale.format(
+                "settings.import.errors.join_channel_limit",
+                &[("channel_id", &category.id)],
+            )));
+        }
+    }
+
+    if category_channel_count > MAX_CATEGORY_CHANNELS {
+        return Err(eyre!(locale.text("settings.import.errors.category_limit")));
+    }
+
+    Ok(changes)
+}
+
+pub async fn import_guild(
+    context: &Arc<Context>,
+    guild: &CachedGuild,
+    guild_export: GuildExport,
+) -> Result<()> {
+    if *guild.permanence.read() != guild_export.permanence {
+        context
+            .database
+            .update_permanence(guild.id, guild_export.permanence)
+            .await?;
+        context
+            .cache
+            .update_permanence(guild.id, guild_export.permanence);
+    }
+
+    if *guild.privacy.read() != guild_export.privacy {
+        context
+            .database
+            .update_privacy(guild.id, guild_export.privacy.clone())
+            .await?;
+        context
+            .cache
+            .update_privacy(guild.id, guild_export.privacy.clone());
+        update_category_privacy(context, guild, &guild_export.privacy);
+    }
+
+    if *guild.locale.read() != guild_export.locale {
+        context
+            .database
+            .update_locale(guild.id, guild_export.locale.clone())
+            .await?;
+        context
+            .cache
+            .update_locale(guild.id, guild_export.locale.clone());
+    }
+
+    if *guild.appearance.read() != guild_export.appearance {
+        context
+            .database
+            .update_appearance(guild.id, guild_export.appearance.clone())
+            .await?;
+        context
+            .cache
+            .update_appearance(guild.id, guild_export.appearance.clone());
+    }
+
+    let channel_templates = context.database.guild_channel_templates(guild.id).await?;
+
+    for template in guild_export.templates {
+        let channel_template = DatabaseChannelTemplate {
+            bitrate: template.bitrate,
+            permission_overwrites: template.permission_overwrites,
+            privacy: template.privacy,
+            user_limit: template.user_limit,
+            ..DatabaseChannelTemplate::new(guild.id, template.name)
+        };
+
+        if channel_templates.iter().any(|existing_channel_template| {
+            existing_channel_template.name == channel_template.name
+        }) {
+            context
+                .database
+                .update_channel_template(channel_template)
+                .await?;
+        } else {
+            context
+                .database
+                .insert_channel_template(channel_template)
+                .await?;
+        }
+    }
+
+    for category in guild_export.categories {
+        let category_channel_id = match context
+            .cache
+            .category_channel(category.id)
+            .filter(|category_channel| category_channel.guild_id == guild.id)
+        {
+            Some(category_channel) => category_channel.id,
+            None => {
+                create_category_channel(context, guild, &category.name, &guild_export.privacy)
+                    .await?
+            }
+        };
+
+        for join_channel in category.join_channels {
+            let preset = DatabaseJoinChannel {
+                bitrate: join_channel.bitrate,
+                name_template: join_channel.name_template.clone(),
+                privacy: join_channel.privacy.clone(),
+                template_name: join_channel.template_name.clone(),
+                user_limit: join_channel.user_limit,
+                ..DatabaseJoinChannel::new(join_channel.id, guild.id, category_channel_id)
+            };
+
+            match cached_join_channel(context, guild.id, join_channel.id) {
+                Some(cached_join_channel)
+                    if is_join_channel_unchanged(&cached_join_channel, &join_channel) => {}
+                Some(cached_join_channel) => {
+                    let join_channel = DatabaseJoinChannel {
+                        category_channel_id: cached_join_channel.category_channel_id,
+                        ..preset
+                    };
+
+                    context
+                        .database
+                        .update_join_channel(join_channel.clone())
+                        .await?;
+                    context.cache.insert_join_channel(join_channel.into());
+                }
+                None => {
+                    let created_join_channel = context
+                        .client
+                        .create_guild_channel(guild.id, &join_channel.name)
+                        .kind(ChannelType::GuildVoice)
+                        .parent_id(category_channel_id)
+                        .position(0)
+                        .observe(context)
+                        .await?
+                        .model()
+                        .await?;
+                    let join_channel = DatabaseJoinChannel {
+                        id: created_join_channel.id,
+                        ..preset
+                    };
+
+                    context
+                        .database
+                        .insert_join_channel(join_channel.clone())
+                        .await?;
+                    context.cache.insert_join_channel(join_channel.into());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_category_channel(
+    context: &Context,
+    guild: &CachedGuild,
+    name: &str,
+    privacy: &str,
+) -> Result<Id<ChannelMarker>> {
+    let everyone_deny = if privacy.eq("invisible") {
+        Permissions::VIEW_CHANNEL
+    } else {
+        Permissions::empty()
+    };
+    let created_category_channel = context
+        .client
+        .create_guild_channel(guild.id, name)
+        .kind(ChannelType::GuildCategory)
+        .permission_overwrites(&[
+            ChannelPermissionOverwrite {
+                allow: Permissions::empty(),
+                deny: everyone_deny,
+                id: guild.id.cast(),
+                kind: ChannelPermissionOverwriteType::Role,
+            },
+            ChannelPermissionOverwrite {
+                allow: Permissions::VIEW_CHANNEL,
+                deny: Permissions::empty(),
+                id: guild.bot_role_id.cast(),
+                kind: ChannelPermissionOverwriteType::Role,
+            },
+        ])
+        .observe(context)
+        .await?
+        .model()
+        .await?;
+
+    context
+        .database
+        .insert_category_channel(created_category_channel.id, guild.id)
+        .await?;
+    context.cache.insert_category_channel(
+        guild.id,
+        created_category_channel.id,
+        created_category_channel
+            .permission_overwrites
+            .unwrap_or_default(),
+        Vec::new(),
+    );
+
+    Ok(created_category_channel.id)
+}
+
+fn cached_join_channel(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    channel_id: Id<ChannelMarker>,
+) -> Option<Arc<CachedJoinChannel>> {
+    context
+        .cache
+        .join_channel(channel_id)
+        .filter(|join_channel| join_channel.guild_id == guild_id)
+}
+
+fn channel_name(
+    channels: &HashMap<Id<ChannelMarker>, Channel>,
+    channel_id: Id<ChannelMarker>,
+    default_name: &str,
+) -> String {
+    channels
+        .get(&channel_id)
+        .and_then(|channel| channel.name.clone())
+        .unwrap_or_else(|| default_name.to_owned())
+}
+
+fn is_join_channel_unchanged(
+    cached_join_channel: &CachedJoinChannel,
+    join_channel: &ExportedJoinChannel,
+) -> bool {
+    cached_join_channel.bitrate == join_channel.bitrate
+        && cached_join_channel.name_template == join_channel.name_template
+        && cached_join_channel.privacy == join_channel.privacy
+        && cached_join_channel.template_name == join_channel.template_name
+        && cached_join_channel.user_limit == join_channel.user_limit
+}
+
+fn is_template_unchanged(
+    channel_template: &DatabaseChannelTemplate,
+    template: &ExportedChannelTemplate,
+) -> bool {
+    channel_template.bitrate == template.bitrate
+        && channel_template.permission_overwrites == template.permission_overwrites
+        && channel_template.privacy == template.privacy
+        && channel_template.user_limit == template.user_limit
+}
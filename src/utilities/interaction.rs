@@ -2,18 +2,44 @@ use std::sync::Arc;
 
 use eyre::{eyre, Result};
 use twilight_model::{
-    application::interaction::{Interaction, InteractionType},
-    channel::message::{Component, MessageFlags},
+    application::interaction::{
+        application_command::{CommandDataOption, CommandOptionValue},
+        message_component::MessageComponentInteractionData,
+        modal::ModalInteractionData,
+        Interaction, InteractionData, InteractionType,
+    },
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, SelectMenuType},
+        Component, Embed, MessageFlags,
+    },
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseType},
+    id::{
+        marker::{ChannelMarker, GuildMarker, InteractionMarker, MessageMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+use twilight_util::builder::{
+    embed::{EmbedBuilder, EmbedFooterBuilder, ImageSource},
+    InteractionResponseDataBuilder,
 };
-use twilight_util::builder::{embed::EmbedBuilder, InteractionResponseDataBuilder};
 
+use super::{
+    constants::{GUILD_MESSAGE_COMPONENT_IDS, PANEL_MESSAGE_SELECT_OPTIONS},
+    request::{retry_create, ObserveRequest},
+};
 use crate::structs::{
     cache::{CachedGuild, CachedVoiceChannel},
     context::Context,
+    database::DatabaseChannelTemplate,
+    error::Error,
+    interaction::GuildMessageComponentInteraction,
+    locale::Locale,
 };
 
+const PANEL_MESSAGE_SEARCH_LIMIT: u16 = 10;
+
 pub fn create_deferred_interaction_response(ephemeral: bool) -> InteractionResponse {
     let mut data_builder = InteractionResponseDataBuilder::new();
 
@@ -22,22 +48,33 @@ pub fn create_deferred_interaction_response(ephemeral: bool) -> InteractionRespo
     }
 
     let data = data_builder.build();
-    let interaction_response = InteractionResponse {
+
+    InteractionResponse {
         data: Some(data),
         kind: InteractionResponseType::DeferredChannelMessageWithSource,
-    };
+    }
+}
 
-    interaction_response
+pub fn create_deferred_update_response() -> InteractionResponse {
+    InteractionResponse {
+        data: None,
+        kind: InteractionResponseType::DeferredUpdateMessage,
+    }
+}
+
+pub fn create_response_embed(color: u32, description: impl Into<String>) -> Embed {
+    EmbedBuilder::new()
+        .color(color)
+        .description(description)
+        .build()
 }
 
 pub fn create_interaction_response_embed(
+    color: u32,
     description: String,
     ephemeral: bool,
 ) -> InteractionResponse {
-    let embed = EmbedBuilder::new()
-        .color(0xF8F8FF)
-        .description(description)
-        .build();
+    let embed = create_response_embed(color, description);
     let mut data_builder = InteractionResponseDataBuilder::new().embeds(vec![embed]);
 
     if ephemeral {
@@ -45,12 +82,213 @@ pub fn create_interaction_response_embed(
     }
 
     let data = data_builder.build();
-    let interaction_response = InteractionResponse {
+
+    InteractionResponse {
         data: Some(data),
         kind: InteractionResponseType::ChannelMessageWithSource,
-    };
+    }
+}
+
+pub fn create_import_confirmation_components(
+    import_id: Id<InteractionMarker>,
+    locale: Locale,
+) -> Vec<Component> {
+    vec![Component::ActionRow(ActionRow {
+        components: vec![
+            Component::Button(Button {
+                custom_id: Some(format!("import-apply-button:{import_id}")),
+                disabled: false,
+                emoji: None,
+                label: Some(locale.text("settings.import.apply").to_owned()),
+                sku_id: None,
+                style: ButtonStyle::Success,
+                url: None,
+            }),
+            Component::Button(Button {
+                custom_id: Some(format!("import-cancel-button:{import_id}")),
+                disabled: false,
+                emoji: None,
+                label: Some(locale.text("settings.import.cancel").to_owned()),
+                sku_id: None,
+                style: ButtonStyle::Secondary,
+                url: None,
+            }),
+        ],
+    })]
+}
+
+pub fn create_panel_message_components(locale: Locale) -> Vec<Component> {
+    let options = PANEL_MESSAGE_SELECT_OPTIONS
+        .into_iter()
+        .map(|(key, value)| SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: locale.text(key).to_owned(),
+            value: value.to_owned(),
+        })
+        .collect::<Vec<SelectMenuOption>>();
+    let select_menu = Component::ActionRow(ActionRow {
+        components: vec![Component::SelectMenu(SelectMenu {
+            channel_types: None,
+            custom_id: "edit-channel-select".to_owned(),
+            default_values: None,
+            disabled: false,
+            kind: SelectMenuType::Text,
+            max_values: Some(1),
+            min_values: Some(1),
+            options: Some(options),
+            placeholder: Some(locale.text("panel.placeholder").to_owned()),
+        })],
+    });
+
+    vec![select_menu]
+}
+
+pub fn create_panel_message_embed(context: &Context, guild: &CachedGuild, locale: Locale) -> Embed {
+    let appearance = guild.appearance.read();
+    let mut embed_builder = EmbedBuilder::new()
+        .color(appearance.color.unwrap_or(context.config.appearance.color))
+        .description(
+            appearance
+                .description
+                .as_deref()
+                .unwrap_or(locale.text("panel.description")),
+        );
+
+    if let Some(title) = &appearance.title {
+        embed_builder = embed_builder.title(title);
+    }
+
+    if let Some(thumbnail_url) = &appearance.thumbnail_url {
+        if let Ok(image_source) = ImageSource::url(thumbnail_url) {
+            embed_builder = embed_builder.thumbnail(image_source);
+        }
+    }
+
+    if let Some(footer) = &appearance.footer {
+        embed_builder = embed_builder.footer(EmbedFooterBuilder::new(footer));
+    }
+
+    embed_builder.build()
+}
+
+// Looks through the channel's latest messages for the bot's own when a failed send may have
+// gone through.
+pub async fn create_panel_message(
+    context: &Context,
+    channel_id: Id<ChannelMarker>,
+    components: &[Component],
+    embeds: &[Embed],
+) -> Result<Id<MessageMarker>> {
+    let bot_id = context.application_id.cast::<UserMarker>();
+    let message = retry_create(
+        || {
+            context
+                .client
+                .create_message(channel_id)
+                .components(components)
+                .embeds(embeds)
+                .observe(context)
+        },
+        || async move {
+            let messages = context
+                .client
+                .channel_messages(channel_id)
+                .limit(PANEL_MESSAGE_SEARCH_LIMIT)
+                .observe(context)
+                .await?
+                .models()
+                .await?;
+
+            Ok(messages
+                .into_iter()
+                .find(|message| message.author.id == bot_id))
+        },
+    )
+    .await?;
+
+    Ok(message.id)
+}
+
+// Every embed takes the server's color if one is set, so replies and panels look the same.
+pub fn embed_color(context: &Context, guild_id: Option<Id<GuildMarker>>) -> u32 {
+    guild_id
+        .and_then(|guild_id| context.cache.guild(guild_id))
+        .and_then(|guild| guild.appearance.read().color)
+        .unwrap_or(context.config.appearance.color)
+}
+
+// Replies follow the server's language if one is set, and the member's own otherwise.
+pub fn interaction_locale(context: &Context, interaction: &Interaction) -> Locale {
+    interaction
+        .guild_id
+        .and_then(|guild_id| context.cache.guild(guild_id))
+        .and_then(|guild| guild.locale.read().as_deref().and_then(Locale::new))
+        .or_else(|| interaction.locale.as_deref().and_then(Locale::new))
+        .or_else(|| interaction.guild_locale.as_deref().and_then(Locale::new))
+        .unwrap_or(Locale::DEFAULT)
+}
+
+// Channel names and panel messages are seen by everyone, so they only follow the server's language.
+pub fn guild_locale(guild: &CachedGuild) -> Locale {
+    guild
+        .locale
+        .read()
+        .as_deref()
+        .and_then(Locale::new)
+        .unwrap_or(Locale::DEFAULT)
+}
+
+pub fn slowmode_label(seconds: u16, locale: Locale) -> String {
+    match seconds {
+        0 => locale.text("voice_channel.modify_slowmode.off").to_owned(),
+        seconds if seconds % 3600 == 0 => format!("{}h", seconds / 3600),
+        seconds if seconds % 60 == 0 => format!("{}m", seconds / 60),
+        seconds => format!("{seconds}s"),
+    }
+}
+
+pub fn channel_template_label(
+    channel_template: &DatabaseChannelTemplate,
+    locale: Locale,
+) -> String {
+    let mut settings = Vec::new();
+
+    if let Some(user_limit) = channel_template.user_limit {
+        settings.push(locale.format(
+            "template.settings.user_limit",
+            &[("user_limit", &user_limit)],
+        ));
+    }
+
+    if let Some(bitrate) = channel_template.bitrate {
+        settings.push(locale.format(
+            "template.settings.bitrate",
+            &[("kilobits", &(bitrate / 1000))],
+        ));
+    }
+
+    if let Some(privacy) = &channel_template.privacy {
+        settings.push(
+            locale
+                .get(&format!("template.settings.{privacy}"))
+                .map_or_else(|| privacy.clone(), str::to_owned),
+        );
+    }
 
-    interaction_response
+    if !channel_template.permission_overwrites.is_empty() {
+        settings.push(locale.format(
+            "template.settings.permission_overwrites",
+            &[("count", &channel_template.permission_overwrites.len())],
+        ));
+    }
+
+    if settings.is_empty() {
+        locale.text("template.settings.none").to_owned()
+    } else {
+        settings.join(", ")
+    }
 }
 
 pub fn create_interaction_response_modal(
@@ -63,12 +301,11 @@ pub fn create_interaction_response_modal(
         .components(components)
         .title(title)
         .build();
-    let interaction_response = InteractionResponse {
+
+    InteractionResponse {
         data: Some(data),
         kind: InteractionResponseType::Modal,
-    };
-
-    interaction_response
+    }
 }
 
 pub fn create_interaction_response_select(
@@ -82,38 +319,30 @@ pub fn create_interaction_response_select(
     }
 
     let data = data_builder.build();
-    let interaction_response = InteractionResponse {
+
+    InteractionResponse {
         data: Some(data),
         kind: InteractionResponseType::ChannelMessageWithSource,
-    };
-
-    interaction_response
+    }
 }
 
+type CheckedInteraction = (Option<Arc<CachedGuild>>, Option<Arc<CachedVoiceChannel>>);
+
 pub fn check_interaction(
     context: &Arc<Context>,
     interaction: &Interaction,
-) -> Result<(Option<Arc<CachedGuild>>, Option<Arc<CachedVoiceChannel>>)> {
+    locale: Locale,
+) -> Result<CheckedInteraction> {
     let Some(guild_id) = interaction.guild_id else {
-        return Err(eyre!("I may only be used in servers!"));
+        return Err(eyre!(locale.text("interaction.guild_only")));
     };
 
     match interaction.kind {
         InteractionType::ApplicationCommand => {
-            let Some(guild) = context.cache.guild(guild_id) else {
-                return Err(eyre!("Please kick and re-invite me!"));
-            };
-
-            if interaction.member.as_ref().is_some_and(|member| {
-                member.permissions.is_some_and(|permissions| {
-                    !permissions.contains(Permissions::ADMINISTRATOR)
-                        && !permissions.contains(Permissions::MANAGE_GUILD)
-                })
-            }) {
-                return Err(eyre!("You need either the **Administrator** or **Manage Server** permissions to use this command!"));
-            }
-
-            Ok((Some(guild), None))
+            check_guild_interaction(context, interaction, guild_id, locale)
+        }
+        InteractionType::MessageComponent if is_guild_message_component(interaction) => {
+            check_guild_interaction(context, interaction, guild_id, locale)
         }
         InteractionType::MessageComponent | InteractionType::ModalSubmit => {
             if interaction.app_permissions.is_some_and(|permissions| {
@@ -127,18 +356,105 @@ pub fn check_interaction(
                         | Permissions::VIEW_CHANNEL,
                 )
             }) {
-                return Err(eyre!("I don't have the right permissions in this channel!"));
+                return Err(eyre!(locale.text("interaction.missing_bot_permissions")));
             }
 
             let Some(channel) = interaction.channel.as_ref() else {
-                return Err(eyre!("I don't recognize this channel!"));
+                return Err(eyre!(locale.text("interaction.unknown_channel")));
             };
             let Some(voice_channel) = context.cache.voice_channel(channel.id) else {
-                return Err(eyre!("I don't recognize this voice channel!"));
+                return Err(eyre!(locale.text("interaction.unknown_voice_channel")));
             };
 
             Ok((None, Some(voice_channel)))
         }
-        _ => Err(eyre!("I don't recognize this interaction type!")),
+        _ => Err(eyre!(locale.text("interaction.unknown_interaction_type"))),
     }
 }
+
+fn check_guild_interaction(
+    context: &Arc<Context>,
+    interaction: &Interaction,
+    guild_id: Id<GuildMarker>,
+    locale: Locale,
+) -> Result<CheckedInteraction> {
+    let Some(guild) = context.cache.guild(guild_id) else {
+        return Err(eyre!(locale.text("interaction.unknown_guild")));
+    };
+
+    if interaction.member.as_ref().is_some_and(|member| {
+        member.permissions.is_some_and(|permissions| {
+            !permissions.contains(Permissions::ADMINISTRATOR)
+                && !permissions.contains(Permissions::MANAGE_GUILD)
+        })
+    }) {
+        return Err(eyre!(locale.text("interaction.missing_member_permissions")));
+    }
+
+    Ok((Some(guild), None))
+}
+
+fn is_guild_message_component(interaction: &Interaction) -> bool {
+    matches!(
+        &interaction.data,
+        Some(InteractionData::MessageComponent(data))
+            if GUILD_MESSAGE_COMPONENT_IDS.contains(custom_id_name(&data.custom_id))
+    )
+}
+
+// Custom IDs may carry an argument after a colon, such as `import-apply-button:{import_id}`.
+pub fn custom_id_name(custom_id: &str) -> &str {
+    custom_id
+        .split_once(':')
+        .map_or(custom_id, |(name, _)| name)
+}
+
+pub fn custom_id_argument(custom_id: &str) -> Option<&str> {
+    custom_id.split_once(':').map(|(_, argument)| argument)
+}
+
+pub fn import_id(interaction: &GuildMessageComponentInteraction) -> Result<Id<InteractionMarker>> {
+    let import_id = custom_id_argument(&interaction.data.custom_id)
+        .and_then(|argument| argument.parse().ok())
+        .ok_or(Error::MissingData("import ID"))?;
+
+    Ok(import_id)
+}
+
+pub fn resolved_user(data: &MessageComponentInteractionData) -> Result<&User> {
+    let user = data
+        .resolved
+        .as_ref()
+        .and_then(|resolved| resolved.users.values().next())
+        .ok_or(Error::MissingData("resolved user"))?;
+
+    Ok(user)
+}
+
+pub fn option_value(options: &[CommandDataOption], name: &str) -> Option<CommandOptionValue> {
+    options
+        .iter()
+        .find(|option| option.name == name)
+        .map(|option| option.value.clone())
+}
+
+pub fn select_value(data: &MessageComponentInteractionData) -> Result<String> {
+    let value = data
+        .values
+        .first()
+        .cloned()
+        .ok_or(Error::MissingData("select menu value"))?;
+
+    Ok(value)
+}
+
+pub fn text_input_value(data: &ModalInteractionData) -> Result<String> {
+    let value = data
+        .components
+        .first()
+        .and_then(|action_row| action_row.components.first())
+        .and_then(|text_input| text_input.value.clone())
+        .ok_or(Error::MissingData("text input value"))?;
+
+    Ok(value)
+}
@@ -0,0 +1,15 @@
+use eyre::{eyre, Result};
+use tracing_subscriber::{fmt, EnvFilter};
+
+use crate::structs::config::{LogConfig, LogFormat};
+
+pub fn init_logging(config: &LogConfig) -> Result<()> {
+    let filter = EnvFilter::try_new(&config.level)?;
+    let subscriber = fmt().with_env_filter(filter);
+
+    match config.format {
+        LogFormat::Json => subscriber.json().flatten_event(true).try_init(),
+        LogFormat::Text => subscriber.try_init(),
+    }
+    .map_err(|source| eyre!(source))
+}
@@ -1,2 +1,9 @@
+pub mod consistency;
 pub mod constants;
+pub mod error;
+pub mod guild_export;
 pub mod interaction;
+pub mod logging;
+pub mod permission;
+pub mod registration;
+pub mod request;
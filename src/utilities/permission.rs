@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use twilight_model::{
+    channel::permission_overwrite::{
+        PermissionOverwrite as ChannelPermissionOverwrite,
+        PermissionOverwriteType as ChannelPermissionOverwriteType,
+    },
+    guild::Permissions,
+    http::permission_overwrite::{
+        PermissionOverwrite as HttpPermissionOverwrite,
+        PermissionOverwriteType as HttpPermissionOverwriteType,
+    },
+    id::{
+        marker::{GenericMarker, GuildMarker, UserMarker},
+        Id,
+    },
+};
+
+use super::request::ObserveRequest;
+use crate::structs::{cache::CachedGuild, context::Context};
+
+pub fn merge_permission_overwrites(
+    permission_overwrites: &mut Vec<ChannelPermissionOverwrite>,
+    other_permission_overwrites: Vec<ChannelPermissionOverwrite>,
+) {
+    for other_permission_overwrite in other_permission_overwrites {
+        permission_overwrites.retain(|permission_overwrite| {
+            permission_overwrite.id.ne(&other_permission_overwrite.id)
+                || permission_overwrite
+                    .kind
+                    .ne(&other_permission_overwrite.kind)
+        });
+        permission_overwrites.push(other_permission_overwrite);
+    }
+}
+
+pub fn current_privacy(
+    permission_overwrites: &[ChannelPermissionOverwrite],
+    guild_id: Id<GuildMarker>,
+) -> &'static str {
+    permission_overwrites
+        .iter()
+        .find(|permission_overwrite| {
+            permission_overwrite.id.eq(&guild_id.cast())
+                && permission_overwrite
+                    .kind
+                    .eq(&ChannelPermissionOverwriteType::Role)
+        })
+        .map_or("unlocked", |everyone_permission_overwrite| {
+            if everyone_permission_overwrite
+                .deny
+                .contains(Permissions::VIEW_CHANNEL)
+            {
+                "invisible"
+            } else if everyone_permission_overwrite
+                .deny
+                .contains(Permissions::CONNECT)
+            {
+                "locked"
+            } else {
+                "unlocked"
+            }
+        })
+}
+
+pub fn apply_privacy(
+    permission_overwrites: &mut Vec<ChannelPermissionOverwrite>,
+    guild_id: Id<GuildMarker>,
+    owner_id: Option<Id<UserMarker>>,
+    privacy_option: &str,
+) {
+    let everyone_role_id: Id<GenericMarker> = guild_id.cast();
+    let (mut voice_channel_owner_allow, mut voice_channel_owner_deny): (Permissions, Permissions) =
+        (Permissions::empty(), Permissions::empty());
+    let (mut everyone_allow, mut everyone_deny): (Permissions, Permissions) =
+        (Permissions::empty(), Permissions::empty());
+
+    permission_overwrites.retain(|permission_overwrite| match permission_overwrite.kind {
+        ChannelPermissionOverwriteType::Member
+            if owner_id
+                .is_some_and(|owner_id| owner_id.get().eq(&permission_overwrite.id.get())) =>
+        {
+            voice_channel_owner_allow = permission_overwrite.allow;
+            voice_channel_owner_deny = permission_overwrite.deny;
+
+            false
+        }
+        ChannelPermissionOverwriteType::Role if everyone_role_id.eq(&permission_overwrite.id) => {
+            everyone_allow = permission_overwrite.allow;
+            everyone_deny = permission_overwrite.deny;
+
+            false
+        }
+        _ => !permission_overwrite.allow.is_empty() || !permission_overwrite.deny.is_empty(),
+    });
+
+    if privacy_option.eq("invisible") {
+        everyone_deny.remove(Permissions::CONNECT);
+        everyone_deny.insert(Permissions::VIEW_CHANNEL);
+        voice_channel_owner_allow.insert(Permissions::VIEW_CHANNEL);
+    } else if privacy_option.eq("locked") {
+        everyone_deny.remove(Permissions::VIEW_CHANNEL);
+        everyone_deny.insert(Permissions::CONNECT);
+        voice_channel_owner_allow.insert(Permissions::CONNECT);
+    } else {
+        everyone_deny.remove(Permissions::CONNECT | Permissions::VIEW_CHANNEL);
+        voice_channel_owner_allow.remove(Permissions::CONNECT | Permissions::VIEW_CHANNEL);
+    }
+
+    permission_overwrites.push(ChannelPermissionOverwrite {
+        allow: everyone_allow,
+        deny: everyone_deny,
+        id: everyone_role_id,
+        kind: ChannelPermissionOverwriteType::Role,
+    });
+
+    if privacy_option.ne("unlocked") {
+        if let Some(owner_id) = owner_id {
+            permission_overwrites.push(ChannelPermissionOverwrite {
+                allow: voice_channel_owner_allow,
+                deny: voice_channel_owner_deny,
+                id: owner_id.cast(),
+                kind: ChannelPermissionOverwriteType::Member,
+            });
+        }
+    }
+}
+
+pub fn update_category_privacy(context: &Arc<Context>, guild: &CachedGuild, privacy_option: &str) {
+    for category_channel_id in guild.category_channel_ids.read().iter() {
+        let Some(category_channel) = context.cache.category_channel(*category_channel_id) else {
+            continue;
+        };
+        let (mut everyone_allow, mut everyone_deny) = category_channel
+            .permission_overwrites
+            .read()
+            .iter()
+            .find(|permission_overwrite| {
+                permission_overwrite.id.eq(&guild.id.cast())
+                    && permission_overwrite
+                        .kind
+                        .eq(&ChannelPermissionOverwriteType::Role)
+            })
+            .map_or(
+                (Permissions::empty(), Permissions::empty()),
+                |permission_overwrite| (permission_overwrite.allow, permission_overwrite.deny),
+            );
+
+        everyone_allow.remove(Permissions::CONNECT | Permissions::VIEW_CHANNEL);
+        everyone_deny.remove(Permissions::CONNECT | Permissions::VIEW_CHANNEL);
+
+        if privacy_option.eq("invisible") {
+            everyone_deny.insert(Permissions::VIEW_CHANNEL);
+        } else if privacy_option.eq("locked") {
+            everyone_deny.insert(Permissions::CONNECT);
+        }
+
+        let task_context = Arc::clone(context);
+
+        context.tasks.spawn(
+            task_context
+                .client
+                .update_channel_permission(
+                    category_channel.id,
+                    &HttpPermissionOverwrite {
+                        allow: Some(everyone_allow),
+                        deny: Some(everyone_deny),
+                        id: guild.id.cast(),
+                        kind: HttpPermissionOverwriteType::Role,
+                    },
+                )
+                .observe(&task_context),
+        );
+
+        for join_channel_id in category_channel.join_channel_ids.read().iter() {
+            context.tasks.spawn(
+                task_context
+                    .client
+                    .update_channel_permission(
+                        *join_channel_id,
+                        &HttpPermissionOverwrite {
+                            allow: Some(everyone_allow),
+                            deny: Some(everyone_deny),
+                            id: guild.id.cast(),
+                            kind: HttpPermissionOverwriteType::Role,
+                        },
+                    )
+                    .observe(&task_context),
+            );
+        }
+    }
+}
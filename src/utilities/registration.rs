@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use eyre::Result;
+use serde_json::Value;
+use tracing::{info, warn};
+use twilight_model::{
+    application::command::Command,
+    id::{marker::GuildMarker, Id},
+};
+
+use super::{constants::COMMANDS, request::ObserveRequest};
+use crate::structs::context::Context;
+
+// Set by Discord rather than by `COMMANDS`, so they don't count as differences.
+const UNCOMPARED_FIELDS: [&str; 7] = [
+    "application_id",
+    "contexts",
+    "dm_permission",
+    "guild_id",
+    "id",
+    "integration_types",
+    "version",
+];
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct CommandChanges {
+    pub created: Vec<String>,
+    pub removed: Vec<String>,
+    pub updated: Vec<String>,
+}
+
+impl CommandChanges {
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+}
+
+pub fn diff_commands(registered_commands: &[Command], commands: &[Command]) -> CommandChanges {
+    let registered_commands = registered_commands
+        .iter()
+        .map(|command| (command.name.as_str(), comparable_command(command)))
+        .collect::<HashMap<_, _>>();
+    let mut changes = CommandChanges::default();
+
+    for command in commands {
+        match registered_commands.get(command.name.as_str()) {
+            Some(registered_command) if *registered_command == comparable_command(command) => {}
+            Some(_) => changes.updated.push(command.name.clone()),
+            None => changes.created.push(command.name.clone()),
+        }
+    }
+
+    for name in registered_commands.keys() {
+        if !commands.iter().any(|command| command.name == *name) {
+            changes.removed.push((*name).to_owned());
+        }
+    }
+
+    changes.removed.sort();
+
+    changes
+}
+
+// Overwrites the commands registered globally or in one guild with `COMMANDS`, unless Discord
+// already has them. Overwriting also removes stale commands. Guilds are recorded so their
+// commands can be cleared once they are no longer configured.
+pub async fn register_commands(
+    context: &Context,
+    guild_id: Option<Id<GuildMarker>>,
+) -> Result<CommandChanges> {
+    let changes = overwrite_commands(context, guild_id, &COMMANDS).await?;
+
+    if let Some(guild_id) = guild_id {
+        context.database.insert_command_guild(guild_id).await?;
+    }
+
+    Ok(changes)
+}
+
+// Removes every command registered globally or in one guild.
+pub async fn clear_commands(
+    context: &Context,
+    guild_id: Option<Id<GuildMarker>>,
+) -> Result<CommandChanges> {
+    let changes = overwrite_commands(context, guild_id, &[]).await?;
+
+    if let Some(guild_id) = guild_id {
+        context.database.remove_command_guild(guild_id).await?;
+    }
+
+    Ok(changes)
+}
+
+// Registers commands in the development guilds if any are configured, or globally otherwise.
+// Commands left behind by the other mode or by guilds removed from the configuration are cleared.
+pub async fn sync_commands(context: &Context) -> Result<()> {
+    let guild_ids = &context.config.development.guild_ids;
+
+    for guild_id in context.database.command_guild_ids().await? {
+        if guild_ids.contains(&guild_id) {
+            continue;
+        }
+
+        match clear_commands(context, Some(guild_id)).await {
+            Ok(changes) => log_changes(Some(guild_id), &changes),
+            Err(source) => warn!(
+                guild_id = guild_id.get(),
+                ?source,
+                "Unable to clear commands"
+            ),
+        }
+    }
+
+    if guild_ids.is_empty() {
+        log_changes(None, &register_commands(context, None).await?);
+
+        return Ok(());
+    }
+
+    log_changes(None, &clear_commands(context, None).await?);
+
+    for guild_id in guild_ids {
+        match register_commands(context, Some(*guild_id)).await {
+            Ok(changes) => log_changes(Some(*guild_id), &changes),
+            Err(source) => warn!(
+                guild_id = guild_id.get(),
+                ?source,
+                "Unable to register commands"
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+async fn overwrite_commands(
+    context: &Context,
+    guild_id: Option<Id<GuildMarker>>,
+    commands: &[Command],
+) -> Result<CommandChanges> {
+    let interaction_client = context.interaction_client();
+    let registered_commands = match guild_id {
+        Some(guild_id) => {
+            interaction_client
+                .guild_commands(guild_id)
+                .with_localizations(true)
+                .observe(context)
+                .await?
+                .models()
+                .await?
+        }
+        None => {
+            interaction_client
+                .global_commands()
+                .with_localizations(true)
+                .observe(context)
+                .await?
+                .models()
+                .await?
+        }
+    };
+    let changes = diff_commands(&registered_commands, commands);
+
+    if changes.is_empty() {
+        return Ok(changes);
+    }
+
+    match guild_id {
+        Some(guild_id) => {
+            interaction_client
+                .set_guild_commands(guild_id, commands)
+                .observe(context)
+                .await?;
+        }
+        None => {
+            interaction_client
+                .set_global_commands(commands)
+                .observe(context)
+                .await?;
+        }
+    }
+
+    Ok(changes)
+}
+
+fn log_changes(guild_id: Option<Id<GuildMarker>>, changes: &CommandChanges) {
+    let guild_id = guild_id.map(Id::get);
+
+    if changes.is_empty() {
+        info!(guild_id, "Commands are up to date");
+    } else {
+        info!(
+            guild_id,
+            created = ?changes.created,
+            updated = ?changes.updated,
+            removed = ?changes.removed,
+            "Registered commands"
+        );
+    }
+}
+
+fn comparable_command(command: &Command) -> Value {
+    let mut value = serde_json::to_value(command).unwrap_or_default();
+
+    if let Value::Object(fields) = &mut value {
+        for field in UNCOMPARED_FIELDS {
+            fields.remove(field);
+        }
+    }
+
+    remove_defaults(&mut value);
+
+    value
+}
+
+// Discord leaves out some fields at their defaults and fills in others, so defaults are dropped.
+fn remove_defaults(value: &mut Value) {
+    match value {
+        Value::Array(values) => values.iter_mut().for_each(remove_defaults),
+        Value::Object(fields) => {
+            fields.values_mut().for_each(remove_defaults);
+            fields.retain(|_, value| match value {
+                Value::Array(values) => !values.is_empty(),
+                Value::Bool(value) => *value,
+                Value::Null => false,
+                Value::Object(fields) => !fields.is_empty(),
+                _ => true,
+            });
+        }
+        _ => {}
+    }
+}
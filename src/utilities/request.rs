@@ -0,0 +1,164 @@
+use std::{
+    error::Error,
+    fmt::Debug,
+    future::{Future, IntoFuture},
+    time::Duration,
+};
+
+use axum::body::Bytes;
+use eyre::{eyre, Result};
+use futures::future::BoxFuture;
+use http_body_util::{BodyExt, Empty};
+use hyper_rustls::HttpsConnectorBuilder;
+use hyper_util::{
+    client::legacy::{Client as HyperClient, Error as HyperError},
+    rt::TokioExecutor,
+};
+use serde::de::DeserializeOwned;
+use tokio::time::sleep;
+use tracing::warn;
+use twilight_http::{
+    error::ErrorType, request::TryIntoRequest, response::Response, Error as HttpError,
+};
+
+use crate::structs::context::Context;
+
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+pub trait ObserveRequest<T>:
+    IntoFuture<Output = Result<Response<T>, HttpError>> + TryIntoRequest
+{
+    fn observe(self, context: &Context) -> BoxFuture<'static, Result<Response<T>, HttpError>>;
+}
+
+impl<T, R> ObserveRequest<T> for R
+where
+    R: IntoFuture<Output = Result<Response<T>, HttpError>> + TryIntoRequest,
+    T: Send + Unpin + 'static,
+{
+    fn observe(self, context: &Context) -> BoxFuture<'static, Result<Response<T>, HttpError>> {
+        let rest_errors = context.metrics.rest_errors.clone();
+        let request = match self.try_into_request() {
+            Ok(request) => request,
+            Err(source) => {
+                rest_errors.with_label_values(&["invalid_request"]).inc();
+
+                return Box::pin(async move { Err(source) });
+            }
+        };
+        let route = route_name(request.ratelimit_path());
+        let response_future = context.client.request::<T>(request);
+
+        Box::pin(async move {
+            let result = response_future.await;
+
+            if result.is_err() {
+                rest_errors.with_label_values(&[&route]).inc();
+            }
+
+            result
+        })
+    }
+}
+
+pub async fn retry<T>(
+    mut request: impl FnMut() -> BoxFuture<'static, Result<Response<T>, HttpError>>,
+) -> Result<Response<T>, HttpError> {
+    let mut attempt = 1;
+
+    loop {
+        match request().await {
+            Err(source) if attempt < MAX_ATTEMPTS && is_transient(&source) => {
+                warn!(attempt, %source, "Retrying failed request");
+                sleep(RETRY_DELAY * 2_u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+// Creating something twice isn't harmless, so a failed creation is only sent again right away when
+// Discord can't have received it. Otherwise `find_created` first looks for what it may have created.
+pub async fn retry_create<T, F>(
+    mut request: impl FnMut() -> BoxFuture<'static, Result<Response<T>, HttpError>>,
+    mut find_created: impl FnMut() -> F,
+) -> Result<T>
+where
+    T: DeserializeOwned + Unpin,
+    F: Future<Output = Result<Option<T>>>,
+{
+    let mut attempt = 1;
+
+    loop {
+        let source = match request().await {
+            Ok(response) => return Ok(response.model().await?),
+            Err(source) => source,
+        };
+
+        if attempt == MAX_ATTEMPTS || !is_transient(&source) {
+            return Err(source.into());
+        }
+
+        if !is_unsent(&source) {
+            if let Some(created) = find_created().await? {
+                return Ok(created);
+            }
+        }
+
+        warn!(attempt, %source, "Retrying failed creation");
+        sleep(RETRY_DELAY * 2_u32.pow(attempt - 1)).await;
+        attempt += 1;
+    }
+}
+
+pub async fn download(url: &str) -> Result<Bytes> {
+    let connector = HttpsConnectorBuilder::new()
+        .with_native_roots()?
+        .https_or_http()
+        .enable_http1()
+        .build();
+    let client = HyperClient::builder(TokioExecutor::new()).build::<_, Empty<Bytes>>(connector);
+    let response = client.get(url.parse()?).await?;
+
+    if !response.status().is_success() {
+        return Err(eyre!("Downloading {url} failed with {}", response.status()));
+    }
+
+    Ok(response.into_body().collect().await?.to_bytes())
+}
+
+pub fn is_not_found(error: &HttpError) -> bool {
+    matches!(error.kind(), ErrorType::Response { status, .. } if status.get() == 404)
+}
+
+fn is_transient(error: &HttpError) -> bool {
+    match error.kind() {
+        ErrorType::RequestError
+        | ErrorType::RequestTimedOut
+        | ErrorType::ServiceUnavailable { .. } => true,
+        ErrorType::Response { status, .. } => status.is_server_error() || status.get() == 429,
+        _ => false,
+    }
+}
+
+// Connection failures and rate limits are the only errors that prove Discord didn't act on a request.
+fn is_unsent(error: &HttpError) -> bool {
+    match error.kind() {
+        ErrorType::RequestError => error
+            .source()
+            .and_then(|source| source.downcast_ref::<HyperError>())
+            .is_some_and(HyperError::is_connect),
+        ErrorType::Response { status, .. } => status.get() == 429,
+        _ => false,
+    }
+}
+
+fn route_name(path: &impl Debug) -> String {
+    let path = format!("{path:?}");
+
+    path.split_once('(')
+        .map_or(path.as_str(), |(name, _)| name)
+        .to_owned()
+}